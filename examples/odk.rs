@@ -26,18 +26,16 @@ fn main() -> Result<(), Box<dyn Error>> {
         PageFlipStyle::Manual
     };
 
-    let bus: VirtualSignBus<'_>;
-    if args.len() > 3 {
+    let bus = if args.len() > 3 {
         let addr = args[3].parse::<u16>()?;
         println!("Providing virtual sign {}", addr);
-        bus = VirtualSignBus::new(vec![VirtualSign::new(Address(addr), flip_style)]);
+        VirtualSignBus::new(vec![VirtualSign::new(Address(addr), flip_style)])
     } else {
         // Populate bus with signs from addresses 2 to 126
         // (which seems to be the possible range for actual signs).
         println!("Providing all virtual signs 2-126");
-        let signs = (2..127).map(Address).map(|addr| VirtualSign::new(addr, flip_style));
-        bus = VirtualSignBus::new(signs);
-    }
+        VirtualSignBus::with_addresses((2..127).map(Address), flip_style)
+    };
 
     // Hook up ODK to virtual bus.
     let mut odk = Odk::try_new(port, bus)?;
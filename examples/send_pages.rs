@@ -45,7 +45,9 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
 
     // For testing purposes, print the virtual sign's configuration and pages.
-    println!("Sign configured as {:?}", bus.borrow().sign(0).sign_type());
+    if let Some(sign_type) = bus.borrow().sign(0).sign_type() {
+        println!("Sign configured as {}", sign_type);
+    }
     for page in bus.borrow().sign(0).pages() {
         println!("Page {}:\n{}", page.id(), page);
     }
@@ -0,0 +1,49 @@
+use std::error::Error;
+use std::future::Future;
+
+use crate::Message;
+
+/// Asynchronous counterpart to [`SignBus`](crate::SignBus) for use with async runtimes.
+///
+/// Only available with the `async` feature enabled. Typically `AsyncSerialSignBus` from [`flipdot-serial`]
+/// (with its `tokio` feature) is sufficient, and you do not need to implement this yourself.
+///
+/// # Examples
+///
+/// Implementing a custom bus:
+///
+/// ```
+/// use flipdot_core::{AsyncSignBus, Message, State};
+///
+/// struct ExampleSignBus {}
+///
+/// impl AsyncSignBus for ExampleSignBus {
+///     async fn process_message<'a>(&mut self, message: Message<'_>)
+///         -> Result<Option<Message<'a>>, Box<dyn std::error::Error + Send + Sync>> {
+///         match message {
+///             Message::Hello(address) |
+///             Message::QueryState(address) =>
+///                 Ok(Some(Message::ReportState(address, State::Unconfigured))),
+///             _ => Ok(None), // Implement rest of protocol here...
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`flipdot-serial`]: https://docs.rs/flipdot-serial
+pub trait AsyncSignBus {
+    /// Sends a message to the bus and returns an optional response.
+    ///
+    /// Behaves identically to [`SignBus::process_message`](crate::SignBus::process_message),
+    /// but yields to the runtime rather than blocking while waiting on the bus.
+    ///
+    /// # Examples
+    ///
+    /// See the [trait-level documentation].
+    ///
+    /// [trait-level documentation]: #examples
+    fn process_message<'a>(
+        &mut self,
+        message: Message<'_>,
+    ) -> impl Future<Output = Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>>> + Send;
+}
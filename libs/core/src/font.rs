@@ -0,0 +1,252 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A fixed-width bitmap font for drawing text on a [`Page`](crate::Page).
+///
+/// Each glyph is stored row-major in the same format accepted by [`Page::draw_glyph`](crate::Page::draw_glyph):
+/// each row is `ceil(glyph_width / 8)` bytes, most significant bit first. Characters without a glyph
+/// are drawn as blank space of the same width.
+///
+/// # Examples
+///
+/// ```
+/// use flipdot_core::{Font, Page, PageId};
+///
+/// let font = Font::ascii_5x7();
+/// assert_eq!(17, font.text_width("abc"));
+///
+/// let mut page = Page::new(PageId(1), 90, 7);
+/// page.draw_text(0, 0, "Hi", &font);
+/// assert!(page.get_pixel(0, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Font {
+    glyph_width: u32,
+    glyph_height: u32,
+    spacing: u32,
+    glyphs: BTreeMap<char, Vec<u8>>,
+}
+
+impl Font {
+    /// Creates a new empty `Font` with the given glyph size and one column of spacing between characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Font;
+    /// let font = Font::new(3, 5);
+    /// assert_eq!((3, 5), (font.glyph_width(), font.glyph_height()));
+    /// assert_eq!(None, font.glyph('a'));
+    /// ```
+    pub fn new(glyph_width: u32, glyph_height: u32) -> Self {
+        Font {
+            glyph_width,
+            glyph_height,
+            spacing: 1,
+            glyphs: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a 5 × 7 font covering the printable ASCII characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Font;
+    /// let font = Font::ascii_5x7();
+    /// assert_eq!((5, 7), (font.glyph_width(), font.glyph_height()));
+    /// assert!(font.glyph('A').is_some());
+    /// assert!(font.glyph('é').is_none());
+    /// ```
+    pub fn ascii_5x7() -> Self {
+        let mut font = Font::new(5, 7);
+        for (ch, columns) in (' '..='~').zip(ASCII_5X7) {
+            let mut rows = vec![0; 7];
+            for (x, column) in columns.iter().enumerate() {
+                for (y, row) in rows.iter_mut().enumerate() {
+                    if column & (1 << y) != 0 {
+                        *row |= 0x80 >> x;
+                    }
+                }
+            }
+            let _ = font.glyphs.insert(ch, rows);
+        }
+        font
+    }
+
+    /// Adds or replaces the glyph for a character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bitmap` is shorter than `ceil(glyph_width / 8) * glyph_height` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Font;
+    /// let font = Font::new(3, 3).with_glyph('+', vec![0b0100_0000, 0b1110_0000, 0b0100_0000]);
+    /// assert_eq!(Some(&[0b0100_0000, 0b1110_0000, 0b0100_0000][..]), font.glyph('+'));
+    /// ```
+    pub fn with_glyph<T: Into<Vec<u8>>>(mut self, ch: char, bitmap: T) -> Self {
+        let bitmap = bitmap.into();
+        let expected_len = (self.glyph_width as usize).div_ceil(8) * self.glyph_height as usize;
+        assert!(
+            bitmap.len() >= expected_len,
+            "Glyph data for {} x {} font must be at least {} bytes, got {}",
+            self.glyph_width,
+            self.glyph_height,
+            expected_len,
+            bitmap.len()
+        );
+        let _ = self.glyphs.insert(ch, bitmap);
+        self
+    }
+
+    /// Sets the number of blank columns between adjacent characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Font;
+    /// let font = Font::ascii_5x7().with_spacing(2);
+    /// assert_eq!(2, font.spacing());
+    /// assert_eq!(12, font.text_width("ab"));
+    /// ```
+    pub fn with_spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Returns the width of each glyph, in pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Font;
+    /// assert_eq!(5, Font::ascii_5x7().glyph_width());
+    /// ```
+    pub fn glyph_width(&self) -> u32 {
+        self.glyph_width
+    }
+
+    /// Returns the height of each glyph, in pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Font;
+    /// assert_eq!(7, Font::ascii_5x7().glyph_height());
+    /// ```
+    pub fn glyph_height(&self) -> u32 {
+        self.glyph_height
+    }
+
+    /// Returns the number of blank columns between adjacent characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Font;
+    /// assert_eq!(1, Font::ascii_5x7().spacing());
+    /// ```
+    pub fn spacing(&self) -> u32 {
+        self.spacing
+    }
+
+    /// Returns the glyph bitmap for a character, or `None` if the font doesn't contain it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Font;
+    /// let font = Font::ascii_5x7();
+    /// assert_eq!(Some(&[0, 0, 0, 0, 0, 0, 0][..]), font.glyph(' '));
+    /// ```
+    pub fn glyph(&self, ch: char) -> Option<&[u8]> {
+        self.glyphs.get(&ch).map(Vec::as_slice)
+    }
+
+    /// Returns the width, in pixels, of `text` when drawn in this font.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Font;
+    /// let font = Font::ascii_5x7();
+    /// assert_eq!(0, font.text_width(""));
+    /// assert_eq!(5, font.text_width("a"));
+    /// assert_eq!(11, font.text_width("ab"));
+    /// ```
+    pub fn text_width(&self, text: &str) -> u32 {
+        match text.chars().count() as u32 {
+            0 => 0,
+            count => count * self.glyph_width + (count - 1) * self.spacing,
+        }
+    }
+}
+
+/// Glyphs for ASCII `' '` through `'~'`, stored column-major with the least significant bit at the top.
+#[rustfmt::skip]
+const ASCII_5X7: [[u8; 5]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], [0x00, 0x00, 0x5F, 0x00, 0x00], [0x00, 0x07, 0x00, 0x07, 0x00], [0x14, 0x7F, 0x14, 0x7F, 0x14],
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12], [0x23, 0x13, 0x08, 0x64, 0x62], [0x36, 0x49, 0x55, 0x22, 0x50], [0x00, 0x05, 0x03, 0x00, 0x00],
+    [0x00, 0x1C, 0x22, 0x41, 0x00], [0x00, 0x41, 0x22, 0x1C, 0x00], [0x08, 0x2A, 0x1C, 0x2A, 0x08], [0x08, 0x08, 0x3E, 0x08, 0x08],
+    [0x00, 0x50, 0x30, 0x00, 0x00], [0x08, 0x08, 0x08, 0x08, 0x08], [0x00, 0x60, 0x60, 0x00, 0x00], [0x20, 0x10, 0x08, 0x04, 0x02],
+    [0x3E, 0x51, 0x49, 0x45, 0x3E], [0x00, 0x42, 0x7F, 0x40, 0x00], [0x42, 0x61, 0x51, 0x49, 0x46], [0x21, 0x41, 0x45, 0x4B, 0x31],
+    [0x18, 0x14, 0x12, 0x7F, 0x10], [0x27, 0x45, 0x45, 0x45, 0x39], [0x3C, 0x4A, 0x49, 0x49, 0x30], [0x01, 0x71, 0x09, 0x05, 0x03],
+    [0x36, 0x49, 0x49, 0x49, 0x36], [0x06, 0x49, 0x49, 0x29, 0x1E], [0x00, 0x36, 0x36, 0x00, 0x00], [0x00, 0x56, 0x36, 0x00, 0x00],
+    [0x08, 0x14, 0x22, 0x41, 0x00], [0x14, 0x14, 0x14, 0x14, 0x14], [0x00, 0x41, 0x22, 0x14, 0x08], [0x02, 0x01, 0x51, 0x09, 0x06],
+    [0x32, 0x49, 0x79, 0x41, 0x3E], [0x7E, 0x11, 0x11, 0x11, 0x7E], [0x7F, 0x49, 0x49, 0x49, 0x36], [0x3E, 0x41, 0x41, 0x41, 0x22],
+    [0x7F, 0x41, 0x41, 0x22, 0x1C], [0x7F, 0x49, 0x49, 0x49, 0x41], [0x7F, 0x09, 0x09, 0x09, 0x01], [0x3E, 0x41, 0x49, 0x49, 0x7A],
+    [0x7F, 0x08, 0x08, 0x08, 0x7F], [0x00, 0x41, 0x7F, 0x41, 0x00], [0x20, 0x40, 0x41, 0x3F, 0x01], [0x7F, 0x08, 0x14, 0x22, 0x41],
+    [0x7F, 0x40, 0x40, 0x40, 0x40], [0x7F, 0x02, 0x0C, 0x02, 0x7F], [0x7F, 0x04, 0x08, 0x10, 0x7F], [0x3E, 0x41, 0x41, 0x41, 0x3E],
+    [0x7F, 0x09, 0x09, 0x09, 0x06], [0x3E, 0x41, 0x51, 0x21, 0x5E], [0x7F, 0x09, 0x19, 0x29, 0x46], [0x46, 0x49, 0x49, 0x49, 0x31],
+    [0x01, 0x01, 0x7F, 0x01, 0x01], [0x3F, 0x40, 0x40, 0x40, 0x3F], [0x1F, 0x20, 0x40, 0x20, 0x1F], [0x3F, 0x40, 0x38, 0x40, 0x3F],
+    [0x63, 0x14, 0x08, 0x14, 0x63], [0x07, 0x08, 0x70, 0x08, 0x07], [0x61, 0x51, 0x49, 0x45, 0x43], [0x00, 0x7F, 0x41, 0x41, 0x00],
+    [0x02, 0x04, 0x08, 0x10, 0x20], [0x00, 0x41, 0x41, 0x7F, 0x00], [0x04, 0x02, 0x01, 0x02, 0x04], [0x40, 0x40, 0x40, 0x40, 0x40],
+    [0x00, 0x01, 0x02, 0x04, 0x00], [0x20, 0x54, 0x54, 0x54, 0x78], [0x7F, 0x48, 0x44, 0x44, 0x38], [0x38, 0x44, 0x44, 0x44, 0x20],
+    [0x38, 0x44, 0x44, 0x48, 0x7F], [0x38, 0x54, 0x54, 0x54, 0x18], [0x08, 0x7E, 0x09, 0x01, 0x02], [0x0C, 0x52, 0x52, 0x52, 0x3E],
+    [0x7F, 0x08, 0x04, 0x04, 0x78], [0x00, 0x44, 0x7D, 0x40, 0x00], [0x20, 0x40, 0x44, 0x3D, 0x00], [0x7F, 0x10, 0x28, 0x44, 0x00],
+    [0x00, 0x41, 0x7F, 0x40, 0x00], [0x7C, 0x04, 0x18, 0x04, 0x78], [0x7C, 0x08, 0x04, 0x04, 0x78], [0x38, 0x44, 0x44, 0x44, 0x38],
+    [0x7C, 0x14, 0x14, 0x14, 0x08], [0x08, 0x14, 0x14, 0x18, 0x7C], [0x7C, 0x08, 0x04, 0x04, 0x08], [0x48, 0x54, 0x54, 0x54, 0x20],
+    [0x04, 0x3F, 0x44, 0x40, 0x20], [0x3C, 0x40, 0x40, 0x20, 0x7C], [0x1C, 0x20, 0x40, 0x20, 0x1C], [0x3C, 0x40, 0x30, 0x40, 0x3C],
+    [0x44, 0x28, 0x10, 0x28, 0x44], [0x0C, 0x50, 0x50, 0x50, 0x3C], [0x44, 0x64, 0x54, 0x4C, 0x44], [0x00, 0x08, 0x36, 0x41, 0x00],
+    [0x00, 0x00, 0x7F, 0x00, 0x00], [0x00, 0x41, 0x36, 0x08, 0x00], [0x08, 0x04, 0x08, 0x10, 0x08],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_5x7_covers_printable() {
+        let font = Font::ascii_5x7();
+        for ch in ' '..='~' {
+            assert_eq!(Some(7), font.glyph(ch).map(<[u8]>::len), "{:?}", ch);
+        }
+        assert_eq!(None, font.glyph('\n'));
+    }
+
+    #[test]
+    fn ascii_5x7_transposed() {
+        let font = Font::ascii_5x7();
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            0b0111_0000,
+            0b1000_1000,
+            0b1000_1000,
+            0b1000_1000,
+            0b1111_1000,
+            0b1000_1000,
+            0b1000_1000,
+        ];
+        assert_eq!(Some(expected), font.glyph('A'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn short_glyph_panics() {
+        let _ = Font::new(9, 2).with_glyph('x', vec![0; 3]);
+    }
+}
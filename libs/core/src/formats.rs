@@ -0,0 +1,322 @@
+//! Reading and writing [`Page`]s to and from files.
+//!
+//! There does not appear to be any documented file format shared by existing sign-editing tools,
+//! so this module defines a simple `.flipdot` container for storing a multi-page message.
+//!
+//! # Format Details
+//!
+//! All integers are little-endian. The header is followed by the pages in order, each stored
+//! in the same native format returned by [`Page::as_bytes`], including the header and padding.
+//! All pages in a file must have the same dimensions, which must each be between 1 and 255 pixels.
+//!
+//! ```text
+//! ┌───────────────────┬─────────┬───────┬────────┬────────────┬────────┬ ┄ ┬────────┐
+//! │ "FLIPDOT"         │ Version │ Width │ Height │ Page count │ Page 0 │...│ Page n │
+//! └───────────────────┴─────────┴───────┴────────┴────────────┴────────┴ ┄ ┴────────┘
+//! ┆      7 bytes      ┆ 1 byte  ┆  u32  ┆  u32   ┆    u32     ┆
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use flipdot_core::{formats, Page, PageId};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! #
+//! let mut page = Page::new(PageId(1), 90, 7);
+//! page.set_pixel(4, 4, true);
+//!
+//! let mut file = Vec::new();
+//! formats::write_flipdot(&mut file, &[page.clone()])?;
+//!
+//! let pages = formats::read_flipdot(&mut &file[..])?;
+//! assert_eq!(vec![page], pages);
+//! #
+//! # Ok(()) }
+//! ```
+
+use std::io::{Read, Write};
+
+use thiserror::Error;
+
+use crate::{Page, PageError};
+
+/// Errors relating to reading and writing [`Page`]s in a file format.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum FormatError {
+    /// Failed reading/writing the file data.
+    #[error("Failed reading/writing file data")]
+    Io {
+        /// The underlying I/O error.
+        #[from]
+        source: std::io::Error,
+    },
+
+    /// The data did not start with the expected magic bytes.
+    #[error("Data is not in .flipdot format")]
+    BadMagic,
+
+    /// The data was written by an unsupported version of the format.
+    #[error("Unsupported .flipdot format version {}", version)]
+    UnsupportedVersion {
+        /// The version that was found.
+        version: u8,
+    },
+
+    /// The page dimensions were zero or too large.
+    #[error("Invalid page dimensions {}x{}: Expected 1-{} pixels in each direction", width, height, max)]
+    InvalidDimensions {
+        /// The page width.
+        width: u32,
+
+        /// The page height.
+        height: u32,
+
+        /// The largest supported width or height.
+        max: u32,
+    },
+
+    /// A page was invalid or inconsistent with the others.
+    #[error("Invalid page data")]
+    InvalidPage {
+        /// The underlying page error.
+        #[from]
+        source: PageError,
+    },
+}
+
+const MAGIC: &[u8; 7] = b"FLIPDOT";
+const VERSION: u8 = 1;
+
+/// The largest page width or height that can be stored, comfortably above that of any known sign.
+const MAX_DIMENSION: u32 = u8::MAX as u32;
+
+/// Reads a sequence of [`Page`]s in `.flipdot` format.
+///
+/// See the [module documentation](self) for details of the format.
+///
+/// # Errors
+///
+/// Returns:
+/// * [`FormatError::Io`] if the data could not be read, including if it ended early.
+/// * [`FormatError::BadMagic`] if the data is not in `.flipdot` format.
+/// * [`FormatError::UnsupportedVersion`] if the data uses an unknown version of the format.
+/// * [`FormatError::InvalidDimensions`] if the data contains pages with unsupported dimensions.
+///
+/// # Examples
+///
+/// ```
+/// # use flipdot_core::formats;
+/// let result = formats::read_flipdot(&mut &b"NOTFLIPDOT"[..]);
+/// assert!(result.is_err());
+/// ```
+pub fn read_flipdot<R: Read>(reader: &mut R) -> Result<Vec<Page<'static>>, FormatError> {
+    let mut magic = [0; 7];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(FormatError::BadMagic);
+    }
+
+    let version = read_u8(reader)?;
+    if version != VERSION {
+        return Err(FormatError::UnsupportedVersion { version });
+    }
+
+    let width = read_u32(reader)?;
+    let height = read_u32(reader)?;
+    let count = read_u32(reader)?;
+    if count > 0 {
+        check_dimensions(width, height)?;
+    }
+    let page_len = Page::total_bytes(width, height);
+
+    // Pages are read one at a time rather than preallocated, so a bogus count just runs into the end of the data.
+    let mut pages = Vec::new();
+    for _ in 0..count {
+        let mut bytes = vec![0; page_len];
+        reader.read_exact(&mut bytes)?;
+        pages.push(Page::from_bytes(width, height, bytes)?);
+    }
+    Ok(pages)
+}
+
+/// Writes a sequence of [`Page`]s in `.flipdot` format.
+///
+/// See the [module documentation](self) for details of the format.
+///
+/// # Errors
+///
+/// Returns:
+/// * [`FormatError::Io`] if the data could not be written.
+/// * [`FormatError::InvalidDimensions`] if the pages are wider or taller than 255 pixels.
+/// * [`FormatError::InvalidPage`] if the pages do not all have the same dimensions.
+///
+/// # Examples
+///
+/// ```
+/// # use flipdot_core::{formats, Page, PageId};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// #
+/// let pages = [Page::new(PageId(1), 90, 7), Page::new(PageId(2), 90, 7)];
+/// let mut file = Vec::new();
+/// formats::write_flipdot(&mut file, &pages)?;
+/// assert!(file.starts_with(b"FLIPDOT"));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn write_flipdot<W: Write>(writer: &mut W, pages: &[Page<'_>]) -> Result<(), FormatError> {
+    let (width, height) = pages.first().map_or((0, 0), |page| (page.width(), page.height()));
+    if let Some(page) = pages.iter().find(|page| (page.width(), page.height()) != (width, height)) {
+        return Err(PageError::DimensionMismatch {
+            expected_width: width,
+            expected_height: height,
+            actual_width: page.width(),
+            actual_height: page.height(),
+        }
+        .into());
+    }
+    if !pages.is_empty() {
+        check_dimensions(width, height)?;
+    }
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[VERSION])?;
+    writer.write_all(&width.to_le_bytes())?;
+    writer.write_all(&height.to_le_bytes())?;
+    writer.write_all(&(pages.len() as u32).to_le_bytes())?;
+    for page in pages {
+        writer.write_all(page.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Verifies that pages of the given dimensions can be stored.
+fn check_dimensions(width: u32, height: u32) -> Result<(), FormatError> {
+    let valid = 1..=MAX_DIMENSION;
+    if valid.contains(&width) && valid.contains(&height) {
+        Ok(())
+    } else {
+        Err(FormatError::InvalidDimensions {
+            width,
+            height,
+            max: MAX_DIMENSION,
+        })
+    }
+}
+
+/// Reads a single byte.
+fn read_u8<R: Read>(reader: &mut R) -> Result<u8, FormatError> {
+    let mut buf = [0; 1];
+    reader.read_exact(&mut buf)?;
+    Ok(buf[0])
+}
+
+/// Reads a little-endian `u32`.
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32, FormatError> {
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PageId;
+    use std::error::Error;
+
+    #[test]
+    fn round_trip() -> Result<(), Box<dyn Error>> {
+        let mut page1 = Page::new(PageId(1), 40, 12);
+        page1.set_pixel(0, 11, true);
+        let page2 = Page::checkerboard(PageId(2), 40, 12);
+
+        let mut file = Vec::new();
+        write_flipdot(&mut file, &[page1.clone(), page2.clone()])?;
+        assert_eq!(7 + 1 + 4 * 3 + 2 * page1.as_bytes().len(), file.len());
+
+        let pages = read_flipdot(&mut &file[..])?;
+        assert_eq!(vec![page1, page2], pages);
+        Ok(())
+    }
+
+    #[test]
+    fn empty() -> Result<(), Box<dyn Error>> {
+        let mut file = Vec::new();
+        write_flipdot(&mut file, &[])?;
+        let pages = read_flipdot(&mut &file[..])?;
+        assert!(pages.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn mismatched_dimensions() {
+        let pages = [Page::new(PageId(1), 90, 7), Page::new(PageId(2), 30, 7)];
+        let error = write_flipdot(&mut Vec::new(), &pages).unwrap_err();
+        assert!(matches!(
+            error,
+            FormatError::InvalidPage {
+                source: PageError::DimensionMismatch { actual_width: 30, .. }
+            }
+        ));
+    }
+
+    #[test]
+    fn bad_header() -> Result<(), Box<dyn Error>> {
+        let mut file = Vec::new();
+        write_flipdot(&mut file, &[Page::new(PageId(1), 90, 7)])?;
+
+        let mut bad_magic = file.clone();
+        bad_magic[0] = b'X';
+        assert!(matches!(read_flipdot(&mut &bad_magic[..]), Err(FormatError::BadMagic)));
+
+        let mut bad_version = file.clone();
+        bad_version[7] = 2;
+        assert!(matches!(
+            read_flipdot(&mut &bad_version[..]),
+            Err(FormatError::UnsupportedVersion { version: 2 })
+        ));
+
+        let truncated = &file[..file.len() - 1];
+        assert!(matches!(read_flipdot(&mut &truncated[..]), Err(FormatError::Io { .. })));
+        Ok(())
+    }
+
+    /// Builds a `.flipdot` header with the given fields and no page data.
+    fn header(width: u32, height: u32, count: u32) -> Vec<u8> {
+        let mut file = MAGIC.to_vec();
+        file.push(VERSION);
+        file.extend_from_slice(&width.to_le_bytes());
+        file.extend_from_slice(&height.to_le_bytes());
+        file.extend_from_slice(&count.to_le_bytes());
+        file
+    }
+
+    #[test]
+    fn malformed_header() {
+        let huge = header(u32::MAX, u32::MAX, 1);
+        assert!(matches!(
+            read_flipdot(&mut &huge[..]),
+            Err(FormatError::InvalidDimensions {
+                width: u32::MAX,
+                height: u32::MAX,
+                max: 255
+            })
+        ));
+
+        let zero = header(0, 7, 1);
+        assert!(matches!(
+            read_flipdot(&mut &zero[..]),
+            Err(FormatError::InvalidDimensions { width: 0, .. })
+        ));
+
+        let many = header(90, 7, u32::MAX);
+        assert!(matches!(read_flipdot(&mut &many[..]), Err(FormatError::Io { .. })));
+    }
+
+    #[test]
+    fn oversized_pages() {
+        let error = write_flipdot(&mut Vec::new(), &[Page::new(PageId(1), 256, 7)]).unwrap_err();
+        assert!(matches!(error, FormatError::InvalidDimensions { width: 256, .. }));
+    }
+}
@@ -1,12 +1,11 @@
-use std::borrow::Cow;
-use std::fmt::{self, Display, Formatter};
-use std::io::{BufRead, BufReader, Read, Write};
-use std::str;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::io::{ErrorKind, Read, Write};
 
 use derive_more::{Display, LowerHex, UpperHex};
-use lazy_static::lazy_static;
-use num_traits::Num;
-use regex::bytes::Regex;
 use thiserror::Error;
 
 /// Errors related to reading/writing [`Frame`]s of data.
@@ -24,6 +23,7 @@ pub enum FrameError {
     },
 
     /// Failed reading/writing a [`Frame`] of data.
+    #[cfg(feature = "std")]
     #[error("Failed reading/writing a frame of data")]
     Io {
         /// The underlying I/O error.
@@ -149,6 +149,30 @@ pub struct Frame<'a> {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display, LowerHex, UpperHex)]
 pub struct MsgType(pub u8);
 
+impl MsgType {
+    /// Message type of [`SendData`](crate::Message::SendData).
+    pub const SEND_DATA: MsgType = MsgType(0);
+
+    /// Message type of [`DataChunksSent`](crate::Message::DataChunksSent).
+    pub const DATA_CHUNKS_SENT: MsgType = MsgType(1);
+
+    /// Message type shared by [`Hello`](crate::Message::Hello), [`QueryState`](crate::Message::QueryState),
+    /// and [`Goodbye`](crate::Message::Goodbye), which are distinguished by their data byte.
+    pub const CONTROL: MsgType = MsgType(2);
+
+    /// Message type of [`RequestOperation`](crate::Message::RequestOperation).
+    pub const REQUEST_OPERATION: MsgType = MsgType(3);
+
+    /// Message type of [`ReportState`](crate::Message::ReportState).
+    pub const REPORT_STATE: MsgType = MsgType(4);
+
+    /// Message type of [`AckOperation`](crate::Message::AckOperation).
+    pub const ACK_OPERATION: MsgType = MsgType(5);
+
+    /// Message type of [`PixelsComplete`](crate::Message::PixelsComplete).
+    pub const PIXELS_COMPLETE: MsgType = MsgType(6);
+}
+
 /// The address of a sign, used to identify it on the bus.
 ///
 /// # Examples
@@ -322,6 +346,58 @@ impl<'a> Frame<'a> {
         output
     }
 
+    /// Compares the wire format of this frame with another, returning the bytes that differ.
+    ///
+    /// Each entry is the index into [`to_bytes`](Self::to_bytes) along with the byte from `self` and
+    /// the byte from `other`. If one frame is longer than the other, the extra trailing bytes are reported
+    /// with `0x00` standing in for the missing side; since the wire format is ASCII text, this can never
+    /// be confused with a real byte. Useful for pinpointing where two captured exchanges diverge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Data, Frame, MsgType};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let frame1 = Frame::new(Address(2), MsgType(1), Data::try_new(vec![3, 31])?); // :02000201031FD9
+    /// let frame2 = Frame::new(Address(3), MsgType(1), Data::try_new(vec![3, 31])?); // :02000301031FD8
+    /// assert_eq!(vec![(6, b'2', b'3'), (14, b'9', b'8')], frame1.byte_diff(&frame2));
+    /// assert!(frame1.byte_diff(&frame1).is_empty());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn byte_diff(&self, other: &Frame<'_>) -> Vec<(usize, u8, u8)> {
+        let ours = self.to_bytes();
+        let theirs = other.to_bytes();
+        (0..ours.len().max(theirs.len()))
+            .map(|i| (i, ours.get(i).copied().unwrap_or(0), theirs.get(i).copied().unwrap_or(0)))
+            .filter(|(_, a, b)| a != b)
+            .collect()
+    }
+
+    /// Reads the declared data length from the header of a frame in the Intel HEX wire format.
+    ///
+    /// Only the leading colon and the two-digit length field are examined, so this works on incomplete
+    /// input and can be used to determine how many more bytes to expect: a complete frame is
+    /// `11 + 2 * len` bytes long, excluding the trailing carriage return/linefeed. Returns `None`
+    /// if the input is too short or the header is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Frame;
+    /// assert_eq!(Some(2), Frame::declared_len(b":02000201031FD9\r\n"));
+    /// assert_eq!(Some(0x10), Frame::declared_len(b":10"));
+    /// assert_eq!(None, Frame::declared_len(b":1"));
+    /// assert_eq!(None, Frame::declared_len(b"02000201031FD9"));
+    /// ```
+    pub fn declared_len(bytes: &[u8]) -> Option<u8> {
+        match bytes {
+            [b':', high, low, ..] => Some(hex_digit(*high)? << 4 | hex_digit(*low)?),
+            _ => None,
+        }
+    }
+
     /// Parses the Intel HEX wire format into a new `Frame`.
     ///
     /// # Errors
@@ -344,49 +420,35 @@ impl<'a> Frame<'a> {
     /// # Ok(()) }
     /// ```
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, FrameError> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"(?x)
-                ^:                                  # Colon marks beginning of frame
-                (?P<data_len>[[:xdigit:]]{2})       # 2 hex digits for data length
-                (?P<address>[[:xdigit:]]{4})        # 4 hex digits for address
-                (?P<message_type>[[:xdigit:]]{2})   # 2 hex digits for message type
-                (?P<data>(?:[[:xdigit:]]{2})*)      # Zero or more groups of 2 hex digits for data
-                (?P<checksum>[[:xdigit:]]{2})       # 2 hex digits for checksum
-                (?:\r\n)?$                          # Optional newline sequence
-            ").unwrap(); // Regex is valid so safe to unwrap.
-        }
-        let captures = RE
-            .captures(bytes)
-            .ok_or_else(|| FrameError::InvalidFrame { data: bytes.into() })?;
-
-        // Regex always matches all capture groups so safe to unwrap.
-        let data_len = parse_hex::<u8>(captures.name("data_len").unwrap().as_bytes());
-        let address = parse_hex::<u16>(captures.name("address").unwrap().as_bytes());
-        let message_type = parse_hex::<u8>(captures.name("message_type").unwrap().as_bytes());
-        let data_bytes = captures.name("data").unwrap().as_bytes();
-        let provided_checksum = parse_hex::<u8>(captures.name("checksum").unwrap().as_bytes());
-
-        let data = data_bytes.chunks(2).map(parse_hex::<u8>).collect::<Vec<_>>();
-        if data.len() != data_len as usize {
-            return Err(FrameError::FrameDataMismatch {
-                data: bytes.into(),
-                expected: data_len as usize,
-                actual: data.len(),
-            });
-        }
-
-        let frame = Frame::new(Address(address), MsgType(message_type), Data::try_new(data)?);
-        let payload = frame.payload();
-        let computed_checksum = checksum(&payload);
-        if computed_checksum != provided_checksum {
-            return Err(FrameError::BadChecksum {
-                data: bytes.into(),
-                expected: provided_checksum,
-                actual: computed_checksum,
-            });
-        }
+        let mut data = Vec::new();
+        let (address, message_type) = parse_frame(bytes, &mut data)?;
+        Ok(Frame::new(address, message_type, Data::try_new(data)?))
+    }
 
-        Ok(frame)
+    /// Checks that `input` is a well-formed frame and returns it in canonical form.
+    ///
+    /// Surrounding whitespace (including a trailing CRLF) is ignored. The result uses uppercase hex digits
+    /// and has no line ending, exactly as [`to_bytes`](Self::to_bytes) would produce. This is handy for
+    /// verifying frames written by hand in documentation or tests.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`from_bytes`](Self::from_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::Frame;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// assert_eq!(":02000201031FD9", Frame::normalize_hex("  :02000201031fd9\r\n")?);
+    /// assert!(Frame::normalize_hex(":02000201031FD8").is_err());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn normalize_hex(input: &str) -> Result<String, FrameError> {
+        let frame = Frame::from_bytes(input.trim().as_bytes())?;
+        Ok(String::from_utf8(frame.to_bytes()).expect("Frame bytes are always ASCII"))
     }
 
     /// Writes the byte representation (including CRLF) of the frame to a writer.
@@ -407,6 +469,7 @@ impl<'a> Frame<'a> {
     /// #
     /// # Ok(()) }
     /// ```
+    #[cfg(feature = "std")]
     pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), FrameError> {
         writer.write_all(&self.to_bytes_with_newline())?;
         Ok(())
@@ -434,12 +497,55 @@ impl<'a> Frame<'a> {
     /// #
     /// # Ok(()) }
     /// ```
-    pub fn read<R: Read>(mut reader: &mut R) -> Result<Self, FrameError> {
-        // One-byte buffer seems to work best with such small payloads
-        let mut buf_reader = BufReader::with_capacity(1, &mut reader);
-        let mut data = Vec::<u8>::new();
-        let _ = buf_reader.read_until(b'\n', &mut data)?;
-        let frame = Frame::from_bytes(&data)?;
+    #[cfg(feature = "std")]
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, FrameError> {
+        Self::read_into(reader, &mut Vec::new())
+    }
+
+    /// Reads the next frame like [`read`](Self::read), but uses `buf` to hold the line being read.
+    ///
+    /// `buf` is cleared first, so passing the same buffer to repeated calls avoids allocating
+    /// a new one for every frame. After the call, it contains the raw bytes that were read.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`read`](Self::read).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Frame};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let mut reader = &b":01007F02FF7F\r\n:00000201FD\r\n"[..];
+    /// let mut buf = Vec::new();
+    /// assert_eq!(Address(0x7F), Frame::read_into(&mut reader, &mut buf)?.address());
+    /// assert_eq!(Address(2), Frame::read_into(&mut reader, &mut buf)?.address());
+    /// assert_eq!(b":00000201FD\r\n", &buf[..]);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_into<R: Read>(reader: &mut R, buf: &mut Vec<u8>) -> Result<Self, FrameError> {
+        buf.clear();
+
+        // Reading a byte at a time avoids consuming anything past the end of the frame.
+        let mut byte = [0u8];
+        loop {
+            match reader.read(&mut byte) {
+                Ok(0) => break,
+                Ok(_) => {
+                    buf.push(byte[0]);
+                    if byte[0] == b'\n' {
+                        break;
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let frame = Frame::from_bytes(buf)?;
         Ok(frame)
     }
 
@@ -465,7 +571,7 @@ impl Display for Frame<'_> {
     /// Useful for viewing traffic on a bus. All numbers are in hex.
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "Type {:02X} | Addr {:04X}", self.message_type.0, self.address.0)?;
-        if self.data.0.len() > 0 {
+        if !self.data.0.is_empty() {
             write!(f, " | Data ")?;
             for byte in self.data.0.iter() {
                 write!(f, "{:02X} ", byte)?;
@@ -475,16 +581,235 @@ impl Display for Frame<'_> {
     }
 }
 
-/// Parses a byte slice representing ASCII text into a hex digit.
+/// Reusable parser for converting many Intel HEX frames without allocating for each one.
+///
+/// [`Frame::from_bytes`] is simpler for casual use, but allocates a new buffer for every frame.
+/// `FrameParser` instead decodes into internal scratch space that is reused across calls,
+/// which is significantly faster when processing large captures.
+///
+/// # Examples
+///
+/// ```
+/// use flipdot_core::{Address, FrameParser, MsgType};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// #
+/// let capture: &[&[u8]] = &[b":02000201031FD9\r\n", b":01007F02FF7F\r\n"];
+///
+/// let mut parser = FrameParser::new();
+/// let mut addresses = Vec::new();
+/// for line in capture {
+///     let frame = parser.parse(line)?;
+///     addresses.push(frame.address());
+/// }
+/// assert_eq!(vec![Address(2), Address(0x7F)], addresses);
+/// #
+/// # Ok(()) }
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct FrameParser {
+    data: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl FrameParser {
+    /// Creates a new `FrameParser`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::FrameParser;
+    /// let parser = FrameParser::new();
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Parses the Intel HEX wire format into a `Frame` whose data borrows from the parser.
+    ///
+    /// The returned frame must be dropped (or converted into an owned form) before parsing the next one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Frame::from_bytes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Data, Frame, FrameParser, MsgType};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let mut parser = FrameParser::new();
+    /// let frame = parser.parse(b":02000201031FD9\r\n")?;
+    /// assert_eq!(Frame::new(Address(2), MsgType(1), Data::try_new(vec![3, 31])?), frame);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn parse(&mut self, bytes: &[u8]) -> Result<Frame<'_>, FrameError> {
+        let (address, message_type) = parse_frame(bytes, &mut self.data)?;
+        Ok(Frame::new(address, message_type, Data::try_new(&self.data[..])?))
+    }
+}
+
+/// Incrementally assembles frames from bytes that arrive one at a time.
+///
+/// This is the streaming counterpart to `Frame::read` for sources that don't implement `Read`, such as
+/// interrupt-driven UART callbacks, and is available without the `std` feature. Bytes are buffered until either a complete frame (according to its
+/// declared length) or a `\n` has been received, at which point the buffered bytes are parsed. Line endings
+/// between frames are skipped, and the buffer is discarded with an error if it grows larger than any valid
+/// frame could be.
+///
+/// # Examples
+///
+/// ```
+/// use flipdot_core::{Address, FrameAssembler};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// #
+/// let mut assembler = FrameAssembler::new();
+/// let mut frames = Vec::new();
+/// for &byte in b":02000201031FD9\r\n:01007F02FF7F\r\n" {
+///     if let Some(result) = assembler.push(byte) {
+///         frames.push(result?);
+///     }
+/// }
+/// assert_eq!(vec![Address(2), Address(0x7F)], frames.iter().map(|frame| frame.address()).collect::<Vec<_>>());
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct FrameAssembler {
+    buffer: Vec<u8>,
+}
+
+impl FrameAssembler {
+    /// Length of the longest possible frame, excluding the trailing carriage return/linefeed.
+    const MAX_FRAME_LEN: usize = 11 + 2 * 0xFF;
+
+    /// Creates a new `FrameAssembler` with an empty buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::FrameAssembler;
+    /// let assembler = FrameAssembler::new();
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a byte to the buffer, returning the parsed frame once one is complete.
+    ///
+    /// Returns `None` while more bytes are needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`Frame::from_bytes`] if the buffered bytes do not form a valid frame, or
+    /// [`FrameError::InvalidFrame`] if more bytes than the longest possible frame are received without a newline.
+    /// The buffer is cleared in either case, so the assembler can resynchronize with the following frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Data, Frame, FrameAssembler, MsgType};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let mut assembler = FrameAssembler::new();
+    /// for &byte in b":01007F02FF7" {
+    ///     assert!(assembler.push(byte).is_none());
+    /// }
+    /// let frame = assembler.push(b'F').unwrap()?;
+    /// assert_eq!(Frame::new(Address(0x7F), MsgType(2), Data::from(&[0xFF])), frame);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn push(&mut self, byte: u8) -> Option<Result<Frame<'static>, FrameError>> {
+        if self.buffer.is_empty() && (byte == b'\r' || byte == b'\n') {
+            return None;
+        }
+
+        self.buffer.push(byte);
+        let complete = Frame::declared_len(&self.buffer).is_some_and(|len| self.buffer.len() == 11 + 2 * usize::from(len));
+        if byte == b'\n' || complete {
+            let result = Frame::from_bytes(&self.buffer);
+            self.buffer.clear();
+            Some(result)
+        } else if self.buffer.len() > Self::MAX_FRAME_LEN {
+            let data = core::mem::take(&mut self.buffer);
+            Some(Err(FrameError::InvalidFrame { data }))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the bytes buffered so far for an incomplete frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::FrameAssembler;
+    /// let mut assembler = FrameAssembler::new();
+    /// let _ = assembler.push(b':');
+    /// assert_eq!(b":", assembler.pending());
+    /// ```
+    pub fn pending(&self) -> &[u8] {
+        &self.buffer
+    }
+}
+
+/// Validates the Intel HEX wire format, storing the decoded data bytes in `data` and returning the address and message type.
 ///
-/// Assumes that the data has already been validated and panics if it is invalid.
-fn parse_hex<T: Num>(bytes: &[u8]) -> T
-where
-    <T as Num>::FromStrRadixErr: 'static + ::std::error::Error,
-{
-    // Regex already determined these are valid hex digits, so we can just unwrap.
-    let string = str::from_utf8(bytes).unwrap();
-    T::from_str_radix(string, 16).unwrap()
+/// `data` is scratch space that can be reused across calls to avoid allocation.
+fn parse_frame(bytes: &[u8], data: &mut Vec<u8>) -> Result<(Address, MsgType), FrameError> {
+    let invalid = || FrameError::InvalidFrame { data: bytes.into() };
+
+    // A colon marks the beginning of the frame, optionally followed by a newline sequence at the end.
+    let line = bytes.strip_suffix(b"\r\n").unwrap_or(bytes);
+    let digits = line.strip_prefix(b":").ok_or_else(invalid)?;
+
+    // The data length, address, message type, and checksum take 5 bytes; the data fills the rest.
+    if digits.len() < 10 || digits.len() % 2 != 0 {
+        return Err(invalid());
+    }
+    data.clear();
+    for pair in digits.chunks(2) {
+        let high = hex_digit(pair[0]).ok_or_else(invalid)?;
+        let low = hex_digit(pair[1]).ok_or_else(invalid)?;
+        data.push(high << 4 | low);
+    }
+
+    // Checked the length above, so safe to unwrap.
+    let provided_checksum = data.pop().unwrap();
+    let header = [data[0], data[1], data[2], data[3]];
+    let _ = data.drain(..header.len());
+    let data_len = header[0];
+    let address = u16::from_be_bytes([header[1], header[2]]);
+    let message_type = header[3];
+
+    if data.len() != data_len as usize {
+        return Err(FrameError::FrameDataMismatch {
+            data: bytes.into(),
+            expected: data_len as usize,
+            actual: data.len(),
+        });
+    }
+
+    let computed_checksum = data.iter().fold(checksum(&header), |acc, &b| acc.wrapping_sub(b));
+    if computed_checksum != provided_checksum {
+        return Err(FrameError::BadChecksum {
+            data: bytes.into(),
+            expected: provided_checksum,
+            actual: computed_checksum,
+        });
+    }
+
+    Ok((Address(address), MsgType(message_type)))
+}
+
+/// Converts a single ASCII hex digit to its value, or `None` if it is not a hex digit.
+fn hex_digit(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|digit| digit as u8)
 }
 
 /// Formats a supposed Intel HEX byte string for display as part of an error message.
@@ -576,6 +901,33 @@ impl<'a> Data<'a> {
         Ok(Data(data))
     }
 
+    /// Creates a new `Data` borrowing a fixed-size array, without allocating.
+    ///
+    /// Since the array length is known at compile time, the 255-byte limit is checked during
+    /// compilation rather than at runtime, so this cannot fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::borrow::Cow;
+    /// # use flipdot_core::Data;
+    /// let bytes = [1, 2, 3, 4, 5, 6];
+    /// let data = Data::from_array(&bytes);
+    /// assert!(matches!(data.get(), Cow::Borrowed(_)));
+    /// assert_eq!(&bytes[..], data.get().as_ref());
+    /// ```
+    ///
+    /// This will fail to compile since the array is too large:
+    ///
+    /// ```compile_fail
+    /// # use flipdot_core::Data;
+    /// let data = Data::from_array(&[0; 256]);
+    /// ```
+    pub const fn from_array<const N: usize>(data: &'a [u8; N]) -> Self {
+        const { assert!(N <= 0xFF, "Data length cannot exceed 255 bytes") };
+        Data(Cow::Borrowed(data))
+    }
+
     /// Returns a reference to the inner [`Cow`]`<[u8]>`.
     ///
     /// # Examples
@@ -600,7 +952,7 @@ impl<'a> Data<'a> {
 macro_rules! impl_from_array_ref_with_length {
     ($length:expr) => {
         impl From<&'static [u8; $length]> for Data<'_> {
-            fn from(value: &'static [u8; $length]) -> Data<'_> {
+            fn from(value: &'static [u8; $length]) -> Data<'static> {
                 Data::try_new(&value[..]).unwrap()
             }
         }
@@ -618,6 +970,122 @@ mod tests {
     use super::*;
     use std::error::Error;
 
+    #[test]
+    fn frame_parser_reuse() -> Result<(), Box<dyn Error>> {
+        let frames = [
+            Frame::new(Address(0x7F), MsgType(0x02), Data::from(&[0xFF])),
+            Frame::new(Address(0x1234), MsgType(0x00), Data::try_new(vec![0xAB; 32])?),
+            Frame::new(Address(0x01), MsgType(0x06), Data::try_new(vec![])?),
+        ];
+
+        let mut parser = FrameParser::new();
+        for frame in &frames {
+            assert_eq!(*frame, parser.parse(&frame.to_bytes_with_newline())?);
+            assert_eq!(*frame, parser.parse(&frame.to_bytes())?);
+            assert_eq!(Frame::from_bytes(&frame.to_bytes())?, parser.parse(&frame.to_bytes())?);
+        }
+
+        assert!(matches!(parser.parse(b":01"), Err(FrameError::InvalidFrame { .. })));
+        assert!(matches!(
+            parser.parse(b":01007F027E"),
+            Err(FrameError::FrameDataMismatch { .. })
+        ));
+        assert!(matches!(parser.parse(b":01007F02FF7E"), Err(FrameError::BadChecksum { .. })));
+
+        // Still usable after errors.
+        assert_eq!(frames[0], parser.parse(b":01007F02FF7F")?);
+        Ok(())
+    }
+
+    #[test]
+    fn frame_assembler_streams() -> Result<(), Box<dyn Error>> {
+        let frames = [
+            Frame::new(Address(0x7F), MsgType(0x02), Data::from(&[0xFF])),
+            Frame::new(Address(0x1234), MsgType(0x00), Data::try_new(vec![0xAB; 255])?),
+            Frame::new(Address(0x01), MsgType(0x06), Data::try_new(vec![])?),
+        ];
+
+        let mut stream = Vec::new();
+        for frame in &frames {
+            stream.extend(frame.to_bytes_with_newline());
+        }
+        stream.extend(frames[0].to_bytes()); // No trailing newline
+
+        let mut assembler = FrameAssembler::new();
+        let mut assembled = Vec::new();
+        for byte in stream {
+            if let Some(result) = assembler.push(byte) {
+                assembled.push(result?);
+            }
+        }
+        assert_eq!(&frames[..], &assembled[..3]);
+        assert_eq!(frames[0], assembled[3]);
+        assert!(assembler.pending().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn frame_assembler_errors() -> Result<(), Box<dyn Error>> {
+        let mut assembler = FrameAssembler::new();
+
+        // Garbage is reported at the end of the line.
+        let results: Vec<_> = b"junk\r\n".iter().filter_map(|&byte| assembler.push(byte)).collect();
+        assert_eq!(1, results.len());
+        assert!(matches!(results[0], Err(FrameError::InvalidFrame { .. })));
+
+        // Bad checksum is reported as soon as the declared length is reached.
+        let results: Vec<_> = b":01007F02FF7E".iter().filter_map(|&byte| assembler.push(byte)).collect();
+        assert!(matches!(results[..], [Err(FrameError::BadChecksum { .. })]));
+
+        // Runaway input without a newline is discarded.
+        let results: Vec<_> = [b'x'; 600].iter().filter_map(|&byte| assembler.push(byte)).collect();
+        assert!(matches!(results[..], [Err(FrameError::InvalidFrame { .. })]));
+
+        // Still usable after errors.
+        assembler = FrameAssembler::new();
+        let results: Vec<_> = b":01007F02FF7F\r\n".iter().filter_map(|&byte| assembler.push(byte)).collect();
+        assert_eq!(1, results.len());
+        assert_eq!(
+            Frame::new(Address(0x7F), MsgType(0x02), Data::from(&[0xFF])),
+            *results[0].as_ref().unwrap()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn declared_len_matches_frames() -> Result<(), Box<dyn Error>> {
+        for len in [0, 1, 0x1F, 0xA0, 0xFF] {
+            let frame = Frame::new(Address(0x7F), MsgType(0x02), Data::try_new(vec![0; len])?);
+            let bytes = frame.to_bytes();
+            assert_eq!(Some(len as u8), Frame::declared_len(&bytes));
+            assert_eq!(11 + 2 * len, bytes.len());
+        }
+
+        assert_eq!(Some(0xAB), Frame::declared_len(b":ab"));
+        assert_eq!(None, Frame::declared_len(b""));
+        assert_eq!(None, Frame::declared_len(b":"));
+        assert_eq!(None, Frame::declared_len(b":G0"));
+        assert_eq!(None, Frame::declared_len(b" :10"));
+        Ok(())
+    }
+
+    #[test]
+    fn byte_diff_different_lengths() {
+        let short = Frame::new(Address(0x7F), MsgType(0x02), Data::from(&[0xFF])); // :01007F02FF7F
+        let long = Frame::new(Address(0x7F), MsgType(0x02), Data::from(&[0xFF, 0x00])); // :02007F02FF007F
+        assert_eq!(
+            vec![
+                (2, b'1', b'2'),
+                (11, b'7', b'0'),
+                (12, b'F', b'0'),
+                (13, 0, b'7'),
+                (14, 0, b'E'),
+            ],
+            short.byte_diff(&long)
+        );
+        assert_eq!(short.byte_diff(&long).len(), long.byte_diff(&short).len());
+    }
+
     #[test]
     fn roundtrip_simple_frame() -> Result<(), Box<dyn Error>> {
         let frame = Frame::new(Address(0x7F), MsgType(0x02), Data::from(&[0xFF]));
@@ -696,6 +1164,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn lowercase_accepted() -> Result<(), Box<dyn Error>> {
+        let decoded = Frame::from_bytes(b":02000201031fd9")?;
+        assert_eq!(Frame::new(Address(2), MsgType(1), Data::from(&[3, 31])), decoded);
+        Ok(())
+    }
+
+    #[test]
+    fn bare_linefeed_rejected() {
+        let error = Frame::from_bytes(b":01007F02FF7F\n").unwrap_err();
+        assert!(matches!(error, FrameError::InvalidFrame { .. }));
+    }
+
     #[test]
     fn bad_checksum_detected() {
         let error = Frame::from_bytes(b":01007F02FF7E").unwrap_err();
@@ -806,6 +1287,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn normalize_hex() -> Result<(), Box<dyn Error>> {
+        assert_eq!(":01007F02FF7F", Frame::normalize_hex(":01007f02ff7f")?);
+        assert_eq!(":00000201FD", Frame::normalize_hex("\t:00000201FD\n")?);
+        assert!(matches!(
+            Frame::normalize_hex(":01007F02FF7E"),
+            Err(FrameError::BadChecksum { .. })
+        ));
+        assert!(matches!(
+            Frame::normalize_hex("01007F02FF7F"),
+            Err(FrameError::InvalidFrame { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn read_into_reuses_buffer() -> Result<(), Box<dyn Error>> {
+        let mut reader = &b":01007F02FF7F\r\n:00000201FD"[..];
+        let mut buf = Vec::with_capacity(64);
+        let capacity = buf.capacity();
+
+        let frame = Frame::read_into(&mut reader, &mut buf)?;
+        assert_eq!(Frame::new(Address(0x7F), MsgType(0x02), Data::from(&[0xFF])), frame);
+        assert_eq!(b":01007F02FF7F\r\n", &buf[..]);
+
+        // The final frame has no newline, so reading stops at the end of the data.
+        let frame = Frame::read_into(&mut reader, &mut buf)?;
+        assert_eq!(Frame::new(Address(2), MsgType(0x01), Data::from(&[])), frame);
+        assert_eq!(b":00000201FD", &buf[..]);
+        assert_eq!(capacity, buf.capacity());
+
+        assert!(Frame::read_into(&mut reader, &mut buf).is_err());
+        assert!(buf.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn display() {
         let frame = Frame::new(Address(0x7F), MsgType(0x02), Data::from(&[0xFF, 0xCB]));
@@ -38,8 +38,16 @@
 //! # Ok(()) }
 //! ```
 //!
+//! # Features
+//!
+//! The `std` feature is enabled by default. Disabling it makes this crate `no_std` (`alloc` is still required)
+//! so that frames, messages, and pages can be built and parsed on embedded targets. Without `std`, frame I/O
+//! (`Frame::read`, `Frame::read_into`, `Frame::write`, and `FrameParser`), the `formats` module,
+//! `PageCache`, `Page::fingerprint`, and `Page::write_frames` are unavailable.
+//!
 //! [`flipdot`]: https://docs.rs/flipdot
 #![doc(html_root_url = "https://docs.rs/flipdot-core/0.7.1")]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(
     missing_copy_implementations,
     missing_debug_implementations,
@@ -55,14 +63,34 @@
     unused_results
 )]
 
+extern crate alloc;
+
+#[cfg(feature = "async")]
+mod async_sign_bus;
+mod font;
+#[cfg(feature = "std")]
+pub mod formats;
 mod frame;
 mod message;
 mod page;
+#[cfg(feature = "std")]
+mod page_cache;
 mod sign_bus;
 mod sign_type;
+mod tee_sign_bus;
 
-pub use self::frame::{Address, Data, Frame, FrameError, MsgType};
-pub use self::message::{ChunkCount, Message, Offset, Operation, State};
-pub use self::page::{Page, PageError, PageFlipStyle, PageId};
+#[cfg(feature = "async")]
+pub use self::async_sign_bus::AsyncSignBus;
+pub use self::font::Font;
+#[cfg(feature = "std")]
+pub use self::formats::FormatError;
+#[cfg(feature = "std")]
+pub use self::frame::FrameParser;
+pub use self::frame::{Address, Data, Frame, FrameAssembler, FrameError, MsgType};
+pub use self::message::{ChunkCount, Message, MessageKind, Offset, Operation, State};
+pub use self::page::{BlitMode, Page, PageBuilder, PageError, PageFlipStyle, PageHeader, PageId};
+#[cfg(feature = "std")]
+pub use self::page_cache::PageCache;
 pub use self::sign_bus::SignBus;
-pub use self::sign_type::{SignType, SignTypeError};
+pub use self::sign_type::{ConfigRelation, PanelGroup, PanelLayout, SignType, SignTypeError};
+pub use self::tee_sign_bus::{TeeSignBus, TeeSignBusError};
@@ -1,4 +1,6 @@
-use std::fmt::{self, Display, Formatter};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
 
 use derive_more::{Display, LowerHex, UpperHex};
 
@@ -79,6 +81,44 @@ pub enum Message<'a> {
     Unknown(Frame<'a>),
 }
 
+/// The kind of a [`Message`], without its payload.
+///
+/// Useful for categorizing messages (e.g. for counters or dispatch tables) without
+/// binding their contents. Obtained via [`Message::kind`].
+///
+/// # Examples
+///
+/// ```
+/// use flipdot_core::{Address, Message, MessageKind};
+///
+/// let message = Message::Hello(Address(3));
+/// assert_eq!(MessageKind::Hello, message.kind());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum MessageKind {
+    /// A [`SendData`](Message::SendData) message.
+    SendData,
+    /// A [`DataChunksSent`](Message::DataChunksSent) message.
+    DataChunksSent,
+    /// A [`Hello`](Message::Hello) message.
+    Hello,
+    /// A [`QueryState`](Message::QueryState) message.
+    QueryState,
+    /// A [`ReportState`](Message::ReportState) message.
+    ReportState,
+    /// A [`RequestOperation`](Message::RequestOperation) message.
+    RequestOperation,
+    /// An [`AckOperation`](Message::AckOperation) message.
+    AckOperation,
+    /// A [`PixelsComplete`](Message::PixelsComplete) message.
+    PixelsComplete,
+    /// A [`Goodbye`](Message::Goodbye) message.
+    Goodbye,
+    /// An [`Unknown`](Message::Unknown) message.
+    Unknown,
+}
+
 /// The memory offset for data sent via a [`SendData`](Message::SendData) message.
 ///
 /// # Examples
@@ -146,6 +186,10 @@ pub enum State {
     ShowingPages,
     /// Sign is ready to reset back to the `Unconfigured` state.
     ReadyToReset,
+    /// A state code not recognized by this library, containing the raw byte reported by the sign.
+    ///
+    /// Preserved so that undocumented states seen on real hardware can be identified.
+    Other(u8),
 }
 
 /// Operations that can be requested of a sign, which trigger actions and/or state changes.
@@ -168,6 +212,135 @@ pub enum Operation {
     FinishReset,
 }
 
+impl Message<'_> {
+    /// Returns whether a sign is expected to send a response to this message.
+    ///
+    /// A sign only replies to messages that query its state or request that it perform an operation.
+    /// [`SignBus`](crate::SignBus) implementations can use this to decide whether to wait for a reply.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, ChunkCount, Message, Operation};
+    /// assert!(Message::QueryState(Address(3)).expects_response());
+    /// assert!(Message::RequestOperation(Address(3), Operation::ReceiveConfig).expects_response());
+    /// assert!(!Message::DataChunksSent(ChunkCount(2)).expects_response());
+    /// ```
+    pub fn expects_response(&self) -> bool {
+        matches!(
+            *self,
+            Message::Hello(_) | Message::QueryState(_) | Message::RequestOperation(_, _)
+        )
+    }
+
+    /// Returns the kind of this message, without its payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, ChunkCount, Message, MessageKind};
+    /// assert_eq!(MessageKind::Goodbye, Message::Goodbye(Address(3)).kind());
+    /// assert_eq!(MessageKind::DataChunksSent, Message::DataChunksSent(ChunkCount(2)).kind());
+    /// ```
+    pub fn kind(&self) -> MessageKind {
+        match *self {
+            Message::SendData(_, _) => MessageKind::SendData,
+            Message::DataChunksSent(_) => MessageKind::DataChunksSent,
+            Message::Hello(_) => MessageKind::Hello,
+            Message::QueryState(_) => MessageKind::QueryState,
+            Message::ReportState(_, _) => MessageKind::ReportState,
+            Message::RequestOperation(_, _) => MessageKind::RequestOperation,
+            Message::AckOperation(_, _) => MessageKind::AckOperation,
+            Message::PixelsComplete(_) => MessageKind::PixelsComplete,
+            Message::Goodbye(_) => MessageKind::Goodbye,
+            Message::Unknown(_) => MessageKind::Unknown,
+        }
+    }
+}
+
+impl<'a> Message<'a> {
+    /// The number of bytes sent in each [`SendData`](Message::SendData) message by real ODKs.
+    pub const DEFAULT_CHUNK_SIZE: u8 = 16;
+
+    /// Returns the messages that transfer `data` to a sign in chunks of at most `chunk_size` bytes.
+    ///
+    /// Each item in `data` (a page of pixels, for instance) is split into [`SendData`](Message::SendData)
+    /// messages with offsets starting over at zero, and the last message is a [`DataChunksSent`](Message::DataChunksSent)
+    /// with the total number of chunks. This is what a sign expects after acknowledging a
+    /// [`ReceiveConfig`](Operation::ReceiveConfig) or [`ReceivePixels`](Operation::ReceivePixels) request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{ChunkCount, Data, Message, Offset};
+    /// let data = [1, 2, 3, 4, 5, 6];
+    /// let messages = Message::data_transfer(&[&data[..]], 4);
+    /// assert_eq!(
+    ///     vec![
+    ///         Message::SendData(Offset(0), Data::from(&[1, 2, 3, 4])),
+    ///         Message::SendData(Offset(4), Data::from(&[5, 6])),
+    ///         Message::DataChunksSent(ChunkCount(2)),
+    ///     ],
+    ///     messages
+    /// );
+    /// ```
+    pub fn data_transfer(data: &[&'a [u8]], chunk_size: u8) -> Vec<Message<'a>> {
+        assert!(chunk_size > 0, "Chunk size must be at least 1 byte");
+        let chunk_size = usize::from(chunk_size);
+
+        let mut messages: Vec<Message<'a>> = data
+            .iter()
+            .flat_map(|item| {
+                item.chunks(chunk_size).enumerate().map(move |(i, chunk)| {
+                    // Chunks are at most 255 bytes since chunk_size is a u8, so this cannot fail.
+                    Message::SendData(Offset((i * chunk_size) as u16), Data::try_new(chunk).unwrap())
+                })
+            })
+            .collect();
+        messages.push(Message::DataChunksSent(ChunkCount(messages.len() as u16)));
+        messages
+    }
+}
+
+impl State {
+    /// Returns the [`ReportState`](Message::ReportState) message a sign at `address` sends to report this state.
+    ///
+    /// Useful for [`SignBus`](crate::SignBus) implementations and for checking responses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message, State};
+    /// let message = State::PageLoaded.expected_report(Address(3));
+    /// assert_eq!(Message::ReportState(Address(3), State::PageLoaded), message);
+    /// ```
+    pub fn expected_report(self, address: Address) -> Message<'static> {
+        Message::ReportState(address, self)
+    }
+}
+
+impl Operation {
+    /// Returns the [`AckOperation`](Message::AckOperation) message a sign at `address` should reply with
+    /// when this operation is requested.
+    ///
+    /// Useful for [`SignBus`](crate::SignBus) implementations and for checking responses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message, Operation};
+    /// let message = Operation::ReceiveConfig.expected_ack(Address(3));
+    /// assert_eq!(Message::AckOperation(Address(3), Operation::ReceiveConfig), message);
+    /// ```
+    pub fn expected_ack(self, address: Address) -> Message<'static> {
+        Message::AckOperation(address, self)
+    }
+}
+
 impl Display for Message<'_> {
     /// Provides a human-readable view of the message.
     ///
@@ -222,50 +395,51 @@ impl<'a> From<Frame<'a>> for Message<'a> {
     fn from(frame: Frame<'a>) -> Self {
         match frame.data().len() {
             0 => match frame.message_type() {
-                MsgType(1) => Message::DataChunksSent(ChunkCount(frame.address().0)),
+                MsgType::DATA_CHUNKS_SENT => Message::DataChunksSent(ChunkCount(frame.address().0)),
                 _ => Message::Unknown(frame),
             },
 
             1 => match (frame.message_type(), frame.data()[0]) {
-                (MsgType(2), 0xFF) => Message::Hello(frame.address()),
-                (MsgType(2), 0x00) => Message::QueryState(frame.address()),
-                (MsgType(2), 0x55) => Message::Goodbye(frame.address()),
-
-                (MsgType(4), 0x0F) => Message::ReportState(frame.address(), State::Unconfigured),
-                (MsgType(4), 0x0D) => Message::ReportState(frame.address(), State::ConfigInProgress),
-                (MsgType(4), 0x07) => Message::ReportState(frame.address(), State::ConfigReceived),
-                (MsgType(4), 0x0C) => Message::ReportState(frame.address(), State::ConfigFailed),
-                (MsgType(4), 0x03) => Message::ReportState(frame.address(), State::PixelsInProgress),
-                (MsgType(4), 0x01) => Message::ReportState(frame.address(), State::PixelsReceived),
-                (MsgType(4), 0x0B) => Message::ReportState(frame.address(), State::PixelsFailed),
-                (MsgType(4), 0x10) => Message::ReportState(frame.address(), State::PageLoaded),
-                (MsgType(4), 0x13) => Message::ReportState(frame.address(), State::PageLoadInProgress),
-                (MsgType(4), 0x12) => Message::ReportState(frame.address(), State::PageShown),
-                (MsgType(4), 0x11) => Message::ReportState(frame.address(), State::PageShowInProgress),
-                (MsgType(4), 0x00) => Message::ReportState(frame.address(), State::ShowingPages),
-                (MsgType(4), 0x08) => Message::ReportState(frame.address(), State::ReadyToReset),
-
-                (MsgType(3), 0xA1) => Message::RequestOperation(frame.address(), Operation::ReceiveConfig),
-                (MsgType(3), 0xA2) => Message::RequestOperation(frame.address(), Operation::ReceivePixels),
-                (MsgType(3), 0xA9) => Message::RequestOperation(frame.address(), Operation::ShowLoadedPage),
-                (MsgType(3), 0xAA) => Message::RequestOperation(frame.address(), Operation::LoadNextPage),
-                (MsgType(3), 0xA6) => Message::RequestOperation(frame.address(), Operation::StartReset),
-                (MsgType(3), 0xA7) => Message::RequestOperation(frame.address(), Operation::FinishReset),
-
-                (MsgType(5), 0x95) => Message::AckOperation(frame.address(), Operation::ReceiveConfig),
-                (MsgType(5), 0x91) => Message::AckOperation(frame.address(), Operation::ReceivePixels),
-                (MsgType(5), 0x96) => Message::AckOperation(frame.address(), Operation::ShowLoadedPage),
-                (MsgType(5), 0x97) => Message::AckOperation(frame.address(), Operation::LoadNextPage),
-                (MsgType(5), 0x93) => Message::AckOperation(frame.address(), Operation::StartReset),
-                (MsgType(5), 0x94) => Message::AckOperation(frame.address(), Operation::FinishReset),
-
-                (MsgType(6), 0x00) => Message::PixelsComplete(frame.address()),
+                (MsgType::CONTROL, 0xFF) => Message::Hello(frame.address()),
+                (MsgType::CONTROL, 0x00) => Message::QueryState(frame.address()),
+                (MsgType::CONTROL, 0x55) => Message::Goodbye(frame.address()),
+
+                (MsgType::REPORT_STATE, 0x0F) => Message::ReportState(frame.address(), State::Unconfigured),
+                (MsgType::REPORT_STATE, 0x0D) => Message::ReportState(frame.address(), State::ConfigInProgress),
+                (MsgType::REPORT_STATE, 0x07) => Message::ReportState(frame.address(), State::ConfigReceived),
+                (MsgType::REPORT_STATE, 0x0C) => Message::ReportState(frame.address(), State::ConfigFailed),
+                (MsgType::REPORT_STATE, 0x03) => Message::ReportState(frame.address(), State::PixelsInProgress),
+                (MsgType::REPORT_STATE, 0x01) => Message::ReportState(frame.address(), State::PixelsReceived),
+                (MsgType::REPORT_STATE, 0x0B) => Message::ReportState(frame.address(), State::PixelsFailed),
+                (MsgType::REPORT_STATE, 0x10) => Message::ReportState(frame.address(), State::PageLoaded),
+                (MsgType::REPORT_STATE, 0x13) => Message::ReportState(frame.address(), State::PageLoadInProgress),
+                (MsgType::REPORT_STATE, 0x12) => Message::ReportState(frame.address(), State::PageShown),
+                (MsgType::REPORT_STATE, 0x11) => Message::ReportState(frame.address(), State::PageShowInProgress),
+                (MsgType::REPORT_STATE, 0x00) => Message::ReportState(frame.address(), State::ShowingPages),
+                (MsgType::REPORT_STATE, 0x08) => Message::ReportState(frame.address(), State::ReadyToReset),
+                (MsgType::REPORT_STATE, other) => Message::ReportState(frame.address(), State::Other(other)),
+
+                (MsgType::REQUEST_OPERATION, 0xA1) => Message::RequestOperation(frame.address(), Operation::ReceiveConfig),
+                (MsgType::REQUEST_OPERATION, 0xA2) => Message::RequestOperation(frame.address(), Operation::ReceivePixels),
+                (MsgType::REQUEST_OPERATION, 0xA9) => Message::RequestOperation(frame.address(), Operation::ShowLoadedPage),
+                (MsgType::REQUEST_OPERATION, 0xAA) => Message::RequestOperation(frame.address(), Operation::LoadNextPage),
+                (MsgType::REQUEST_OPERATION, 0xA6) => Message::RequestOperation(frame.address(), Operation::StartReset),
+                (MsgType::REQUEST_OPERATION, 0xA7) => Message::RequestOperation(frame.address(), Operation::FinishReset),
+
+                (MsgType::ACK_OPERATION, 0x95) => Message::AckOperation(frame.address(), Operation::ReceiveConfig),
+                (MsgType::ACK_OPERATION, 0x91) => Message::AckOperation(frame.address(), Operation::ReceivePixels),
+                (MsgType::ACK_OPERATION, 0x96) => Message::AckOperation(frame.address(), Operation::ShowLoadedPage),
+                (MsgType::ACK_OPERATION, 0x97) => Message::AckOperation(frame.address(), Operation::LoadNextPage),
+                (MsgType::ACK_OPERATION, 0x93) => Message::AckOperation(frame.address(), Operation::StartReset),
+                (MsgType::ACK_OPERATION, 0x94) => Message::AckOperation(frame.address(), Operation::FinishReset),
+
+                (MsgType::PIXELS_COMPLETE, 0x00) => Message::PixelsComplete(frame.address()),
 
                 (_, _) => Message::Unknown(frame),
             },
 
             _ => match frame.message_type() {
-                MsgType(0) => Message::SendData(Offset(frame.address().0), frame.into_data()),
+                MsgType::SEND_DATA => Message::SendData(Offset(frame.address().0), frame.into_data()),
                 _ => Message::Unknown(frame),
             },
         }
@@ -286,49 +460,91 @@ impl<'a> From<Message<'a>> for Frame<'a> {
     /// #
     /// let message = Message::ReportState(Address(0xFF), State::ConfigReceived);
     /// let frame = Frame::from(message);
-    /// assert_eq!(Frame::new(Address(0xFF), MsgType(4), Data::try_new(vec![0x07])?), frame);
+    /// assert_eq!(Frame::new(Address(0xFF), MsgType::REPORT_STATE, Data::try_new(vec![0x07])?), frame);
     /// #
     /// # Ok(()) }
     /// ```
     fn from(message: Message<'a>) -> Self {
         match message {
-            Message::SendData(Offset(offset), data) => Frame::new(Address(offset), MsgType(0), data),
-
-            Message::DataChunksSent(ChunkCount(chunks)) => Frame::new(Address(chunks), MsgType(1), Data::from(&[])),
-
-            Message::Hello(address) => Frame::new(address, MsgType(2), Data::from(&[0xFF])),
-            Message::Goodbye(address) => Frame::new(address, MsgType(2), Data::from(&[0x55])),
-            Message::QueryState(address) => Frame::new(address, MsgType(2), Data::from(&[0x00])),
-
-            Message::ReportState(address, State::Unconfigured) => Frame::new(address, MsgType(4), Data::from(&[0x0F])),
-            Message::ReportState(address, State::ConfigInProgress) => Frame::new(address, MsgType(4), Data::from(&[0x0D])),
-            Message::ReportState(address, State::ConfigReceived) => Frame::new(address, MsgType(4), Data::from(&[0x07])),
-            Message::ReportState(address, State::ConfigFailed) => Frame::new(address, MsgType(4), Data::from(&[0x0C])),
-            Message::ReportState(address, State::PixelsInProgress) => Frame::new(address, MsgType(4), Data::from(&[0x03])),
-            Message::ReportState(address, State::PixelsReceived) => Frame::new(address, MsgType(4), Data::from(&[0x01])),
-            Message::ReportState(address, State::PixelsFailed) => Frame::new(address, MsgType(4), Data::from(&[0x0B])),
-            Message::ReportState(address, State::PageLoaded) => Frame::new(address, MsgType(4), Data::from(&[0x10])),
-            Message::ReportState(address, State::PageLoadInProgress) => Frame::new(address, MsgType(4), Data::from(&[0x13])),
-            Message::ReportState(address, State::PageShown) => Frame::new(address, MsgType(4), Data::from(&[0x12])),
-            Message::ReportState(address, State::PageShowInProgress) => Frame::new(address, MsgType(4), Data::from(&[0x11])),
-            Message::ReportState(address, State::ShowingPages) => Frame::new(address, MsgType(4), Data::from(&[0x00])),
-            Message::ReportState(address, State::ReadyToReset) => Frame::new(address, MsgType(4), Data::from(&[0x08])),
-
-            Message::RequestOperation(address, Operation::ReceiveConfig) => Frame::new(address, MsgType(3), Data::from(&[0xA1])),
-            Message::RequestOperation(address, Operation::ReceivePixels) => Frame::new(address, MsgType(3), Data::from(&[0xA2])),
-            Message::RequestOperation(address, Operation::ShowLoadedPage) => Frame::new(address, MsgType(3), Data::from(&[0xA9])),
-            Message::RequestOperation(address, Operation::LoadNextPage) => Frame::new(address, MsgType(3), Data::from(&[0xAA])),
-            Message::RequestOperation(address, Operation::StartReset) => Frame::new(address, MsgType(3), Data::from(&[0xA6])),
-            Message::RequestOperation(address, Operation::FinishReset) => Frame::new(address, MsgType(3), Data::from(&[0xA7])),
-
-            Message::AckOperation(address, Operation::ReceiveConfig) => Frame::new(address, MsgType(5), Data::from(&[0x95])),
-            Message::AckOperation(address, Operation::ReceivePixels) => Frame::new(address, MsgType(5), Data::from(&[0x91])),
-            Message::AckOperation(address, Operation::ShowLoadedPage) => Frame::new(address, MsgType(5), Data::from(&[0x96])),
-            Message::AckOperation(address, Operation::LoadNextPage) => Frame::new(address, MsgType(5), Data::from(&[0x97])),
-            Message::AckOperation(address, Operation::StartReset) => Frame::new(address, MsgType(5), Data::from(&[0x93])),
-            Message::AckOperation(address, Operation::FinishReset) => Frame::new(address, MsgType(5), Data::from(&[0x94])),
-
-            Message::PixelsComplete(address) => Frame::new(address, MsgType(6), Data::from(&[0x00])),
+            Message::SendData(Offset(offset), data) => Frame::new(Address(offset), MsgType::SEND_DATA, data),
+
+            Message::DataChunksSent(ChunkCount(chunks)) => {
+                Frame::new(Address(chunks), MsgType::DATA_CHUNKS_SENT, Data::from(&[]))
+            }
+
+            Message::Hello(address) => Frame::new(address, MsgType::CONTROL, Data::from(&[0xFF])),
+            Message::Goodbye(address) => Frame::new(address, MsgType::CONTROL, Data::from(&[0x55])),
+            Message::QueryState(address) => Frame::new(address, MsgType::CONTROL, Data::from(&[0x00])),
+
+            Message::ReportState(address, State::Unconfigured) => Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x0F])),
+            Message::ReportState(address, State::ConfigInProgress) => {
+                Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x0D]))
+            }
+            Message::ReportState(address, State::ConfigReceived) => {
+                Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x07]))
+            }
+            Message::ReportState(address, State::ConfigFailed) => Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x0C])),
+            Message::ReportState(address, State::PixelsInProgress) => {
+                Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x03]))
+            }
+            Message::ReportState(address, State::PixelsReceived) => {
+                Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x01]))
+            }
+            Message::ReportState(address, State::PixelsFailed) => Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x0B])),
+            Message::ReportState(address, State::PageLoaded) => Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x10])),
+            Message::ReportState(address, State::PageLoadInProgress) => {
+                Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x13]))
+            }
+            Message::ReportState(address, State::PageShown) => Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x12])),
+            Message::ReportState(address, State::PageShowInProgress) => {
+                Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x11]))
+            }
+            Message::ReportState(address, State::ShowingPages) => Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x00])),
+            Message::ReportState(address, State::ReadyToReset) => Frame::new(address, MsgType::REPORT_STATE, Data::from(&[0x08])),
+            Message::ReportState(address, State::Other(state)) => {
+                // Safe to unwrap as a single byte is always within the data length limit.
+                Frame::new(address, MsgType::REPORT_STATE, Data::try_new(vec![state]).unwrap())
+            }
+
+            Message::RequestOperation(address, Operation::ReceiveConfig) => {
+                Frame::new(address, MsgType::REQUEST_OPERATION, Data::from(&[0xA1]))
+            }
+            Message::RequestOperation(address, Operation::ReceivePixels) => {
+                Frame::new(address, MsgType::REQUEST_OPERATION, Data::from(&[0xA2]))
+            }
+            Message::RequestOperation(address, Operation::ShowLoadedPage) => {
+                Frame::new(address, MsgType::REQUEST_OPERATION, Data::from(&[0xA9]))
+            }
+            Message::RequestOperation(address, Operation::LoadNextPage) => {
+                Frame::new(address, MsgType::REQUEST_OPERATION, Data::from(&[0xAA]))
+            }
+            Message::RequestOperation(address, Operation::StartReset) => {
+                Frame::new(address, MsgType::REQUEST_OPERATION, Data::from(&[0xA6]))
+            }
+            Message::RequestOperation(address, Operation::FinishReset) => {
+                Frame::new(address, MsgType::REQUEST_OPERATION, Data::from(&[0xA7]))
+            }
+
+            Message::AckOperation(address, Operation::ReceiveConfig) => {
+                Frame::new(address, MsgType::ACK_OPERATION, Data::from(&[0x95]))
+            }
+            Message::AckOperation(address, Operation::ReceivePixels) => {
+                Frame::new(address, MsgType::ACK_OPERATION, Data::from(&[0x91]))
+            }
+            Message::AckOperation(address, Operation::ShowLoadedPage) => {
+                Frame::new(address, MsgType::ACK_OPERATION, Data::from(&[0x96]))
+            }
+            Message::AckOperation(address, Operation::LoadNextPage) => {
+                Frame::new(address, MsgType::ACK_OPERATION, Data::from(&[0x97]))
+            }
+            Message::AckOperation(address, Operation::StartReset) => {
+                Frame::new(address, MsgType::ACK_OPERATION, Data::from(&[0x93]))
+            }
+            Message::AckOperation(address, Operation::FinishReset) => {
+                Frame::new(address, MsgType::ACK_OPERATION, Data::from(&[0x94]))
+            }
+
+            Message::PixelsComplete(address) => Frame::new(address, MsgType::PIXELS_COMPLETE, Data::from(&[0x00])),
 
             Message::Unknown(frame) => frame,
         }
@@ -349,6 +565,48 @@ mod tests {
         assert_eq!(orig_frame, converted_frame);
     }
 
+    #[test]
+    fn data_transfer_chunks_each_item() {
+        let first = [1u8; 5];
+        let second = [2u8; 3];
+        let messages = Message::data_transfer(&[&first[..], &second[..], &[]], 4);
+        assert_eq!(
+            vec![
+                Message::SendData(Offset(0), Data::from(&[1, 1, 1, 1])),
+                Message::SendData(Offset(4), Data::from(&[1])),
+                Message::SendData(Offset(0), Data::from(&[2, 2, 2])),
+                Message::DataChunksSent(ChunkCount(3)),
+            ],
+            messages
+        );
+
+        assert_eq!(
+            vec![Message::DataChunksSent(ChunkCount(0))],
+            Message::data_transfer(&[], Message::DEFAULT_CHUNK_SIZE)
+        );
+    }
+
+    #[test]
+    fn message_kind() {
+        assert_eq!(
+            MessageKind::SendData,
+            Message::SendData(Offset(0), Data::from(&[0x01])).kind()
+        );
+        assert_eq!(
+            MessageKind::ReportState,
+            Message::ReportState(Address(3), State::PageShown).kind()
+        );
+        assert_eq!(
+            MessageKind::AckOperation,
+            Message::AckOperation(Address(3), Operation::ReceivePixels).kind()
+        );
+        assert_eq!(MessageKind::PixelsComplete, Message::PixelsComplete(Address(3)).kind());
+        assert_eq!(
+            MessageKind::Unknown,
+            Message::from(Frame::new(Address(3), MsgType(0x7E), Data::from(&[]))).kind()
+        );
+    }
+
     #[test]
     fn frame_message_roundtrip() {
         verify_roundtrip(
@@ -483,6 +741,11 @@ mod tests {
             Message::PixelsComplete(Address(0xFFFF)),
         );
 
+        verify_roundtrip(
+            Frame::new(Address(0x7F), MsgType(4), Data::from(&[0x42])),
+            Message::ReportState(Address(0x7F), State::Other(0x42)),
+        );
+
         verify_roundtrip(
             Frame::new(Address(0xF00D), MsgType(99), Data::from(&[])),
             Message::Unknown(Frame::new(Address(0xF00D), MsgType(99), Data::from(&[]))),
@@ -499,6 +762,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn expects_response() {
+        assert!(Message::Hello(Address(1)).expects_response());
+        assert!(Message::QueryState(Address(1)).expects_response());
+        assert!(Message::RequestOperation(Address(1), Operation::StartReset).expects_response());
+
+        assert!(!Message::SendData(Offset(0), Data::from(&[0x01])).expects_response());
+        assert!(!Message::DataChunksSent(ChunkCount(1)).expects_response());
+        assert!(!Message::ReportState(Address(1), State::Unconfigured).expects_response());
+        assert!(!Message::AckOperation(Address(1), Operation::StartReset).expects_response());
+        assert!(!Message::PixelsComplete(Address(1)).expects_response());
+        assert!(!Message::Goodbye(Address(1)).expects_response());
+        assert!(!Message::Unknown(Frame::new(Address(1), MsgType(2), Data::from(&[]))).expects_response());
+    }
+
     #[test]
     fn display() {
         let message = Message::SendData(Offset(0x10), Data::from(&[0x20, 0xFF]));
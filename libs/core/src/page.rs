@@ -1,9 +1,25 @@
-use std::borrow::Cow;
-use std::fmt::{self, Display, Formatter};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use core::hash::Hasher;
+use core::ops::Range;
+#[cfg(feature = "std")]
+use std::collections::hash_map::DefaultHasher;
+#[cfg(feature = "std")]
+use std::io::Write;
 
 use derive_more::{Display, LowerHex, UpperHex};
 use thiserror::Error;
 
+use crate::{Font, Message, Offset};
+#[cfg(feature = "std")]
+use crate::{Frame, FrameError};
+
 /// Errors relating to [`Page`]s.
 #[derive(Copy, Clone, Debug, Error)]
 #[non_exhaustive]
@@ -29,10 +45,151 @@ pub enum PageError {
         /// The actual length of the page data that was provided.
         actual: usize,
     },
+
+    /// Two [`Page`]s that were expected to be the same size had different dimensions.
+    #[error(
+        "Page dimensions do not match: Expected {}x{}, got {}x{}",
+        expected_width,
+        expected_height,
+        actual_width,
+        actual_height
+    )]
+    DimensionMismatch {
+        /// The expected page width.
+        expected_width: u32,
+
+        /// The expected page height.
+        expected_height: u32,
+
+        /// The actual width of the page that was provided.
+        actual_width: u32,
+
+        /// The actual height of the page that was provided.
+        actual_height: u32,
+    },
+
+    /// Data chunks did not form a contiguous block starting from offset zero.
+    #[error("Data chunks are not contiguous: Expected offset {}, got {}", expected, actual)]
+    NonContiguousData {
+        /// The offset at which the next chunk was expected to start.
+        expected: u16,
+
+        /// The actual offset of the next chunk.
+        actual: u16,
+    },
+
+    /// A page sequence number was outside the valid range.
+    #[error("Page sequence number {} is out of range: Expected 1-{}", sequence, max)]
+    InvalidSequence {
+        /// The invalid sequence number.
+        sequence: u32,
+
+        /// The maximum valid sequence number.
+        max: u8,
+    },
+
+    /// An operation that requires at least one page was given none.
+    #[error("No pages provided")]
+    NoPages,
+
+    /// A [`Page`] was too large to fit within the requested dimensions.
+    #[error("A {}x{} page does not fit within {}x{}", width, height, target_width, target_height)]
+    TooLarge {
+        /// The width of the page that was provided.
+        width: u32,
+
+        /// The height of the page that was provided.
+        height: u32,
+
+        /// The width it needed to fit within.
+        target_width: u32,
+
+        /// The height it needed to fit within.
+        target_height: u32,
+    },
+
+    /// Numbering a sequence of pages consecutively would run past the last page ID.
+    #[error("{} pages numbered from {} would run past the last page ID, {}", count, first, max)]
+    TooManyPages {
+        /// The ID of the first page.
+        first: u8,
+
+        /// The number of pages needed.
+        count: usize,
+
+        /// The last valid page ID.
+        max: u8,
+    },
 }
 
 const HEADER_LEN: usize = 4;
 
+/// The fields of a [`Page`]'s 4-byte header, as far as they are understood.
+///
+/// Only the page ID is well understood; the other bytes are exposed by position so that callers can read
+/// and write them as their meanings are worked out.
+///
+/// # Examples
+///
+/// ```
+/// # use flipdot_core::{Page, PageId};
+/// let header = Page::new(PageId(2), 90, 7).header_fields();
+/// assert_eq!(PageId(2), header.id);
+/// assert_eq!([0x02, 0x10, 0x00, 0x00], header.to_bytes());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PageHeader {
+    /// The page number (byte 0).
+    pub id: PageId,
+
+    /// Byte 1, commonly `0x10`. Its meaning is unknown.
+    ///
+    /// This byte has been reported to hold how long the page is shown, in deciseconds, but that hasn't been
+    /// confirmed against a real ODK, so there is no [`Duration`](core::time::Duration)-based accessor for it yet.
+    pub byte1: u8,
+
+    /// Byte 2, commonly `0x00`. Its meaning is unknown.
+    pub byte2: u8,
+
+    /// Byte 3, commonly `0x00`. Its meaning is unknown.
+    pub byte3: u8,
+}
+
+impl PageHeader {
+    /// Creates a `PageHeader` from the four raw header bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{PageHeader, PageId};
+    /// let header = PageHeader::from_bytes([0x01, 0x20, 0x03, 0x04]);
+    /// assert_eq!(PageId(1), header.id);
+    /// assert_eq!(0x04, header.byte3);
+    /// ```
+    pub fn from_bytes(bytes: [u8; HEADER_LEN]) -> Self {
+        PageHeader {
+            id: PageId(bytes[0]),
+            byte1: bytes[1],
+            byte2: bytes[2],
+            byte3: bytes[3],
+        }
+    }
+
+    /// Returns the four raw header bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageHeader;
+    /// let bytes = [0x01, 0x20, 0x03, 0x04];
+    /// assert_eq!(bytes, PageHeader::from_bytes(bytes).to_bytes());
+    /// ```
+    pub fn to_bytes(&self) -> [u8; HEADER_LEN] {
+        [self.id.0, self.byte1, self.byte2, self.byte3]
+    }
+}
+
 /// A page of a message for display on a sign.
 ///
 /// # Examples
@@ -51,7 +208,8 @@ const HEADER_LEN: usize = 4;
 /// column and one bit per pixel. The least significant bit is oriented toward the top of the display.
 /// The `ID` field is a "page number" used to identify individual pages in multi-page messages.
 /// The other bytes in the header are unknown, but from inspection of real ODKs seem to be most
-/// commonly `0x10 0x00 0x00`, which is what [`Page::new`] currently uses.
+/// commonly `0x10 0x00 0x00`, which is what [`Page::new`] currently uses. [`Page::header_fields`]
+/// exposes them individually.
 ///
 /// ```text
 ///                   ┌─┬ ┄ ┬─┐
@@ -95,7 +253,9 @@ pub struct Page<'a> {
 
 /// The page number of a [`Page`].
 ///
-/// Used to identify a particular page in a multi-page message.
+/// Used to identify a particular page in a multi-page message. Real ODKs number pages
+/// sequentially starting from 1, so [`PageId::from_sequence`] is the preferred way to
+/// create one from a page index.
 ///
 /// # Examples
 ///
@@ -112,16 +272,89 @@ pub struct Page<'a> {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Display, LowerHex, UpperHex)]
 pub struct PageId(pub u8);
 
+impl PageId {
+    /// Creates a `PageId` from a 1-based sequence number, as used by real ODKs.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PageError::InvalidSequence`] if `sequence` is 0 or greater than 255.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageId;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// assert_eq!(PageId(1), PageId::from_sequence(1)?);
+    /// assert!(PageId::from_sequence(0).is_err());
+    /// assert!(PageId::from_sequence(256).is_err());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn from_sequence(sequence: u32) -> Result<Self, PageError> {
+        match u8::try_from(sequence) {
+            Ok(id) if id > 0 => Ok(PageId(id)),
+            _ => Err(PageError::InvalidSequence { sequence, max: u8::MAX }),
+        }
+    }
+
+    /// Returns the 1-based sequence number of this page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageId;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let ids = (1..=3).map(PageId::from_sequence).collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(vec![1, 2, 3], ids.iter().map(|id| id.sequence()).collect::<Vec<_>>());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn sequence(self) -> u8 {
+        self.0
+    }
+}
+
 /// Whether the sign or controller (ODK) is in charge of flipping pages.
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
 pub enum PageFlipStyle {
     /// The sign will flip pages itself.
+    ///
+    /// The interval between flips is chosen by the sign. It is not reported in any known message, nor set by
+    /// the configuration data or any other part of the protocol, so it can neither be queried nor changed;
+    /// measure it by observing the sign if needed.
     Automatic,
 
     /// The controller will notify the sign when to load/show pages.
     Manual,
 }
 
+/// Logical operation used to combine the pixels of two [`Page`]s.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BlitMode {
+    /// A pixel is on if it is on in either page.
+    Or,
+
+    /// A pixel is on only if it is on in both pages.
+    And,
+
+    /// A pixel is on if it is on in exactly one of the pages.
+    Xor,
+}
+
+impl BlitMode {
+    /// Applies the operation to a pair of bytes.
+    fn apply(self, a: u8, b: u8) -> u8 {
+        match self {
+            BlitMode::Or => a | b,
+            BlitMode::And => a & b,
+            BlitMode::Xor => a ^ b,
+        }
+    }
+}
+
 impl<'a> Page<'a> {
     /// Creates a new `Page` with given ID and dimensions.
     ///
@@ -135,6 +368,23 @@ impl<'a> Page<'a> {
     /// assert_eq!(false, page.get_pixel(75, 3)); // All pixels initially off
     /// ```
     pub fn new(id: PageId, width: u32, height: u32) -> Self {
+        Self::new_with_padding(id, width, height, 0xFF)
+    }
+
+    /// Creates a new `Page` with given ID and dimensions, padding the data with `pad` instead of the usual `0xFF`.
+    ///
+    /// Pages are padded to a multiple of 16 bytes. Real ODKs have been observed using different padding values,
+    /// so this allows reproducing their byte sequences exactly. The padding does not affect the displayed image.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let page = Page::new_with_padding(PageId(1), 8, 8, 0x00);
+    /// assert_eq!(vec![1, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], page.as_bytes());
+    /// assert_eq!(Some(0x00), page.padding_byte());
+    /// ```
+    pub fn new_with_padding(id: PageId, width: u32, height: u32, pad: u8) -> Self {
         let mut bytes = Vec::<u8>::with_capacity(Self::total_bytes(width, height));
 
         // 4-byte header
@@ -143,8 +393,8 @@ impl<'a> Page<'a> {
         // Fill remaining data bytes with 0 for a blank initial image
         bytes.resize(Self::data_bytes(width, height), 0x00);
 
-        // Pad to multiple of 16 with 0xFF bytes
-        bytes.resize(Self::total_bytes(width, height), 0xFF);
+        // Pad to multiple of 16
+        bytes.resize(Self::total_bytes(width, height), pad);
 
         Page {
             width,
@@ -153,6 +403,82 @@ impl<'a> Page<'a> {
         }
     }
 
+    /// Creates a new `Page` with given ID and dimensions and all pixels set to `value`.
+    ///
+    /// A fully lit page is a useful test pattern for verifying that every dot on a sign works.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let page = Page::filled(PageId(1), 90, 7, true);
+    /// assert!(page.get_pixel(0, 0));
+    /// assert!(page.get_pixel(89, 6));
+    /// ```
+    pub fn filled(id: PageId, width: u32, height: u32, value: bool) -> Self {
+        let mut page = Self::new(id, width, height);
+        page.set_all_pixels(value);
+        page
+    }
+
+    /// Creates a new `Page` with given ID and dimensions filled with a checkerboard pattern.
+    ///
+    /// The pixel at `(0, 0)` is on, and each pixel differs from its horizontal and vertical neighbors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let page = Page::checkerboard(PageId(1), 90, 7);
+    /// assert!(page.get_pixel(0, 0));
+    /// assert!(!page.get_pixel(1, 0));
+    /// assert!(!page.get_pixel(0, 1));
+    /// assert!(page.get_pixel(1, 1));
+    /// ```
+    pub fn checkerboard(id: PageId, width: u32, height: u32) -> Self {
+        let mut page = Self::new(id, width, height);
+        for x in 0..width {
+            for y in 0..height {
+                page.set_pixel(x, y, (x + y) % 2 == 0);
+            }
+        }
+        page
+    }
+
+    /// Creates a new `Page` with given ID and dimensions filled with pseudo-random pixels.
+    ///
+    /// The pixels are generated deterministically from `seed` using a small built-in generator, so
+    /// the same seed always produces the same page. This makes it suitable for reproducible tests.
+    /// The generator is not cryptographically secure, and the exact pattern for a given seed may change
+    /// between versions of this crate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let page = Page::random(PageId(1), 90, 7, 42);
+    /// assert_eq!(page, Page::random(PageId(1), 90, 7, 42));
+    /// assert_ne!(page, Page::random(PageId(1), 90, 7, 43));
+    /// ```
+    pub fn random(id: PageId, width: u32, height: u32, seed: u64) -> Self {
+        let mut page = Self::new(id, width, height);
+        let mut state = seed;
+        let mut bits = 0;
+        let mut remaining = 0;
+        for x in 0..width {
+            for y in 0..height {
+                if remaining == 0 {
+                    bits = split_mix_64(&mut state);
+                    remaining = 64;
+                }
+                page.set_pixel(x, y, bits & 1 != 0);
+                bits >>= 1;
+                remaining -= 1;
+            }
+        }
+        page
+    }
+
     /// Creates a new `Page` with given dimensions from the underlying byte representation.
     ///
     /// The data must be convertible to [`Cow`], which allows us to create efficient views of
@@ -175,7 +501,7 @@ impl<'a> Page<'a> {
     /// let data: Vec<u8> = vec![1, 16, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255];
     /// let page = Page::from_bytes(8, 8, data)?;
     /// assert_eq!(PageId(1), page.id());
-    /// assert_eq!(true, page.get_pixel(0, 0));
+    /// assert!(page.get_pixel(0, 0));
     /// assert_eq!(false, page.get_pixel(1, 0));
     ///
     /// let bad_data: Vec<u8> = vec![1, 0, 0, 0, 1];
@@ -204,6 +530,64 @@ impl<'a> Page<'a> {
         Ok(page)
     }
 
+    /// Reassembles a `Page` with given dimensions from the [`SendData`](Message::SendData) messages used to transfer it.
+    ///
+    /// This is useful for decoding pages from a captured bus session. The chunks are ordered by [`Offset`]
+    /// and must cover the page data exactly, with no gaps or overlaps. Any other messages
+    /// (e.g. the trailing [`DataChunksSent`](Message::DataChunksSent)) are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`PageError::NonContiguousData`] if the chunks have gaps or overlap.
+    /// * [`PageError::WrongPageLength`] if the reassembled data length does not match the specified dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{ChunkCount, Data, Message, Offset, Page, PageId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let mut page = Page::new(PageId(1), 90, 7);
+    /// page.set_pixel(45, 3, true);
+    ///
+    /// let mut messages: Vec<Message> = page
+    ///     .as_bytes()
+    ///     .chunks(16)
+    ///     .enumerate()
+    ///     .map(|(i, chunk)| Message::SendData(Offset(i as u16 * 16), Data::try_new(chunk).unwrap()))
+    ///     .collect();
+    /// messages.push(Message::DataChunksSent(ChunkCount(6)));
+    ///
+    /// let reassembled = Page::from_send_data(90, 7, &messages)?;
+    /// assert_eq!(page, reassembled);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn from_send_data(width: u32, height: u32, messages: &[Message<'_>]) -> Result<Page<'static>, PageError> {
+        let mut chunks: Vec<(Offset, &[u8])> = messages
+            .iter()
+            .filter_map(|message| match *message {
+                Message::SendData(offset, ref data) => Some((offset, &data.get()[..])),
+                _ => None,
+            })
+            .collect();
+        chunks.sort_by_key(|&(offset, _)| offset.0);
+
+        let mut bytes = Vec::with_capacity(Self::total_bytes(width, height));
+        for (offset, data) in chunks {
+            if offset.0 as usize != bytes.len() {
+                return Err(PageError::NonContiguousData {
+                    expected: bytes.len() as u16,
+                    actual: offset.0,
+                });
+            }
+            bytes.extend_from_slice(data);
+        }
+
+        Page::from_bytes(width, height, bytes)
+    }
+
     /// Returns the ID (page number) of this page.
     ///
     /// # Examples
@@ -217,6 +601,43 @@ impl<'a> Page<'a> {
         PageId(self.bytes[0])
     }
 
+    /// Returns the fields of this page's header.
+    ///
+    /// See [`PageHeader`] for which bytes are currently understood.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let header = Page::new(PageId(1), 90, 7).header_fields();
+    /// assert_eq!(PageId(1), header.id);
+    /// assert_eq!(0x10, header.byte1);
+    /// assert_eq!(0x00, header.byte2);
+    /// assert_eq!(0x00, header.byte3);
+    /// ```
+    pub fn header_fields(&self) -> PageHeader {
+        let mut bytes = [0; HEADER_LEN];
+        bytes.copy_from_slice(&self.bytes[..HEADER_LEN]);
+        PageHeader::from_bytes(bytes)
+    }
+
+    /// Replaces this page's header, including its ID, with `header`.
+    ///
+    /// The pixel data is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageHeader, PageId};
+    /// let mut page = Page::new(PageId(1), 90, 7);
+    /// page.set_header_fields(PageHeader::from_bytes([0x02, 0x20, 0x00, 0x00]));
+    /// assert_eq!(PageId(2), page.id());
+    /// assert_eq!(0x20, page.header_fields().byte1);
+    /// ```
+    pub fn set_header_fields(&mut self, header: PageHeader) {
+        self.bytes.to_mut()[..HEADER_LEN].copy_from_slice(&header.to_bytes());
+    }
+
     /// Returns the width of this page.
     ///
     /// # Examples
@@ -243,6 +664,56 @@ impl<'a> Page<'a> {
         self.height
     }
 
+    /// Returns the value of the first byte of padding following the pixel data, or `None` if the page has no padding.
+    ///
+    /// Pages created with [`new`](Self::new) are padded with `0xFF`. No padding is needed when the
+    /// header and pixel data already fill a multiple of 16 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// assert_eq!(Some(0xFF), Page::new(PageId(1), 90, 7).padding_byte());
+    /// assert_eq!(Some(0x00), Page::new_with_padding(PageId(1), 90, 7, 0x00).padding_byte());
+    /// assert_eq!(None, Page::new(PageId(1), 12, 8).padding_byte());
+    /// ```
+    pub fn padding_byte(&self) -> Option<u8> {
+        self.bytes.get(Self::data_bytes(self.width, self.height)).copied()
+    }
+
+    /// Returns the number of bytes used to store each column of this page.
+    ///
+    /// Columns are stored one after another following the 4-byte header, with the least
+    /// significant bit of each column's first byte holding the topmost pixel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let page = Page::new(PageId(1), 90, 7);
+    /// assert_eq!(1, page.bytes_per_column());
+    ///
+    /// let page = Page::new(PageId(1), 112, 16);
+    /// assert_eq!(2, page.bytes_per_column());
+    /// ```
+    pub fn bytes_per_column(&self) -> usize {
+        Self::bytes_per_column_for_height(self.height)
+    }
+
+    /// Returns the number of bytes used to store each column of a page `height` pixels tall.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, SignType};
+    /// let (_, height) = SignType::Max3000Front98x16.dimensions();
+    /// assert_eq!(2, Page::bytes_per_column_for_height(height));
+    /// assert_eq!(3, Page::bytes_per_column_for_height(17));
+    /// ```
+    pub fn bytes_per_column_for_height(height: u32) -> usize {
+        (height as usize).div_ceil(8)
+    }
+
     /// Returns whether or not the pixel at the given `(x, y)` coordinate is on.
     ///
     /// # Panics
@@ -308,52 +779,964 @@ impl<'a> Page<'a> {
         self.bytes.to_mut()[HEADER_LEN..Self::data_bytes(self.width, self.height)].fill(byte);
     }
 
-    /// Returns the raw byte representation of this page.
-    ///
-    /// This is generally called on your behalf when sending a page to a sign.
+    /// Returns the number of pixels that are on.
     ///
     /// # Examples
     ///
     /// ```
     /// # use flipdot_core::{Page, PageId};
-    /// let mut page = Page::new(PageId(1), 8, 8);
-    /// page.set_pixel(0, 0, true);
-    /// let bytes = page.as_bytes();
-    /// assert_eq!(vec![1, 16, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255], bytes);
+    /// let mut page = Page::new(PageId(1), 90, 7);
+    /// page.set_pixel(5, 5, true);
+    /// page.set_pixel(6, 6, true);
+    /// assert_eq!(2, page.lit_count());
     /// ```
-    pub fn as_bytes(&self) -> &[u8] {
-        &self.bytes
-    }
-
-    /// Returns the number of bytes used to store each column.
-    fn bytes_per_column(height: u32) -> usize {
-        (height as usize + 7) / 8 // Divide by 8 rounding up
+    pub fn lit_count(&self) -> u32 {
+        self.lit_pixel_coordinates().count() as u32
     }
 
-    /// Returns the number of actual meaningful bytes (including header but not padding).
-    fn data_bytes(width: u32, height: u32) -> usize {
-        HEADER_LEN + width as usize * Self::bytes_per_column(height)
+    /// Returns the `(x, y)` coordinates of every pixel that is on.
+    ///
+    /// Coordinates are in the page's native column-major order: column by column from left to right,
+    /// and from top to bottom within each column. This is a compact alternative to scanning every pixel
+    /// when few are lit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let mut page = Page::new(PageId(1), 90, 7);
+    /// page.set_pixel(6, 0, true);
+    /// page.set_pixel(5, 5, true);
+    /// page.set_pixel(5, 1, true);
+    /// assert_eq!(vec![(5, 1), (5, 5), (6, 0)], page.lit_pixels());
+    /// ```
+    pub fn lit_pixels(&self) -> Vec<(u32, u32)> {
+        self.lit_pixel_coordinates().collect()
     }
 
-    /// Returns the total number of bytes, including the padding.
-    fn total_bytes(width: u32, height: u32) -> usize {
-        (Self::data_bytes(width, height) + 15) / 16 * 16 // Round to multiple of 16
+    /// Returns the pixels of row `y` packed into bytes, most significant bit first.
+    ///
+    /// Bit 7 of the first byte is column 0, bit 6 is column 1, and so on. The result is `ceil(width / 8)`
+    /// bytes long, with any unused trailing bits set to zero. This is the row-major format
+    /// expected by many LED matrix libraries, as opposed to the column-major native format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let mut page = Page::new(PageId(1), 10, 7);
+    /// page.set_pixel(0, 2, true);
+    /// page.set_pixel(9, 2, true);
+    /// assert_eq!(vec![0b1000_0000, 0b0100_0000], page.row_bits(2));
+    /// ```
+    pub fn row_bits(&self, y: u32) -> Vec<u8> {
+        let mut bits = vec![0; (self.width as usize).div_ceil(8)];
+        for x in 0..self.width {
+            if self.get_pixel(x, y) {
+                bits[x as usize / 8] |= 0x80 >> (x % 8);
+            }
+        }
+        bits
     }
 
-    /// Given an x-y coordinate, returns the byte and bit at which it is stored.
-    fn byte_bit_indices(&self, x: u32, y: u32) -> (usize, u8) {
-        if x >= self.width || y >= self.height {
+    /// Sets the pixels of row `y` from bytes packed most significant bit first.
+    ///
+    /// This is the inverse of [`row_bits`](Self::row_bits). Any bits beyond the page width are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is out of bounds or `bits` is shorter than `ceil(width / 8)` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let mut page = Page::new(PageId(1), 10, 7);
+    /// page.set_row_bits(2, &[0b1000_0000, 0b0100_0000]);
+    /// assert!(page.get_pixel(0, 2));
+    /// assert!(page.get_pixel(9, 2));
+    /// assert!(!page.get_pixel(1, 2));
+    /// ```
+    pub fn set_row_bits(&mut self, y: u32, bits: &[u8]) {
+        let expected = (self.width as usize).div_ceil(8);
+        if bits.len() < expected {
             panic!(
-                "Coordinate ({}, {}) out of bounds for page of size {} x {}",
-                x, y, self.width, self.height
+                "Row data too short for page of width {}: Expected {} bytes, got {}",
+                self.width,
+                expected,
+                bits.len()
             );
         }
 
-        let byte_index = 4 + x as usize * Self::bytes_per_column(self.height) + y as usize / 8;
-        let bit_index = y % 8;
-        (byte_index, bit_index as u8)
+        for x in 0..self.width {
+            let value = bits[x as usize / 8] & (0x80 >> (x % 8)) != 0;
+            self.set_pixel(x, y, value);
+        }
     }
-}
+
+    /// Returns the page's pixels packed row-major, one row after another.
+    ///
+    /// Each row is laid out as by [`row_bits`](Self::row_bits), so the result is `height * ceil(width / 8)` bytes
+    /// long. The page header and padding are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let mut page = Page::new(PageId(1), 10, 2);
+    /// page.set_pixel(0, 0, true);
+    /// page.set_pixel(9, 1, true);
+    /// assert_eq!(vec![0b1000_0000, 0, 0, 0b0100_0000], page.to_row_major_bytes());
+    /// ```
+    pub fn to_row_major_bytes(&self) -> Vec<u8> {
+        (0..self.height).flat_map(|y| self.row_bits(y)).collect()
+    }
+
+    /// Creates a page with the given ID and dimensions from pixels packed row-major.
+    ///
+    /// This is the inverse of [`to_row_major_bytes`](Self::to_row_major_bytes). Any bits beyond the page width
+    /// in each row are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PageError::WrongPageLength`] if `bytes` is not exactly `height * ceil(width / 8)` bytes long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let page = Page::from_row_major_bytes(PageId(1), 10, 2, &[0b1000_0000, 0, 0, 0b0100_0000])?;
+    /// assert!(page.get_pixel(0, 0));
+    /// assert!(page.get_pixel(9, 1));
+    /// assert_eq!(2, page.lit_count());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn from_row_major_bytes(id: PageId, width: u32, height: u32, bytes: &[u8]) -> Result<Page<'static>, PageError> {
+        let row_len = (width as usize).div_ceil(8);
+        let expected = row_len * height as usize;
+        if bytes.len() != expected {
+            return Err(PageError::WrongPageLength {
+                width,
+                height,
+                expected,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut page = Page::new(id, width, height);
+        if row_len > 0 {
+            for (y, row) in bytes.chunks(row_len).enumerate() {
+                page.set_row_bits(y as u32, row);
+            }
+        }
+        Ok(page)
+    }
+
+    /// Inverts the pixels within the rectangle whose top-left corner is at `(x, y)`
+    /// and which extends `width` columns to the right and `height` rows down.
+    ///
+    /// Any portion of the rectangle that falls outside the page is ignored, so this
+    /// is safe to call with arbitrary coordinates. Pixels outside the rectangle and
+    /// the page header are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let mut page = Page::new(PageId(1), 90, 7);
+    /// page.set_pixel(1, 1, true);
+    /// page.invert_rect(0, 0, 3, 3);
+    /// assert!(page.get_pixel(0, 0));
+    /// assert!(!page.get_pixel(1, 1));
+    /// assert!(!page.get_pixel(3, 3));
+    ///
+    /// // Regions hanging off the edge of the page are clipped.
+    /// page.invert_rect(85, 5, 100, 100);
+    /// assert!(page.get_pixel(89, 6));
+    /// ```
+    pub fn invert_rect(&mut self, x: u32, y: u32, width: u32, height: u32) {
+        let (columns, rows) = self.clip_rect(x, y, width, height);
+        for x in columns {
+            for y in rows.clone() {
+                let value = self.get_pixel(x, y);
+                self.set_pixel(x, y, !value);
+            }
+        }
+    }
+
+    /// Turns off the pixels within the rectangle whose top-left corner is at `(x, y)`
+    /// and which extends `width` columns to the right and `height` rows down.
+    ///
+    /// Handy for erasing part of a page before redrawing it. As with [`invert_rect`](Self::invert_rect),
+    /// any portion of the rectangle that falls outside the page is ignored, and pixels outside the
+    /// rectangle and the page header are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let mut page = Page::filled(PageId(1), 90, 7, true);
+    /// page.clear_rect(10, 2, 5, 3);
+    /// assert!(!page.get_pixel(10, 2));
+    /// assert!(!page.get_pixel(14, 4));
+    /// assert!(page.get_pixel(15, 4));
+    /// assert_eq!(90 * 7 - 15, page.lit_count());
+    ///
+    /// // Regions hanging off the edge of the page are clipped.
+    /// page.clear_rect(85, 5, 100, 100);
+    /// assert!(!page.get_pixel(89, 6));
+    /// ```
+    pub fn clear_rect(&mut self, x: u32, y: u32, width: u32, height: u32) {
+        let (columns, rows) = self.clip_rect(x, y, width, height);
+        for x in columns {
+            for y in rows.clone() {
+                self.set_pixel(x, y, false);
+            }
+        }
+    }
+
+    /// Sets the pixels along a straight line from `(x0, y0)` to `(x1, y1)`, inclusive, to `value`.
+    ///
+    /// Any portion of the line that falls outside the page is ignored, so this
+    /// is safe to call with arbitrary coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let mut page = Page::new(PageId(1), 90, 7);
+    /// page.draw_line(0, 0, 6, 6, true);
+    /// assert!(page.get_pixel(0, 0));
+    /// assert!(page.get_pixel(3, 3));
+    /// assert!(page.get_pixel(6, 6));
+    /// assert_eq!(7, page.lit_count());
+    /// ```
+    pub fn draw_line(&mut self, x0: u32, y0: u32, x1: u32, y1: u32, value: bool) {
+        // Bresenham's algorithm, generalized to all octants.
+        let (mut x, mut y) = (i64::from(x0), i64::from(y0));
+        let (x1, y1) = (i64::from(x1), i64::from(y1));
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let step_x = if x < x1 { 1 } else { -1 };
+        let step_y = if y < y1 { 1 } else { -1 };
+        let mut error = dx + dy;
+
+        loop {
+            if x < i64::from(self.width) && y < i64::from(self.height) {
+                self.set_pixel(x as u32, y as u32, value);
+            }
+            if x == x1 && y == y1 {
+                break;
+            }
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Turns on pixels according to a glyph bitmap, with its top-left corner at `(x, y)`.
+    ///
+    /// The glyph is stored row-major in the same format as [`row_bits`](Self::row_bits): each row
+    /// is `ceil(glyph_width / 8)` bytes, most significant bit first. Pixels corresponding to set bits are
+    /// turned on, and all others are left unchanged. Any portion of the glyph that falls outside the page is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `glyph` is shorter than `ceil(glyph_width / 8) * glyph_height` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// // A 3x3 plus sign.
+    /// const PLUS: &[u8] = &[0b0100_0000, 0b1110_0000, 0b0100_0000];
+    ///
+    /// let mut page = Page::new(PageId(1), 90, 7);
+    /// page.draw_glyph(10, 2, PLUS, 3, 3);
+    /// assert!(page.get_pixel(11, 2));
+    /// assert!(page.get_pixel(10, 3));
+    /// assert!(!page.get_pixel(10, 2));
+    /// assert_eq!(5, page.lit_count());
+    /// ```
+    pub fn draw_glyph(&mut self, x: u32, y: u32, glyph: &[u8], glyph_width: u32, glyph_height: u32) {
+        let row_len = (glyph_width as usize).div_ceil(8);
+        let expected_len = row_len * glyph_height as usize;
+        assert!(
+            glyph.len() >= expected_len,
+            "Glyph data for {} x {} glyph must be at least {} bytes, got {}",
+            glyph_width,
+            glyph_height,
+            expected_len,
+            glyph.len()
+        );
+
+        let (columns, rows) = self.clip_rect(x, y, glyph_width, glyph_height);
+        for page_y in rows {
+            let row = &glyph[(page_y - y) as usize * row_len..][..row_len];
+            for page_x in columns.clone() {
+                let glyph_x = page_x - x;
+                if row[glyph_x as usize / 8] & (0x80 >> (glyph_x % 8)) != 0 {
+                    self.set_pixel(page_x, page_y, true);
+                }
+            }
+        }
+    }
+
+    /// Turns on pixels to draw `text` in the given font, with the top-left corner of the first character at `(x, y)`.
+    ///
+    /// Characters not present in the font are left blank. Any portion of the text that falls outside the page is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Font, Page, PageId};
+    /// let font = Font::ascii_5x7();
+    /// let mut page = Page::new(PageId(1), 90, 7);
+    /// page.draw_text(1, 0, "|", &font);
+    /// assert!(page.get_pixel(3, 0));
+    /// assert!(page.get_pixel(3, 6));
+    /// assert_eq!(7, page.lit_count());
+    /// ```
+    pub fn draw_text(&mut self, x: u32, y: u32, text: &str, font: &Font) {
+        self.draw_text_at(i64::from(x), i64::from(y), text, font);
+    }
+
+    /// Creates a sequence of pages that scroll `text` from right to left across a display.
+    ///
+    /// The text is drawn in the given font, vertically centered, and moves `step` columns per page.
+    /// The first page shows the text just off the right edge and the last shows it just off the left edge,
+    /// so both are blank and the text can be arbitrarily wider than the display. Pages are numbered consecutively
+    /// starting from `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PageError::TooManyPages`] if the page IDs would run past 255.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Font, Page, PageId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let font = Font::ascii_5x7();
+    /// let pages = Page::scroll_text(PageId(1), 30, 7, "Hi", &font, 1)?;
+    /// assert_eq!(30 + 11 + 1, pages.len());
+    /// assert_eq!(PageId(1), pages[0].id());
+    /// assert_eq!(PageId(2), pages[1].id());
+    /// assert_eq!(0, pages[0].lit_count());
+    /// assert_eq!(0, pages.last().unwrap().lit_count());
+    /// assert!(pages[15].lit_count() > 0);
+    ///
+    /// // A long scroll at a fine step needs more pages than there are IDs.
+    /// assert!(Page::scroll_text(PageId(1), 112, 16, "Please stand clear of the doors", &font, 1).is_err());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn scroll_text(
+        id: PageId,
+        width: u32,
+        height: u32,
+        text: &str,
+        font: &Font,
+        step: u32,
+    ) -> Result<Vec<Page<'static>>, PageError> {
+        assert!(step > 0, "Scroll step must be at least 1");
+
+        let distance = width + font.text_width(text);
+        let mut offsets: Vec<u32> = (0..=distance).step_by(step as usize).collect();
+        if offsets.last() != Some(&distance) {
+            offsets.push(distance);
+        }
+
+        let y = i64::from(height.saturating_sub(font.glyph_height()) / 2);
+        let ids = Self::consecutive_ids(id, offsets.len())?;
+        let pages = offsets
+            .into_iter()
+            .zip(ids)
+            .map(|(offset, id)| {
+                let mut page = Page::new(id, width, height);
+                page.draw_text_at(i64::from(width) - i64::from(offset), y, text, font);
+                page
+            })
+            .collect();
+        Ok(pages)
+    }
+
+    /// Lays out `text` across as many pages as needed to show all of it.
+    ///
+    /// Words are separated by whitespace and wrapped onto new lines, leaving one blank row between lines;
+    /// a word too wide for the display is broken across lines. Each page holds as many lines as fit,
+    /// with each line horizontally centered and the block of lines vertically centered. Pages are numbered
+    /// consecutively starting from `id`. Blank text produces a single blank page.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PageError::TooManyPages`] if the page IDs would run past 255.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Font, Page, PageId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let font = Font::ascii_5x7();
+    /// let pages = Page::paginate_text(PageId(1), 60, 16, "Now boarding at gate five", &font)?;
+    /// // Lines are "Now", "boarding", "at gate", and "five"; two fit on each page.
+    /// assert_eq!(2, pages.len());
+    /// assert_eq!(PageId(1), pages[0].id());
+    /// assert_eq!(PageId(2), pages[1].id());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn paginate_text(id: PageId, width: u32, height: u32, text: &str, font: &Font) -> Result<Vec<Page<'static>>, PageError> {
+        let advance = font.glyph_width() + font.spacing();
+        let max_chars = ((width + font.spacing()) / advance).max(1) as usize;
+        let line_pitch = font.glyph_height() + 1;
+        let lines_per_page = ((height + 1) / line_pitch).max(1) as usize;
+
+        let mut lines: Vec<String> = Vec::new();
+        let mut current: Vec<char> = Vec::new();
+        for word in text.split_whitespace() {
+            let word: Vec<char> = word.chars().collect();
+            if !current.is_empty() {
+                if current.len() + 1 + word.len() <= max_chars {
+                    current.push(' ');
+                    current.extend_from_slice(&word);
+                    continue;
+                }
+                lines.push(current.drain(..).collect());
+            }
+
+            for piece in word.chunks(max_chars) {
+                if !current.is_empty() {
+                    lines.push(current.drain(..).collect());
+                }
+                current.extend_from_slice(piece);
+            }
+        }
+        if !current.is_empty() {
+            lines.push(current.into_iter().collect());
+        }
+
+        if lines.is_empty() {
+            return Ok(vec![Page::new(id, width, height)]);
+        }
+
+        let ids = Self::consecutive_ids(id, lines.len().div_ceil(lines_per_page))?;
+        let pages = lines
+            .chunks(lines_per_page)
+            .zip(ids)
+            .map(|(page_lines, id)| {
+                let mut page = Page::new(id, width, height);
+                let block_height = page_lines.len() as u32 * line_pitch - 1;
+                let top = i64::from(height.saturating_sub(block_height) / 2);
+                for (row, line) in page_lines.iter().enumerate() {
+                    let x = i64::from(width.saturating_sub(font.text_width(line)) / 2);
+                    page.draw_text_at(x, top + row as i64 * i64::from(line_pitch), line, font);
+                }
+                page
+            })
+            .collect();
+        Ok(pages)
+    }
+
+    /// Returns `count` consecutive page IDs starting from `first`, or an error if they would run past 255.
+    fn consecutive_ids(first: PageId, count: usize) -> Result<impl Iterator<Item = PageId>, PageError> {
+        if usize::from(first.0) + count.saturating_sub(1) > usize::from(u8::MAX) {
+            return Err(PageError::TooManyPages {
+                first: first.0,
+                count,
+                max: u8::MAX,
+            });
+        }
+        Ok((0..count).map(move |i| PageId(first.0 + i as u8)))
+    }
+
+    /// Draws text with its top-left corner at a possibly-negative position, clipping to the page.
+    fn draw_text_at(&mut self, x: i64, y: i64, text: &str, font: &Font) {
+        let row_len = (font.glyph_width() as usize).div_ceil(8);
+        let advance = i64::from(font.glyph_width() + font.spacing());
+        for (i, ch) in text.chars().enumerate() {
+            let glyph = match font.glyph(ch) {
+                Some(glyph) => glyph,
+                None => continue,
+            };
+
+            let glyph_x = x + i as i64 * advance;
+            for row in 0..font.glyph_height() {
+                let page_y = y + i64::from(row);
+                if page_y < 0 || page_y >= i64::from(self.height) {
+                    continue;
+                }
+
+                let bits = &glyph[row as usize * row_len..][..row_len];
+                for column in 0..font.glyph_width() {
+                    let page_x = glyph_x + i64::from(column);
+                    if page_x >= 0 && page_x < i64::from(self.width) && bits[column as usize / 8] & (0x80 >> (column % 8)) != 0 {
+                        self.set_pixel(page_x as u32, page_y as u32, true);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Combines this page with `other` using the given operation, returning a new page.
+    ///
+    /// Neither page is modified. The result has the same ID and header as `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PageError::DimensionMismatch`] if the two pages are not the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{BlitMode, Page, PageId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let mut background = Page::new(PageId(1), 90, 7);
+    /// background.set_pixel(0, 0, true);
+    /// let mut foreground = Page::new(PageId(2), 90, 7);
+    /// foreground.set_pixel(1, 1, true);
+    ///
+    /// let combined = background.combine(&foreground, BlitMode::Or)?;
+    /// assert_eq!(PageId(1), combined.id());
+    /// assert!(combined.get_pixel(0, 0));
+    /// assert!(combined.get_pixel(1, 1));
+    ///
+    /// let too_small = Page::new(PageId(3), 30, 7);
+    /// assert!(background.combine(&too_small, BlitMode::Or).is_err());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn combine(&self, other: &Page<'_>, op: BlitMode) -> Result<Page<'static>, PageError> {
+        self.check_same_dimensions(other)?;
+
+        let mut bytes = self.bytes.to_vec();
+        let data_range = HEADER_LEN..Self::data_bytes(self.width, self.height);
+        for (byte, other_byte) in bytes[data_range.clone()].iter_mut().zip(&other.bytes[data_range]) {
+            *byte = op.apply(*byte, *other_byte);
+        }
+
+        Ok(Page {
+            width: self.width,
+            height: self.height,
+            bytes: bytes.into(),
+        })
+    }
+
+    /// Turns off every pixel that is not also on in `mask`, restricting the page's content to the masked region.
+    ///
+    /// This is equivalent to combining with [`BlitMode::And`], but modifies the page in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PageError::DimensionMismatch`] if the two pages are not the same size. The page is unchanged in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let mut page = Page::filled(PageId(1), 90, 7, true);
+    ///
+    /// // Only keep the leftmost 10 columns.
+    /// let mut window = Page::new(PageId(0), 90, 7);
+    /// window.invert_rect(0, 0, 10, 7);
+    /// page.mask(&window)?;
+    ///
+    /// assert!(page.get_pixel(9, 3));
+    /// assert!(!page.get_pixel(10, 3));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn mask(&mut self, mask: &Page<'_>) -> Result<(), PageError> {
+        self.check_same_dimensions(mask)?;
+
+        let data_range = HEADER_LEN..Self::data_bytes(self.width, self.height);
+        let bytes = &mut self.bytes.to_mut()[data_range.clone()];
+        for (byte, mask_byte) in bytes.iter_mut().zip(&mask.bytes[data_range]) {
+            *byte = BlitMode::And.apply(*byte, *mask_byte);
+        }
+        Ok(())
+    }
+
+    /// Returns a page with each pixel on if it is on in any of the given pages.
+    ///
+    /// This is handy for previewing the full area covered by an animation. The result has the same ID and header
+    /// as the first page.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`PageError::NoPages`] if `pages` is empty.
+    /// * [`PageError::DimensionMismatch`] if the pages are not all the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let frames: Vec<Page> = (0..3)
+    ///     .map(|i| {
+    ///         let mut page = Page::new(PageId(i), 90, 7);
+    ///         page.set_pixel(u32::from(i), 0, true);
+    ///         page
+    ///     })
+    ///     .collect();
+    ///
+    /// let union = Page::union(&frames)?;
+    /// assert_eq!(PageId(0), union.id());
+    /// assert!((0..3).all(|x| union.get_pixel(x, 0)));
+    /// assert!(!union.get_pixel(3, 0));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn union(pages: &[Page<'_>]) -> Result<Page<'static>, PageError> {
+        let (first, rest) = pages.split_first().ok_or(PageError::NoPages)?;
+        let initial = Page {
+            width: first.width,
+            height: first.height,
+            bytes: first.bytes.to_vec().into(),
+        };
+        rest.iter().try_fold(initial, |union, page| union.combine(page, BlitMode::Or))
+    }
+
+    /// Creates a larger page with the given ID and dimensions, with this page's content centered in it.
+    ///
+    /// The area around the content is blank. If the content can't be centered exactly, it is placed
+    /// one pixel further up and/or left.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PageError::TooLarge`] if this page is wider or taller than the target dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let mut logo = Page::new(PageId(1), 10, 5);
+    /// logo.set_pixel(0, 0, true);
+    ///
+    /// let page = logo.embed_centered(PageId(2), 90, 7)?;
+    /// assert_eq!((90, 7), (page.width(), page.height()));
+    /// assert_eq!(PageId(2), page.id());
+    /// assert!(page.get_pixel(40, 1));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn embed_centered(&self, id: PageId, target_width: u32, target_height: u32) -> Result<Page<'static>, PageError> {
+        if self.width > target_width || self.height > target_height {
+            return Err(PageError::TooLarge {
+                width: self.width,
+                height: self.height,
+                target_width,
+                target_height,
+            });
+        }
+
+        let mut page = Page::new(id, target_width, target_height);
+        let x_offset = (target_width - self.width) / 2;
+        let y_offset = (target_height - self.height) / 2;
+        for x in 0..self.width {
+            for y in 0..self.height {
+                if self.get_pixel(x, y) {
+                    page.set_pixel(x + x_offset, y + y_offset, true);
+                }
+            }
+        }
+        Ok(page)
+    }
+
+    /// Asserts that this page shows the same image as `expected`, panicking with a readable comparison if not.
+    ///
+    /// Only the dimensions and pixels are compared; the page ID and other header bytes are ignored.
+    /// This is intended for tests, where comparing pages with `assert_eq!` produces an unreadable dump of bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pages have different dimensions or any pixel differs. The panic message shows
+    /// both pages side by side as ASCII art, along with a third page marking the differing pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let mut actual = Page::new(PageId(1), 8, 4);
+    /// actual.set_pixel(1, 1, true);
+    ///
+    /// let mut expected = Page::new(PageId(2), 8, 4);
+    /// expected.set_pixel(1, 1, true);
+    /// actual.assert_eq_visual(&expected);
+    /// ```
+    ///
+    /// On mismatch, the panic message looks like this:
+    ///
+    /// ```text
+    /// Pages differ in 1 pixel(s)
+    /// Actual      Expected    Differences
+    /// +--------+  +--------+  +--------+
+    /// |        |  |        |  |        |
+    /// | @      |  |        |  | @      |
+    /// |        |  |        |  |        |
+    /// |        |  |        |  |        |
+    /// +--------+  +--------+  +--------+
+    /// ```
+    #[track_caller]
+    pub fn assert_eq_visual(&self, expected: &Page<'_>) {
+        if (self.width, self.height) != (expected.width, expected.height) {
+            panic!(
+                "Page dimensions differ: got {} x {}, expected {} x {}\n{}",
+                self.width,
+                self.height,
+                expected.width,
+                expected.height,
+                side_by_side(&[("Actual", self), ("Expected", expected)])
+            );
+        }
+
+        let mut differences = Page::new(PageId(0), self.width, self.height);
+        let mut count = 0;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.get_pixel(x, y) != expected.get_pixel(x, y) {
+                    differences.set_pixel(x, y, true);
+                    count += 1;
+                }
+            }
+        }
+
+        if count > 0 {
+            panic!(
+                "Pages differ in {} pixel(s)\n{}",
+                count,
+                side_by_side(&[("Actual", self), ("Expected", expected), ("Differences", &differences)])
+            );
+        }
+    }
+
+    /// Fails with [`PageError::DimensionMismatch`] unless `other` is the same size as this page.
+    fn check_same_dimensions(&self, other: &Page<'_>) -> Result<(), PageError> {
+        if (self.width, self.height) != (other.width, other.height) {
+            return Err(PageError::DimensionMismatch {
+                expected_width: self.width,
+                expected_height: self.height,
+                actual_width: other.width,
+                actual_height: other.height,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a hash of the page's dimensions and pixel data, suitable for use as a cache key.
+    ///
+    /// The page ID and other header bytes are ignored, so two pages that look identical
+    /// will have the same fingerprint even if they have different IDs. The value is only
+    /// meaningful within a single run of a program and should not be persisted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let mut page1 = Page::new(PageId(1), 90, 7);
+    /// let mut page2 = Page::new(PageId(2), 90, 7);
+    /// page1.set_pixel(5, 5, true);
+    /// page2.set_pixel(5, 5, true);
+    /// assert_eq!(page1.fingerprint(), page2.fingerprint());
+    ///
+    /// page2.set_pixel(6, 6, true);
+    /// assert_ne!(page1.fingerprint(), page2.fingerprint());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.width.hash(&mut hasher);
+        self.height.hash(&mut hasher);
+        self.bytes[HEADER_LEN..Self::data_bytes(self.width, self.height)].hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Returns the raw byte representation of this page.
+    ///
+    /// This is generally called on your behalf when sending a page to a sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId};
+    /// let mut page = Page::new(PageId(1), 8, 8);
+    /// page.set_pixel(0, 0, true);
+    /// let bytes = page.as_bytes();
+    /// assert_eq!(vec![1, 16, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255], bytes);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Writes the wire frames that transfer this page to a sign.
+    ///
+    /// This emits the same [`SendData`](Message::SendData) frames a sign would receive, split into
+    /// 16-byte chunks at increasing offsets as real ODKs do, followed by a [`DataChunksSent`](Message::DataChunksSent)
+    /// frame with the chunk count. Each frame is written in Intel HEX followed by CRLF, so the output can later
+    /// be streamed to a sign after the appropriate [`ReceivePixels`](crate::Operation::ReceivePixels) request.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FrameError::Io`] if a write fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Frame, Message, Page, PageId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let page = Page::new(PageId(1), 90, 7);
+    /// let mut output = Vec::new();
+    /// page.write_frames(&mut output)?;
+    ///
+    /// let mut reader = &output[..];
+    /// let mut messages = Vec::new();
+    /// while !reader.is_empty() {
+    ///     messages.push(Message::from(Frame::read(&mut reader)?));
+    /// }
+    /// assert_eq!(page, Page::from_send_data(90, 7, &messages)?);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_frames<W: Write>(&self, writer: &mut W) -> Result<(), FrameError> {
+        self.write_frames_with_chunk_size(writer, Message::DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Writes the wire frames that transfer this page to a sign, using chunks of at most `chunk_size` bytes.
+    ///
+    /// Like [`write_frames`](Self::write_frames), but matches a `Sign` configured with a non-default chunk size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FrameError::Io`] if a write fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Frame, Message, Page, PageId};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let page = Page::new(PageId(1), 90, 7);
+    /// let mut output = Vec::new();
+    /// page.write_frames_with_chunk_size(&mut output, 32)?;
+    ///
+    /// let mut reader = &output[..];
+    /// let first = Message::from(Frame::read(&mut reader)?);
+    /// assert!(matches!(first, Message::SendData(_, data) if data.get().len() == 32));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_frames_with_chunk_size<W: Write>(&self, writer: &mut W, chunk_size: u8) -> Result<(), FrameError> {
+        for message in Message::data_transfer(&[&self.bytes], chunk_size) {
+            Frame::from(message).write(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of actual meaningful bytes (including header but not padding).
+    fn data_bytes(width: u32, height: u32) -> usize {
+        HEADER_LEN + width as usize * Self::bytes_per_column_for_height(height)
+    }
+
+    /// Returns the total number of bytes, including the padding.
+    pub(crate) fn total_bytes(width: u32, height: u32) -> usize {
+        Self::data_bytes(width, height).next_multiple_of(16)
+    }
+
+    /// Iterates over the coordinates of the pixels that are on, in column-major order.
+    fn lit_pixel_coordinates(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (0..self.width)
+            .flat_map(|x| (0..self.height).map(move |y| (x, y)))
+            .filter(|&(x, y)| self.get_pixel(x, y))
+    }
+
+    /// Clips the given rectangle to the page bounds, returning the ranges of columns and rows it covers.
+    fn clip_rect(&self, x: u32, y: u32, width: u32, height: u32) -> (Range<u32>, Range<u32>) {
+        let columns = x.min(self.width)..x.saturating_add(width).min(self.width);
+        let rows = y.min(self.height)..y.saturating_add(height).min(self.height);
+        (columns, rows)
+    }
+
+    /// Given an x-y coordinate, returns the byte and bit at which it is stored.
+    fn byte_bit_indices(&self, x: u32, y: u32) -> (usize, u8) {
+        if x >= self.width || y >= self.height {
+            panic!(
+                "Coordinate ({}, {}) out of bounds for page of size {} x {}",
+                x, y, self.width, self.height
+            );
+        }
+
+        let byte_index = 4 + x as usize * self.bytes_per_column() + y as usize / 8;
+        let bit_index = y % 8;
+        (byte_index, bit_index as u8)
+    }
+}
+
+/// Advances the SplitMix64 generator state and returns the next pseudo-random value.
+fn split_mix_64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Renders titled pages next to each other as ASCII art, for use in assertion messages.
+fn side_by_side(pages: &[(&str, &Page<'_>)]) -> String {
+    let columns: Vec<(usize, Vec<String>)> = pages
+        .iter()
+        .map(|&(title, page)| {
+            let mut lines = vec![String::from(title)];
+            lines.extend(page.to_string().lines().map(String::from));
+            let width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+            (width, lines)
+        })
+        .collect();
+
+    let rows = columns.iter().map(|(_, lines)| lines.len()).max().unwrap_or(0);
+    let mut output = String::new();
+    for row in 0..rows {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|(width, lines)| format!("{:<width$}", lines.get(row).map_or("", String::as_str), width = width))
+            .collect();
+        output.push_str(cells.join("  ").trim_end());
+        output.push('\n');
+    }
+    output
+}
 
 impl Display for Page<'_> {
     /// Formats the page for display using ASCII art.
@@ -376,9 +1759,165 @@ impl Display for Page<'_> {
     }
 }
 
+/// Fluent interface for drawing the contents of a [`Page`].
+///
+/// Each drawing method returns `&mut Self` so that calls can be chained, and [`build`](PageBuilder::build)
+/// returns the finished page.
+///
+/// # Examples
+///
+/// ```
+/// use flipdot_core::{PageBuilder, PageId};
+///
+/// let page = PageBuilder::new(PageId(1), 90, 7)
+///     .pixel(0, 0)
+///     .line(0, 6, 89, 6)
+///     .invert_rect(80, 0, 10, 7)
+///     .build();
+///
+/// assert!(page.get_pixel(0, 0));
+/// assert!(page.get_pixel(10, 6));
+/// assert!(!page.get_pixel(85, 6));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PageBuilder {
+    page: Page<'static>,
+}
+
+impl PageBuilder {
+    /// Creates a new `PageBuilder` for a blank page with the given ID and dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageBuilder, PageId};
+    /// let page = PageBuilder::new(PageId(1), 90, 7).build();
+    /// assert_eq!(Page::new(PageId(1), 90, 7), page);
+    /// ```
+    pub fn new(id: PageId, width: u32, height: u32) -> Self {
+        PageBuilder {
+            page: Page::new(id, width, height),
+        }
+    }
+
+    /// Turns on the pixel at `(x, y)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{PageBuilder, PageId};
+    /// let page = PageBuilder::new(PageId(1), 90, 7).pixel(3, 4).build();
+    /// assert!(page.get_pixel(3, 4));
+    /// ```
+    pub fn pixel(&mut self, x: u32, y: u32) -> &mut Self {
+        self.page.set_pixel(x, y, true);
+        self
+    }
+
+    /// Turns on the pixels along a straight line from `(x0, y0)` to `(x1, y1)`, inclusive.
+    ///
+    /// See [`Page::draw_line`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{PageBuilder, PageId};
+    /// let page = PageBuilder::new(PageId(1), 90, 7).line(0, 0, 0, 6).build();
+    /// assert_eq!(7, page.lit_count());
+    /// ```
+    pub fn line(&mut self, x0: u32, y0: u32, x1: u32, y1: u32) -> &mut Self {
+        self.page.draw_line(x0, y0, x1, y1, true);
+        self
+    }
+
+    /// Inverts the pixels within a rectangle.
+    ///
+    /// See [`Page::invert_rect`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{PageBuilder, PageId};
+    /// let page = PageBuilder::new(PageId(1), 90, 7).invert_rect(0, 0, 2, 2).build();
+    /// assert_eq!(4, page.lit_count());
+    /// ```
+    pub fn invert_rect(&mut self, x: u32, y: u32, width: u32, height: u32) -> &mut Self {
+        self.page.invert_rect(x, y, width, height);
+        self
+    }
+
+    /// Turns off the pixels within a rectangle.
+    ///
+    /// See [`Page::clear_rect`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{PageBuilder, PageId};
+    /// let page = PageBuilder::new(PageId(1), 90, 7).fill(true).clear_rect(0, 0, 90, 6).build();
+    /// assert_eq!(90, page.lit_count());
+    /// ```
+    pub fn clear_rect(&mut self, x: u32, y: u32, width: u32, height: u32) -> &mut Self {
+        self.page.clear_rect(x, y, width, height);
+        self
+    }
+
+    /// Draws text in the given font, with the top-left corner of the first character at `(x, y)`.
+    ///
+    /// See [`Page::draw_text`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Font, PageBuilder, PageId};
+    /// let page = PageBuilder::new(PageId(1), 90, 7).text(0, 0, "-", &Font::ascii_5x7()).build();
+    /// assert_eq!(5, page.lit_count());
+    /// ```
+    pub fn text(&mut self, x: u32, y: u32, text: &str, font: &Font) -> &mut Self {
+        self.page.draw_text(x, y, text, font);
+        self
+    }
+
+    /// Turns all pixels on or off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{PageBuilder, PageId};
+    /// let page = PageBuilder::new(PageId(1), 90, 7).fill(true).build();
+    /// assert_eq!(630, page.lit_count());
+    /// ```
+    pub fn fill(&mut self, value: bool) -> &mut Self {
+        self.page.set_all_pixels(value);
+        self
+    }
+
+    /// Returns the page drawn so far.
+    ///
+    /// The builder is left intact, so it can continue to be used to produce variations of the same page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{PageBuilder, PageId};
+    /// let mut builder = PageBuilder::new(PageId(1), 90, 7);
+    /// let before = builder.build();
+    /// let after = builder.pixel(0, 0).build();
+    /// assert_ne!(before, after);
+    /// ```
+    pub fn build(&self) -> Page<'static> {
+        self.page.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Address, ChunkCount, Data};
     use std::error::Error;
     use test_case::test_case;
 
@@ -421,71 +1960,238 @@ mod tests {
         let page2 = Page::from_bytes(40, 12, bytes)?;
         assert_eq!(page, page2);
 
-        Ok(())
+        Ok(())
+    }
+
+    #[test]
+    fn one_byte_per_column_set_bits() -> Result<(), Box<dyn Error>> {
+        let mut page = Page::new(PageId(3), 90, 7);
+        page.set_pixel(0, 0, true);
+        page.set_pixel(89, 5, true);
+        page.set_pixel(89, 6, true);
+        page.set_pixel(4, 4, true);
+        page.set_pixel(4, 4, false);
+        let bytes = page.as_bytes();
+        #[rustfmt::skip]
+        const EXPECTED: &[u8] = &[
+            0x03, 0x10, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0xFF, 0xFF,
+        ];
+        assert_eq!(bytes, EXPECTED);
+
+        let page2 = Page::from_bytes(90, 7, bytes)?;
+        assert_eq!(page, page2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn two_bytes_per_column_set_bits() -> Result<(), Box<dyn Error>> {
+        let mut page = Page::new(PageId(1), 40, 12);
+        page.set_pixel(0, 0, true);
+        page.set_pixel(0, 11, true);
+        page.set_pixel(39, 5, true);
+        page.set_pixel(39, 6, true);
+        page.set_pixel(39, 8, true);
+        page.set_pixel(4, 4, true);
+        page.set_pixel(4, 4, false);
+        let bytes = page.as_bytes();
+        #[rustfmt::skip]
+        const EXPECTED: &[u8] = &[
+            0x01, 0x10, 0x00, 0x00, 0x01, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x60, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        ];
+        assert_eq!(bytes, EXPECTED);
+
+        let page2 = Page::from_bytes(40, 12, bytes)?;
+        assert_eq!(page, page2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_size_rejected() {
+        let error = Page::from_bytes(90, 7, vec![0x01, 0x01, 0x03]).unwrap_err();
+        assert!(matches!(
+            error,
+            PageError::WrongPageLength {
+                expected: 96,
+                actual: 3,
+                ..
+            }
+        ));
+    }
+
+    fn send_data_messages(page: &Page<'_>) -> Vec<Message<'static>> {
+        page.as_bytes()
+            .chunks(16)
+            .enumerate()
+            .map(|(i, chunk)| Message::SendData(Offset(i as u16 * 16), Data::try_new(chunk.to_vec()).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn random_is_deterministic() {
+        let page = Page::random(PageId(1), 112, 16, 1234);
+        assert_eq!(page, Page::random(PageId(1), 112, 16, 1234));
+        assert_ne!(page, Page::random(PageId(1), 112, 16, 1235));
+
+        // Roughly half the pixels should be lit.
+        let lit = page.lit_count();
+        assert!((112 * 16 / 3..112 * 16 * 2 / 3).contains(&lit), "{}", lit);
+
+        // Padding is untouched.
+        assert_eq!(Some(0xFF), Page::random(PageId(1), 90, 7, 1).padding_byte());
+    }
+
+    #[test]
+    fn custom_padding() {
+        let mut page = Page::new_with_padding(PageId(3), 90, 7, 0xA5);
+        page.set_all_pixels(true);
+        assert_eq!(&[0xA5, 0xA5], &page.as_bytes()[94..]);
+        assert_eq!(Some(0xA5), page.padding_byte());
+        assert!(page.get_pixel(89, 6));
+    }
+
+    #[test]
+    fn embed_centered() -> Result<(), Box<dyn Error>> {
+        let mut source = Page::checkerboard(PageId(1), 3, 3);
+        source.set_pixel(2, 2, false);
+
+        let page = source.embed_centered(PageId(5), 8, 4)?;
+        let mut expected = Page::new(PageId(5), 8, 4);
+        for (x, y) in [(2, 0), (4, 0), (3, 1), (2, 2)] {
+            expected.set_pixel(x, y, true);
+        }
+        assert_eq!(expected, page);
+
+        assert_eq!(
+            source.as_bytes()[HEADER_LEN..],
+            source.embed_centered(PageId(1), 3, 3)?.as_bytes()[HEADER_LEN..]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn embed_centered_too_large() {
+        let error = Page::new(PageId(1), 90, 7).embed_centered(PageId(1), 30, 10).unwrap_err();
+        assert!(matches!(
+            error,
+            PageError::TooLarge {
+                width: 90,
+                height: 7,
+                target_width: 30,
+                target_height: 10
+            }
+        ));
+    }
+
+    #[test]
+    fn assert_eq_visual_matching() {
+        let mut page = Page::new(PageId(1), 10, 5);
+        page.set_pixel(3, 2, true);
+        let mut other = Page::new(PageId(7), 10, 5);
+        other.set_pixel(3, 2, true);
+        page.assert_eq_visual(&other);
+    }
+
+    #[test]
+    #[should_panic(expected = "Pages differ in 2 pixel(s)")]
+    fn assert_eq_visual_pixel_mismatch() {
+        let mut page = Page::new(PageId(1), 10, 5);
+        page.set_pixel(3, 2, true);
+        let mut other = Page::new(PageId(1), 10, 5);
+        other.set_pixel(4, 2, true);
+        page.assert_eq_visual(&other);
+    }
+
+    #[test]
+    #[should_panic(expected = "Page dimensions differ: got 10 x 5, expected 10 x 6")]
+    fn assert_eq_visual_dimension_mismatch() {
+        Page::new(PageId(1), 10, 5).assert_eq_visual(&Page::new(PageId(1), 10, 6));
     }
 
     #[test]
-    fn one_byte_per_column_set_bits() -> Result<(), Box<dyn Error>> {
-        let mut page = Page::new(PageId(3), 90, 7);
+    fn side_by_side_layout() {
+        let mut page = Page::new(PageId(1), 2, 1);
         page.set_pixel(0, 0, true);
-        page.set_pixel(89, 5, true);
-        page.set_pixel(89, 6, true);
-        page.set_pixel(4, 4, true);
-        page.set_pixel(4, 4, false);
-        let bytes = page.as_bytes();
-        #[rustfmt::skip]
-        const EXPECTED: &[u8] = &[
-            0x03, 0x10, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0xFF, 0xFF,
-        ];
-        assert_eq!(bytes, EXPECTED);
+        let rendered = side_by_side(&[("Actual", &page), ("Expected", &Page::new(PageId(1), 2, 1))]);
+        assert_eq!("Actual  Expected\n+--+    +--+\n|@ |    |  |\n+--+    +--+\n", rendered);
+    }
 
-        let page2 = Page::from_bytes(90, 7, bytes)?;
-        assert_eq!(page, page2);
+    #[test]
+    fn write_frames() -> Result<(), Box<dyn Error>> {
+        let mut page = Page::new(PageId(2), 112, 16);
+        page.set_pixel(111, 15, true);
+
+        let mut output = Vec::new();
+        page.write_frames(&mut output)?;
+
+        let mut reader = &output[..];
+        let mut messages = Vec::new();
+        while !reader.is_empty() {
+            messages.push(Message::from(Frame::read(&mut reader)?));
+        }
 
+        let mut expected = send_data_messages(&page);
+        expected.push(Message::DataChunksSent(ChunkCount(15)));
+        assert_eq!(expected, messages);
         Ok(())
     }
 
     #[test]
-    fn two_bytes_per_column_set_bits() -> Result<(), Box<dyn Error>> {
-        let mut page = Page::new(PageId(1), 40, 12);
-        page.set_pixel(0, 0, true);
+    fn from_send_data() -> Result<(), Box<dyn Error>> {
+        let mut page = Page::new(PageId(4), 40, 12);
         page.set_pixel(0, 11, true);
-        page.set_pixel(39, 5, true);
-        page.set_pixel(39, 6, true);
-        page.set_pixel(39, 8, true);
-        page.set_pixel(4, 4, true);
-        page.set_pixel(4, 4, false);
-        let bytes = page.as_bytes();
-        #[rustfmt::skip]
-        const EXPECTED: &[u8] = &[
-            0x01, 0x10, 0x00, 0x00, 0x01, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x60, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
-        ];
-        assert_eq!(bytes, EXPECTED);
+        page.set_pixel(39, 0, true);
 
-        let page2 = Page::from_bytes(40, 12, bytes)?;
-        assert_eq!(page, page2);
+        let mut messages = send_data_messages(&page);
+        messages.swap(0, 3);
+        messages.insert(2, Message::QueryState(Address(3)));
+        messages.push(Message::DataChunksSent(ChunkCount(6)));
+
+        let reassembled = Page::from_send_data(40, 12, &messages)?;
+        assert_eq!(page, reassembled);
 
         Ok(())
     }
 
     #[test]
-    fn wrong_size_rejected() {
-        let error = Page::from_bytes(90, 7, vec![0x01, 0x01, 0x03]).unwrap_err();
+    fn from_send_data_gap() {
+        let page = Page::new(PageId(4), 40, 12);
+        let mut messages = send_data_messages(&page);
+        let _ = messages.remove(2);
+
+        let error = Page::from_send_data(40, 12, &messages).unwrap_err();
+        assert!(matches!(
+            error,
+            PageError::NonContiguousData {
+                expected: 32,
+                actual: 48
+            }
+        ));
+    }
+
+    #[test]
+    fn from_send_data_wrong_length() {
+        let page = Page::new(PageId(4), 40, 12);
+        let mut messages = send_data_messages(&page);
+        let _ = messages.pop();
+
+        let error = Page::from_send_data(40, 12, &messages).unwrap_err();
         assert!(matches!(
             error,
             PageError::WrongPageLength {
                 expected: 96,
-                actual: 3,
+                actual: 80,
                 ..
             }
         ));
@@ -496,14 +2202,14 @@ mod tests {
         let mut page = Page::new(PageId(1), 16, 16);
 
         page.set_pixel(0, 0, true);
-        assert_eq!(true, page.get_pixel(0, 0));
+        assert!(page.get_pixel(0, 0));
         page.set_pixel(0, 0, false);
-        assert_eq!(false, page.get_pixel(0, 0));
+        assert!(!page.get_pixel(0, 0));
 
         page.set_pixel(13, 10, true);
-        assert_eq!(true, page.get_pixel(13, 10));
+        assert!(page.get_pixel(13, 10));
         page.set_pixel(13, 10, false);
-        assert_eq!(false, page.get_pixel(13, 10));
+        assert!(!page.get_pixel(13, 10));
     }
 
     #[test]
@@ -534,6 +2240,475 @@ mod tests {
         assert_eq!(expected, display);
     }
 
+    #[test_case(90, 7 ; "one byte per column")]
+    #[test_case(40, 12 ; "two bytes per column")]
+    fn filled(width: u32, height: u32) {
+        let page = Page::filled(PageId(2), width, height, true);
+        verify_all_pixels(&page, true);
+        assert_eq!(PageId(2), page.id());
+        assert_eq!(Page::new(PageId(2), width, height).as_bytes().len(), page.as_bytes().len());
+        assert_eq!(0xFF, *page.as_bytes().last().unwrap());
+
+        let page = Page::filled(PageId(2), width, height, false);
+        assert_eq!(Page::new(PageId(2), width, height), page);
+    }
+
+    #[test]
+    fn checkerboard() {
+        let page = Page::checkerboard(PageId(1), 5, 12);
+        for x in 0..page.width() {
+            for y in 0..page.height() {
+                assert_eq!((x + y) % 2 == 0, page.get_pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn row_bits() {
+        let mut page = Page::new(PageId(1), 12, 10);
+        for &x in &[0, 2, 3, 8, 11] {
+            page.set_pixel(x, 9, true);
+        }
+        page.set_pixel(1, 8, true);
+
+        assert_eq!(vec![0b1011_0000, 0b1001_0000], page.row_bits(9));
+        assert_eq!(vec![0b0100_0000, 0b0000_0000], page.row_bits(8));
+        assert_eq!(vec![0b0000_0000, 0b0000_0000], page.row_bits(0));
+    }
+
+    #[test]
+    fn set_row_bits() {
+        let mut page = Page::new(PageId(1), 12, 10);
+        page.set_row_bits(9, &[0b1011_0000, 0b1001_1111]);
+        assert_eq!(vec![0b1011_0000, 0b1001_0000], page.row_bits(9));
+
+        let mut expected = Page::new(PageId(1), 12, 10);
+        for &x in &[0, 2, 3, 8, 11] {
+            expected.set_pixel(x, 9, true);
+        }
+        assert_eq!(expected, page);
+
+        page.set_row_bits(9, &[0x00, 0x00]);
+        assert_eq!(Page::new(PageId(1), 12, 10), page);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_row_bits_too_short() {
+        let mut page = Page::new(PageId(1), 12, 10);
+        page.set_row_bits(0, &[0xFF]);
+    }
+
+    #[test_case(BlitMode::Or, [false, true, true, true] ; "or")]
+    #[test_case(BlitMode::And, [false, false, false, true] ; "and")]
+    #[test_case(BlitMode::Xor, [false, true, true, false] ; "xor")]
+    fn combine(op: BlitMode, expected: [bool; 4]) -> Result<(), Box<dyn Error>> {
+        // Pixels (0, 10), (1, 10), (2, 10), (3, 10) cover the four input combinations.
+        let mut a = Page::new(PageId(1), 4, 12);
+        a.set_pixel(2, 10, true);
+        a.set_pixel(3, 10, true);
+        let mut b = Page::new(PageId(2), 4, 12);
+        b.set_pixel(1, 10, true);
+        b.set_pixel(3, 10, true);
+
+        let combined = a.combine(&b, op)?;
+        for (x, &value) in expected.iter().enumerate() {
+            assert_eq!(value, combined.get_pixel(x as u32, 10));
+        }
+        assert_eq!(&a.as_bytes()[..4], &combined.as_bytes()[..4]);
+        assert_eq!(a.as_bytes().len(), combined.as_bytes().len());
+        assert_eq!(0xFF, *combined.as_bytes().last().unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn combine_dimension_mismatch() {
+        let a = Page::new(PageId(1), 4, 12);
+        let b = Page::new(PageId(1), 4, 7);
+        let error = a.combine(&b, BlitMode::Xor).unwrap_err();
+        assert!(matches!(
+            error,
+            PageError::DimensionMismatch {
+                expected_height: 12,
+                actual_height: 7,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn header_fields() -> Result<(), Box<dyn Error>> {
+        let mut bytes = vec![0x07, 0x20, 0x03, 0x04];
+        bytes.extend_from_slice(&[0; 12]);
+        let page = Page::from_bytes(8, 8, bytes)?;
+
+        let header = page.header_fields();
+        assert_eq!(PageId(7), header.id);
+        assert_eq!(0x20, header.byte1);
+        assert_eq!(0x03, header.byte2);
+        assert_eq!(0x04, header.byte3);
+        assert_eq!([0x07, 0x20, 0x03, 0x04], header.to_bytes());
+        Ok(())
+    }
+
+    #[test]
+    fn set_header_fields_round_trip() {
+        let mut page = Page::random(PageId(1), 90, 7, 42);
+        let pixels = page.as_bytes()[HEADER_LEN..].to_vec();
+
+        let mut header = page.header_fields();
+        header.id = PageId(9);
+        header.byte1 = 0x20;
+        header.byte2 = 0x03;
+        header.byte3 = 0x04;
+        page.set_header_fields(header);
+
+        assert_eq!(header, page.header_fields());
+        assert_eq!(PageId(9), page.id());
+        assert_eq!(&[0x09, 0x20, 0x03, 0x04], &page.as_bytes()[..HEADER_LEN]);
+        assert_eq!(&pixels[..], &page.as_bytes()[HEADER_LEN..]);
+    }
+
+    #[test]
+    fn mask_single_column() -> Result<(), Box<dyn Error>> {
+        let mut page = Page::filled(PageId(1), 20, 12, true);
+        let mut mask = Page::new(PageId(2), 20, 12);
+        for y in 0..mask.height() {
+            mask.set_pixel(7, y, true);
+        }
+
+        page.mask(&mask)?;
+        for x in 0..page.width() {
+            for y in 0..page.height() {
+                assert_eq!(x == 7, page.get_pixel(x, y));
+            }
+        }
+        assert_eq!(PageId(1), page.id());
+        assert_eq!(0xFF, *page.as_bytes().last().unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn mask_dimension_mismatch() {
+        let mut page = Page::filled(PageId(1), 20, 12, true);
+        let error = page.mask(&Page::new(PageId(1), 20, 7)).unwrap_err();
+        assert!(matches!(error, PageError::DimensionMismatch { actual_height: 7, .. }));
+        assert_eq!(240, page.lit_count());
+    }
+
+    #[test]
+    fn union() -> Result<(), Box<dyn Error>> {
+        let mut a = Page::new(PageId(1), 4, 12);
+        a.set_pixel(0, 0, true);
+        let mut b = Page::new(PageId(2), 4, 12);
+        b.set_pixel(1, 11, true);
+        let mut c = Page::new(PageId(3), 4, 12);
+        c.set_pixel(0, 0, true);
+        c.set_pixel(3, 5, true);
+
+        let union = Page::union(&[a.clone(), b, c])?;
+        assert_eq!(PageId(1), union.id());
+        for x in 0..union.width() {
+            for y in 0..union.height() {
+                assert_eq!([(0, 0), (1, 11), (3, 5)].contains(&(x, y)), union.get_pixel(x, y));
+            }
+        }
+
+        assert_eq!(a, Page::union(&[a.clone()])?);
+        Ok(())
+    }
+
+    #[test]
+    fn union_errors() {
+        assert!(matches!(Page::union(&[]), Err(PageError::NoPages)));
+
+        let pages = [
+            Page::new(PageId(1), 4, 12),
+            Page::new(PageId(2), 4, 12),
+            Page::new(PageId(3), 5, 12),
+        ];
+        assert!(matches!(
+            Page::union(&pages),
+            Err(PageError::DimensionMismatch { actual_width: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn invert_rect() {
+        let mut page = Page::new(PageId(1), 8, 12);
+        page.set_pixel(2, 9, true);
+        page.invert_rect(1, 7, 3, 3);
+
+        for x in 0..page.width() {
+            for y in 0..page.height() {
+                let inside = (1..4).contains(&x) && (7..10).contains(&y);
+                assert_eq!(inside && (x, y) != (2, 9), page.get_pixel(x, y));
+            }
+        }
+        assert_eq!(&[0x01, 0x10, 0x00, 0x00], &page.as_bytes()[..4]);
+    }
+
+    #[test]
+    fn invert_rect_clipped() {
+        let mut page = Page::new(PageId(1), 8, 8);
+        page.invert_rect(6, 6, u32::MAX, u32::MAX);
+        page.invert_rect(20, 20, 5, 5);
+        page.invert_rect(0, 0, 0, 0);
+        for x in 0..page.width() {
+            for y in 0..page.height() {
+                assert_eq!(x >= 6 && y >= 6, page.get_pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn clear_rect() {
+        let mut page = Page::filled(PageId(1), 8, 12, true);
+        page.clear_rect(1, 7, 3, 3);
+        page.clear_rect(6, 10, u32::MAX, u32::MAX);
+        page.clear_rect(20, 20, 5, 5);
+
+        for x in 0..page.width() {
+            for y in 0..page.height() {
+                let inside = ((1..4).contains(&x) && (7..10).contains(&y)) || (x >= 6 && y >= 10);
+                assert_eq!(!inside, page.get_pixel(x, y));
+            }
+        }
+        assert_eq!(&[0x01, 0x10, 0x00, 0x00], &page.as_bytes()[..4]);
+    }
+
+    #[test_case(Page::new(PageId(3), 90, 7) ; "one byte per column")]
+    #[test_case(Page::new(PageId(1), 40, 12) ; "two bytes per column")]
+    fn lit_count_ignores_unused_bits(mut page: Page) {
+        assert_eq!(0, page.lit_count());
+        page.set_all_pixels(true);
+        assert_eq!(page.width() * page.height(), page.lit_count());
+        page.set_pixel(0, 0, false);
+        assert_eq!(page.width() * page.height() - 1, page.lit_count());
+    }
+
+    #[test]
+    fn lit_pixels_matches_lit_count() {
+        let page = Page::random(PageId(1), 40, 12, 7);
+        let lit = page.lit_pixels();
+        assert_eq!(page.lit_count() as usize, lit.len());
+        assert!(lit.iter().all(|&(x, y)| page.get_pixel(x, y)));
+        assert!(lit.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Unused bits in the last byte of each column are not reported.
+        let page = Page::filled(PageId(1), 2, 12, true);
+        assert_eq!(24, page.lit_pixels().len());
+    }
+
+    #[test_case(0, 0, 4, 0, &[(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)] ; "horizontal")]
+    #[test_case(2, 3, 2, 0, &[(2, 0), (2, 1), (2, 2), (2, 3)] ; "vertical reversed")]
+    #[test_case(0, 0, 4, 2, &[(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)] ; "shallow")]
+    #[test_case(4, 2, 0, 0, &[(0, 0), (1, 0), (2, 1), (3, 1), (4, 2)] ; "shallow reversed")]
+    #[test_case(1, 0, 0, 3, &[(1, 0), (1, 1), (0, 2), (0, 3)] ; "steep")]
+    #[test_case(8, 4, 12, 8, &[(8, 4), (9, 5)] ; "clipped")]
+    fn draw_line(x0: u32, y0: u32, x1: u32, y1: u32, expected: &[(u32, u32)]) {
+        let mut page = Page::new(PageId(1), 10, 6);
+        page.draw_line(x0, y0, x1, y1, true);
+        for x in 0..page.width() {
+            for y in 0..page.height() {
+                assert_eq!(expected.contains(&(x, y)), page.get_pixel(x, y), "({}, {})", x, y);
+            }
+        }
+
+        page.draw_line(x0, y0, x1, y1, false);
+        assert_eq!(0, page.lit_count());
+    }
+
+    #[test_case(1, Some(PageId(1)) ; "first")]
+    #[test_case(255, Some(PageId(255)) ; "last")]
+    #[test_case(0, None ; "zero")]
+    #[test_case(256, None ; "too large")]
+    fn page_id_from_sequence(sequence: u32, expected: Option<PageId>) {
+        let id = PageId::from_sequence(sequence).ok();
+        assert_eq!(expected, id);
+        if let Some(id) = id {
+            assert_eq!(sequence, u32::from(id.sequence()));
+        }
+    }
+
+    #[test]
+    fn draw_glyph_wide_and_clipped() {
+        // 10x2 glyph: a solid top row and a single pixel at the end of the bottom row.
+        const GLYPH: &[u8] = &[0xFF, 0xC0, 0x00, 0x40];
+
+        let mut page = Page::new(PageId(1), 12, 4);
+        page.draw_glyph(0, 0, GLYPH, 10, 2);
+        assert_eq!(11, page.lit_count());
+        assert!(page.get_pixel(9, 0));
+        assert!(page.get_pixel(9, 1));
+        assert!(!page.get_pixel(10, 0));
+
+        let mut page = Page::new(PageId(1), 12, 4);
+        page.draw_glyph(5, 3, GLYPH, 10, 2);
+        assert_eq!(7, page.lit_count());
+        for x in 5..12 {
+            assert!(page.get_pixel(x, 3));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn draw_glyph_too_short() {
+        let mut page = Page::new(PageId(1), 12, 4);
+        page.draw_glyph(0, 0, &[0xFF], 10, 2);
+    }
+
+    #[test]
+    fn draw_text_skips_unknown_characters() {
+        let font = Font::new(2, 1).with_glyph('x', vec![0xC0]).with_spacing(1);
+        let mut page = Page::new(PageId(1), 12, 4);
+        page.draw_text(0, 1, "x?x", &font);
+        assert_eq!(4, page.lit_count());
+        assert!(page.get_pixel(0, 1));
+        assert!(page.get_pixel(1, 1));
+        assert!(!page.get_pixel(3, 1));
+        assert!(page.get_pixel(6, 1));
+        assert!(page.get_pixel(7, 1));
+    }
+
+    #[test]
+    fn scroll_text_moves_left() -> Result<(), Box<dyn Error>> {
+        let font = Font::new(1, 1).with_glyph('x', vec![0x80]);
+        let pages = Page::scroll_text(PageId(251), 3, 3, "x", &font, 1)?;
+        assert_eq!(5, pages.len());
+
+        let ids: Vec<PageId> = pages.iter().map(Page::id).collect();
+        assert_eq!(vec![PageId(251), PageId(252), PageId(253), PageId(254), PageId(255)], ids);
+
+        let lit: Vec<Vec<(u32, u32)>> = pages
+            .iter()
+            .map(|page| {
+                (0..3)
+                    .flat_map(|x| (0..3).map(move |y| (x, y)))
+                    .filter(|&(x, y)| page.get_pixel(x, y))
+                    .collect()
+            })
+            .collect();
+        assert_eq!(vec![vec![], vec![(2, 1)], vec![(1, 1)], vec![(0, 1)], vec![]], lit);
+        Ok(())
+    }
+
+    #[test]
+    fn scroll_text_large_step_ends_blank() -> Result<(), Box<dyn Error>> {
+        let font = Font::ascii_5x7();
+        let pages = Page::scroll_text(PageId(1), 10, 7, "Hello", &font, 7)?;
+        // Distance is 10 + 29 = 39: offsets 0, 7, ..., 35, then 39.
+        assert_eq!(7, pages.len());
+        assert_eq!(0, pages[0].lit_count());
+        assert_eq!(0, pages[6].lit_count());
+        Ok(())
+    }
+
+    #[test]
+    fn scroll_text_runs_out_of_ids() {
+        let font = Font::new(1, 1).with_glyph('x', vec![0x80]);
+        let error = Page::scroll_text(PageId(252), 3, 3, "x", &font, 1).unwrap_err();
+        assert!(matches!(
+            error,
+            PageError::TooManyPages {
+                first: 252,
+                count: 5,
+                max: 255
+            }
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn scroll_text_zero_step() {
+        let _ = Page::scroll_text(PageId(1), 10, 7, "Hi", &Font::ascii_5x7(), 0);
+    }
+
+    #[test]
+    fn row_major_roundtrip() -> Result<(), Box<dyn Error>> {
+        for (width, height) in [(90, 7), (8, 8), (13, 16), (1, 1)] {
+            let page = Page::random(PageId(3), width, height, u64::from(width));
+            let bytes = page.to_row_major_bytes();
+            assert_eq!(height as usize * (width as usize).div_ceil(8), bytes.len());
+            assert_eq!(page, Page::from_row_major_bytes(PageId(3), width, height, &bytes)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn row_major_layout() -> Result<(), Box<dyn Error>> {
+        let mut page = Page::new(PageId(1), 9, 3);
+        page.set_pixel(1, 0, true);
+        page.set_pixel(8, 2, true);
+        assert_eq!(vec![0x40, 0x00, 0x00, 0x00, 0x00, 0x80], page.to_row_major_bytes());
+
+        // Bits past the page width are ignored.
+        let page = Page::from_row_major_bytes(PageId(1), 9, 3, &[0x40, 0x7F, 0, 0, 0, 0x80])?;
+        assert_eq!(2, page.lit_count());
+
+        let error = Page::from_row_major_bytes(PageId(1), 9, 3, &[0; 5]).unwrap_err();
+        assert!(matches!(
+            error,
+            PageError::WrongPageLength {
+                expected: 6,
+                actual: 5,
+                ..
+            }
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn paginate_text_wraps_words() -> Result<(), Box<dyn Error>> {
+        let font = Font::new(1, 1).with_glyph('a', vec![0x80]).with_glyph('b', vec![0x80]);
+        // Two characters fit per line and one line per page.
+        let pages = Page::paginate_text(PageId(251), 3, 1, "a b  ab\naaa", &font)?;
+
+        let ids: Vec<PageId> = pages.iter().map(Page::id).collect();
+        assert_eq!(vec![PageId(251), PageId(252), PageId(253), PageId(254), PageId(255)], ids);
+
+        let lit: Vec<Vec<u32>> = pages
+            .iter()
+            .map(|page| (0..3).filter(|&x| page.get_pixel(x, 0)).collect())
+            .collect();
+        assert_eq!(vec![vec![1], vec![1], vec![0, 2], vec![0, 2], vec![1]], lit);
+
+        let error = Page::paginate_text(PageId(252), 3, 1, "a b  ab\naaa", &font).unwrap_err();
+        assert!(matches!(
+            error,
+            PageError::TooManyPages {
+                first: 252,
+                count: 5,
+                ..
+            }
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn paginate_text_stacks_lines() -> Result<(), Box<dyn Error>> {
+        let font = Font::new(1, 1).with_glyph('a', vec![0x80]);
+        let pages = Page::paginate_text(PageId(1), 1, 4, "a a a", &font)?;
+        assert_eq!(2, pages.len());
+
+        let lit: Vec<Vec<u32>> = pages
+            .iter()
+            .map(|page| (0..4).filter(|&y| page.get_pixel(0, y)).collect())
+            .collect();
+        assert_eq!(vec![vec![0, 2], vec![1]], lit);
+        Ok(())
+    }
+
+    #[test]
+    fn paginate_blank_text() -> Result<(), Box<dyn Error>> {
+        let pages = Page::paginate_text(PageId(4), 90, 7, "  ", &Font::ascii_5x7())?;
+        assert_eq!(1, pages.len());
+        assert_eq!(PageId(4), pages[0].id());
+        assert_eq!(0, pages[0].lit_count());
+        Ok(())
+    }
+
     fn verify_all_pixels(page: &Page, value: bool) {
         for x in 0..page.width() {
             for y in 0..page.height() {
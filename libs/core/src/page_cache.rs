@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::Page;
+
+/// A collection of pre-rendered [`Page`]s, built on demand and reused on subsequent lookups.
+///
+/// Useful when repeatedly displaying a fixed set of messages, to avoid rendering the same
+/// content every time. Pages are keyed by an arbitrary value; [`Page::fingerprint`] is a good choice
+/// when the content itself identifies the page, since it ignores the page ID that would cause
+/// visually identical pages to compare unequal.
+///
+/// # Examples
+///
+/// ```
+/// use flipdot_core::{Page, PageCache, PageId};
+///
+/// let mut cache = PageCache::new();
+/// let page = cache.get_or_insert("hello", || Page::filled(PageId(1), 90, 7, true));
+/// assert!(page.get_pixel(0, 0));
+///
+/// // The builder is not called again for the same key.
+/// let page = cache.get_or_insert("hello", || unreachable!());
+/// assert!(page.get_pixel(0, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageCache<K: Eq + Hash> {
+    pages: HashMap<K, Page<'static>>,
+}
+
+impl<K: Eq + Hash> PageCache<K> {
+    /// Creates a new, empty `PageCache`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageCache;
+    /// let cache = PageCache::<u64>::new();
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        PageCache { pages: HashMap::new() }
+    }
+
+    /// Returns the page stored under `key`, first calling `build` to create it if not already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageCache, PageId};
+    /// let mut cache = PageCache::new();
+    /// let page = cache.get_or_insert(1, || Page::checkerboard(PageId(1), 30, 10));
+    /// assert_eq!(30, page.width());
+    /// ```
+    pub fn get_or_insert<F: FnOnce() -> Page<'static>>(&mut self, key: K, build: F) -> &Page<'static> {
+        self.pages.entry(key).or_insert_with(build)
+    }
+
+    /// Returns the page stored under `key`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageCache, PageId};
+    /// let mut cache = PageCache::new();
+    /// assert!(cache.get(&1).is_none());
+    /// let _ = cache.get_or_insert(1, || Page::new(PageId(1), 90, 7));
+    /// assert!(cache.get(&1).is_some());
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&Page<'static>> {
+        self.pages.get(key)
+    }
+
+    /// Removes and returns the page stored under `key`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageCache, PageId};
+    /// let mut cache = PageCache::new();
+    /// let _ = cache.get_or_insert(1, || Page::new(PageId(1), 90, 7));
+    /// assert!(cache.remove(&1).is_some());
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<Page<'static>> {
+        self.pages.remove(key)
+    }
+
+    /// Removes all pages from the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageCache, PageId};
+    /// let mut cache = PageCache::new();
+    /// let _ = cache.get_or_insert(1, || Page::new(PageId(1), 90, 7));
+    /// cache.clear();
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.pages.clear();
+    }
+
+    /// Returns the number of pages in the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageCache, PageId};
+    /// let mut cache = PageCache::new();
+    /// let _ = cache.get_or_insert(1, || Page::new(PageId(1), 90, 7));
+    /// let _ = cache.get_or_insert(2, || Page::new(PageId(2), 90, 7));
+    /// assert_eq!(2, cache.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Returns `true` if the cache contains no pages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageCache;
+    /// let cache = PageCache::<u64>::new();
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+}
+
+impl<K: Eq + Hash> Default for PageCache<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PageId;
+
+    #[test]
+    fn builds_once_per_key() {
+        let mut cache = PageCache::new();
+        let mut builds = 0;
+        for _ in 0..3 {
+            let _ = cache.get_or_insert("a", || {
+                builds += 1;
+                Page::new(PageId(1), 90, 7)
+            });
+        }
+        assert_eq!(1, builds);
+
+        let _ = cache.get_or_insert("b", || {
+            builds += 1;
+            Page::new(PageId(2), 90, 7)
+        });
+        assert_eq!(2, builds);
+        assert_eq!(2, cache.len());
+    }
+
+    #[test]
+    fn fingerprint_keys_ignore_page_id() {
+        let mut cache = PageCache::new();
+        let first = Page::filled(PageId(1), 90, 7, true);
+        let second = Page::filled(PageId(2), 90, 7, true);
+
+        let cached = cache.get_or_insert(first.fingerprint(), || first.clone());
+        assert_eq!(PageId(1), cached.id());
+
+        let cached = cache.get_or_insert(second.fingerprint(), || second.clone());
+        assert_eq!(PageId(1), cached.id());
+        assert_eq!(1, cache.len());
+
+        let different = Page::filled(PageId(1), 90, 7, false);
+        let _ = cache.get_or_insert(different.fingerprint(), || different.clone());
+        assert_eq!(2, cache.len());
+    }
+}
@@ -1,7 +1,8 @@
-use std::error::Error;
-use std::fmt::{self, Debug, Formatter};
+use alloc::boxed::Box;
+use core::error::Error;
+use core::fmt::{self, Debug, Formatter};
 
-use crate::Message;
+use crate::{Address, Message, SignType};
 
 /// Abstraction over a bus containing devices that are able to send and receive [`Message`]s.
 ///
@@ -69,6 +70,49 @@ pub trait SignBus {
     ///
     /// [trait-level documentation]: #examples
     fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>>;
+
+    /// Returns the [`SignType`] that the sign at `address` recognized from the configuration it was sent, if known.
+    ///
+    /// The sign protocol provides no way to ask a real sign for this, so the default implementation
+    /// returns `None`. Simulated buses such as `VirtualSignBus` from [`flipdot-testing`] override it
+    /// to report what was actually decoded, which is useful for confirming a configuration in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, PageFlipStyle, SignBus};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// assert_eq!(None, bus.recognized_sign_type(Address(3)));
+    /// ```
+    ///
+    /// [`flipdot-testing`]: https://docs.rs/flipdot_testing
+    fn recognized_sign_type(&self, _address: Address) -> Option<SignType> {
+        None
+    }
+
+    /// Returns whether this bus communicates with real hardware, or `None` if it doesn't say.
+    ///
+    /// Tools that only have a `dyn SignBus` can use this to adapt their behavior, e.g. asking for
+    /// confirmation before destructive operations. Since a bus that doesn't override this might well drive
+    /// real signs, callers should treat `None` as cautiously as `Some(true)`. The default implementation
+    /// returns `None`; `SerialSignBus` from [`flipdot-serial`] returns `Some(true)`, and `VirtualSignBus`
+    /// from [`flipdot-testing`] returns `Some(false)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, PageFlipStyle, SignBus};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// assert_eq!(Some(false), bus.is_physical());
+    /// ```
+    ///
+    /// [`flipdot-serial`]: https://docs.rs/flipdot_serial
+    /// [`flipdot-testing`]: https://docs.rs/flipdot_testing
+    fn is_physical(&self) -> Option<bool> {
+        None
+    }
 }
 
 // Provide a Debug representation so types that contain trait objects can derive Debug.
@@ -1,5 +1,12 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::ops::Range;
+
 use thiserror::Error;
 
+use crate::{Address, Data, Message, Offset, Page};
+
 /// Errors related to [`SignType`]s.
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -20,6 +27,149 @@ pub enum SignTypeError {
         /// The provided configuration data.
         bytes: Vec<u8>,
     },
+
+    /// Name didn't match any known [`SignType`].
+    #[error("Name didn't match any known sign: {:?}", name)]
+    UnknownName {
+        /// The provided name.
+        name: String,
+    },
+
+    /// Dimensions didn't match any known [`SignType`].
+    #[error("Dimensions didn't match any known sign: {} × {}", width, height)]
+    UnknownDimensions {
+        /// The provided width.
+        width: u32,
+
+        /// The provided height.
+        height: u32,
+    },
+
+    /// Dimensions matched more than one known [`SignType`].
+    #[error("Dimensions {} × {} match multiple signs: {:?}", width, height, candidates)]
+    AmbiguousDimensions {
+        /// The provided width.
+        width: u32,
+
+        /// The provided height.
+        height: u32,
+
+        /// The sign types that share these dimensions.
+        candidates: Vec<SignType>,
+    },
+
+    /// Sign specification was not of the form `address:name`.
+    #[error("Invalid sign specification {:?}: Expected address:name, e.g. 3:Max3000Side90x7", spec)]
+    InvalidSpec {
+        /// The provided specification.
+        spec: String,
+    },
+}
+
+/// The arrangement of physical sub-panels that make up a sign's width, decoded from its configuration data.
+///
+/// The panels are listed in configuration order, which is assumed to run left to right across the sign.
+/// This has not been verified for every sign, so the layout may help explain output that looks scrambled.
+///
+/// # Examples
+///
+/// ```
+/// # use flipdot_core::{PanelGroup, PanelLayout, SignType};
+/// let layout = SignType::HorizonFront140x16.panel_layout();
+/// assert_eq!(
+///     PanelLayout::Horizon {
+///         groups: [PanelGroup { count: 1, panel_width: 20 }, PanelGroup { count: 3, panel_width: 40 }]
+///     },
+///     layout
+/// );
+/// assert_eq!(140, layout.width());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PanelLayout {
+    /// Up to four segments of the given widths (`W1` through `W4`); unused segments have zero width.
+    Max3000 {
+        /// The width of each segment in pixels.
+        segment_widths: [u8; 4],
+    },
+
+    /// Two groups of identically-sized panels (`A1 × B1` and `A2 × B2`); an unused group has a count of zero.
+    Horizon {
+        /// The panel groups.
+        groups: [PanelGroup; 2],
+    },
+}
+
+/// A run of identically-sized panels within a [`PanelLayout::Horizon`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PanelGroup {
+    /// The number of panels in the group (`A`).
+    pub count: u8,
+
+    /// The width of each panel in pixels (`B`).
+    pub panel_width: u8,
+}
+
+impl PanelLayout {
+    /// Returns the total width of all panels in pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::SignType;
+    /// assert_eq!(98, SignType::Max3000Front98x16.panel_layout().width());
+    /// ```
+    pub fn width(&self) -> u32 {
+        self.panel_columns().last().map_or(0, |columns| columns.end)
+    }
+
+    /// Returns the range of logical columns covered by each physical panel, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::SignType;
+    /// let columns = SignType::HorizonSide96x8.panel_layout().panel_columns();
+    /// assert_eq!(vec![0..48, 48..96], columns);
+    /// ```
+    pub fn panel_columns(&self) -> Vec<Range<u32>> {
+        let widths: Vec<u32> = match *self {
+            PanelLayout::Max3000 { segment_widths } => segment_widths.iter().copied().map(u32::from).collect(),
+            PanelLayout::Horizon { groups } => groups
+                .iter()
+                .flat_map(|group| core::iter::repeat_n(u32::from(group.panel_width), usize::from(group.count)))
+                .collect(),
+        };
+
+        let mut start = 0;
+        widths
+            .into_iter()
+            .filter(|&width| width > 0)
+            .map(|width| {
+                let columns = start..start + width;
+                start += width;
+                columns
+            })
+            .collect()
+    }
+}
+
+/// How two sign configurations relate to each other, as determined by [`SignType::classify_configs`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfigRelation {
+    /// The configurations are byte-for-byte identical.
+    Identical,
+
+    /// The family and ID bytes (0 and 1) match, but other bytes differ, suggesting a revision
+    /// of the same model rather than a new one.
+    SameModel,
+
+    /// The family byte (0) matches, but the ID byte (1) differs, suggesting a different model in the same family.
+    SameFamily,
+
+    /// The configurations are from different families, or are too short to tell.
+    Different,
 }
 
 /// The configuration information for a particular model of sign.
@@ -63,7 +213,7 @@ pub enum SignTypeError {
 /// Max3000 signs have an initial byte of `0x04`. `ID` is a unique ID for the particular sign type
 /// within the family, e.g. the 90 × 7 side sign has ID `0x20`. Byte 2 seems to always be zero,
 /// and byte 3 is unknown. `H` is the height in pixels, and `W1 + W2 + W3 + W4` is the
-/// total width. `B` indicates the number of bits per column (either 8 or 16). The remaining
+/// total width (see [`SignType::panel_layout`]). `B` indicates the number of bits per column (either 8 or 16). The remaining
 /// bytes appear unused and are always zero.
 ///
 /// ## Horizon
@@ -79,8 +229,12 @@ pub enum SignTypeError {
 /// within the family, e.g. the 96 × 8 side sign has ID `0xB4`. Byte 2 seems to always be zero,
 /// and bytes 3 and 4 are unknown. `H` is the height in pixels, and `W` is the width. The next
 /// four bytes seem to indicate the arrangement of sub-panels to create the final width:
-/// `W = A1 × B1 + A2 × B2`. Byte 12 is unknown (generally zero but `0x04` for the 40 × 12 dash sign).
-/// The remaining bytes appear unused and are always zero.
+/// `W = A1 × B1 + A2 × B2`, where `A` is a number of panels and `B` is their width
+/// (see [`SignType::panel_layout`]). Byte 12 is unknown (generally zero but `0x04` for the 40 × 12 dash sign;
+/// see [`SignType::horizon_flags`]). The remaining bytes appear unused and are always zero.
+///
+/// Only the first two bytes are used to identify the sign type, so variants that differ elsewhere
+/// (such as in byte 12) are still recognized.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum SignType {
@@ -109,6 +263,28 @@ pub enum SignType {
 }
 
 impl SignType {
+    /// All known sign types, in declaration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::SignType;
+    /// assert!(SignType::ALL.contains(&SignType::Max3000Side90x7));
+    /// ```
+    pub const ALL: &'static [SignType] = &[
+        SignType::Max3000Front112x16,
+        SignType::Max3000Front98x16,
+        SignType::Max3000Side90x7,
+        SignType::Max3000Rear30x10,
+        SignType::Max3000Rear23x10,
+        SignType::Max3000Dash30x7,
+        SignType::HorizonFront160x16,
+        SignType::HorizonFront140x16,
+        SignType::HorizonSide96x8,
+        SignType::HorizonRear48x16,
+        SignType::HorizonDash40x12,
+    ];
+
     /// Converts a slice representing configuration data into a `SignType`.
     ///
     /// # Errors
@@ -155,6 +331,193 @@ impl SignType {
         }
     }
 
+    /// Compares two raw configurations and reports whether they describe the same model, family, or neither.
+    ///
+    /// Only the family byte (0) and ID byte (1) are used to identify a model (see the format details above),
+    /// so two configurations that agree on those but differ elsewhere are likely variants of the same model.
+    /// This helps decide whether a newly captured configuration warrants a new `SignType` variant. Neither
+    /// configuration need correspond to a known sign type or be 16 bytes long.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{ConfigRelation, SignType};
+    /// let side = SignType::Max3000Side90x7.to_bytes();
+    /// let front = SignType::Max3000Front112x16.to_bytes();
+    /// let horizon = SignType::HorizonSide96x8.to_bytes();
+    /// assert_eq!(ConfigRelation::Identical, SignType::classify_configs(side, side));
+    /// assert_eq!(ConfigRelation::SameFamily, SignType::classify_configs(side, front));
+    /// assert_eq!(ConfigRelation::Different, SignType::classify_configs(side, horizon));
+    ///
+    /// let mut revised = side.to_vec();
+    /// revised[12] = 0x04;
+    /// assert_eq!(ConfigRelation::SameModel, SignType::classify_configs(side, &revised));
+    /// ```
+    pub fn classify_configs(a: &[u8], b: &[u8]) -> ConfigRelation {
+        if a == b {
+            ConfigRelation::Identical
+        } else if a.len() >= 2 && b.len() >= 2 && a[..2] == b[..2] {
+            ConfigRelation::SameModel
+        } else if !a.is_empty() && !b.is_empty() && a[0] == b[0] {
+            ConfigRelation::SameFamily
+        } else {
+            ConfigRelation::Different
+        }
+    }
+
+    /// Produces a human-readable, field-by-field breakdown of raw configuration data.
+    ///
+    /// Each byte is labeled according to the layout documented above for its family, and bytes that
+    /// are normally zero but aren't, or whose meaning is unknown, are flagged. The data need not
+    /// correspond to a known sign type (or even be the right length), which makes this useful for
+    /// explaining configurations captured from unfamiliar signs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::SignType;
+    /// let bytes = [0x04, 0x21, 0x00, 0x06, 0x07, 0x10, 0x10, 0x10, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    /// let description = SignType::describe_config(&bytes);
+    /// assert!(description.contains("Max3000"));
+    /// assert!(description.contains("Height: 7"));
+    /// assert!(description.contains("Total width: 48"));
+    /// println!("{}", description);
+    /// ```
+    pub fn describe_config(bytes: &[u8]) -> String {
+        ConfigDescription(bytes).to_string()
+    }
+
+    /// Looks up a `SignType` by its name, as returned by [`name`](Self::name).
+    ///
+    /// Matching is case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SignTypeError::UnknownName`] if the name does not correspond to a known sign type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::SignType;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// assert_eq!(SignType::Max3000Side90x7, SignType::from_name("Max3000Side90x7")?);
+    /// assert_eq!(SignType::HorizonDash40x12, SignType::from_name("horizondash40x12")?);
+    /// assert!(SignType::from_name("Max3000Side91x7").is_err());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn from_name(name: &str) -> Result<Self, SignTypeError> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|sign_type| sign_type.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| SignTypeError::UnknownName { name: name.into() })
+    }
+
+    /// Looks up the `SignType` with the given dimensions, in pixels.
+    ///
+    /// Useful when the physical size of a sign is known but not its exact model.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignTypeError::UnknownDimensions`] if no known sign type has these dimensions.
+    /// * [`SignTypeError::AmbiguousDimensions`] if more than one known sign type has these dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::SignType;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// assert_eq!(SignType::Max3000Side90x7, SignType::from_dimensions(90, 7)?);
+    /// assert!(SignType::from_dimensions(91, 7).is_err());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn from_dimensions(width: u32, height: u32) -> Result<Self, SignTypeError> {
+        Self::from_dimensions_in(Self::ALL, width, height)
+    }
+
+    /// Finds the single sign type among `sign_types` with the given dimensions.
+    fn from_dimensions_in(sign_types: &[SignType], width: u32, height: u32) -> Result<Self, SignTypeError> {
+        let candidates: Vec<SignType> = sign_types
+            .iter()
+            .copied()
+            .filter(|sign_type| sign_type.dimensions() == (width, height))
+            .collect();
+
+        match candidates[..] {
+            [] => Err(SignTypeError::UnknownDimensions { width, height }),
+            [sign_type] => Ok(sign_type),
+            _ => Err(SignTypeError::AmbiguousDimensions {
+                width,
+                height,
+                candidates,
+            }),
+        }
+    }
+
+    /// Parses a sign specification of the form `address:name` into an [`Address`] and `SignType`.
+    ///
+    /// The address is a decimal number and the name is as accepted by [`from_name`](Self::from_name).
+    /// Surrounding whitespace is ignored. Useful for accepting sign descriptions on the command line.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignTypeError::InvalidSpec`] if the string is not of the form `address:name` or the address is invalid.
+    /// * [`SignTypeError::UnknownName`] if the name does not correspond to a known sign type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, SignType};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let (address, sign_type) = SignType::from_spec("3:Max3000Side90x7")?;
+    /// assert_eq!(Address(3), address);
+    /// assert_eq!(SignType::Max3000Side90x7, sign_type);
+    ///
+    /// assert!(SignType::from_spec("Max3000Side90x7").is_err());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn from_spec(spec: &str) -> Result<(Address, Self), SignTypeError> {
+        let invalid_spec = || SignTypeError::InvalidSpec { spec: spec.into() };
+
+        let (address, name) = spec.split_once(':').ok_or_else(invalid_spec)?;
+        let address = address.trim().parse::<u16>().map_err(|_| invalid_spec())?;
+        let sign_type = Self::from_name(name.trim())?;
+        Ok((Address(address), sign_type))
+    }
+
+    /// Gets the name of this sign type, which matches the name of the enum variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::SignType;
+    /// assert_eq!("Max3000Side90x7", SignType::Max3000Side90x7.name());
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            SignType::Max3000Front112x16 => "Max3000Front112x16",
+            SignType::Max3000Front98x16 => "Max3000Front98x16",
+            SignType::Max3000Side90x7 => "Max3000Side90x7",
+            SignType::Max3000Rear30x10 => "Max3000Rear30x10",
+            SignType::Max3000Rear23x10 => "Max3000Rear23x10",
+            SignType::Max3000Dash30x7 => "Max3000Dash30x7",
+
+            SignType::HorizonFront160x16 => "HorizonFront160x16",
+            SignType::HorizonFront140x16 => "HorizonFront140x16",
+            SignType::HorizonSide96x8 => "HorizonSide96x8",
+            SignType::HorizonRear48x16 => "HorizonRear48x16",
+            SignType::HorizonDash40x12 => "HorizonDash40x12",
+        }
+    }
+
     /// Gets the dimensions (width, height), in pixels, of this sign type.
     ///
     /// # Examples
@@ -181,6 +544,108 @@ impl SignType {
         }
     }
 
+    /// Returns whether `page` has the same dimensions as this sign type, and can therefore be displayed on it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Page, PageId, SignType};
+    /// let sign_type = SignType::Max3000Side90x7;
+    /// assert!(sign_type.fits(&Page::new(PageId(1), 90, 7)));
+    /// assert!(!sign_type.fits(&Page::new(PageId(1), 112, 16)));
+    /// ```
+    pub fn fits(self, page: &Page<'_>) -> bool {
+        self.dimensions() == (page.width(), page.height())
+    }
+
+    /// Decodes the arrangement of sub-panels that make up this sign type's width.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{PanelLayout, SignType};
+    /// let layout = SignType::Max3000Front98x16.panel_layout();
+    /// assert_eq!(PanelLayout::Max3000 { segment_widths: [14, 28, 28, 28] }, layout);
+    /// assert_eq!(vec![0..14, 14..42, 42..70, 70..98], layout.panel_columns());
+    /// ```
+    pub fn panel_layout(self) -> PanelLayout {
+        let bytes = self.to_bytes();
+        match bytes[0] {
+            0x04 => PanelLayout::Max3000 {
+                segment_widths: [bytes[5], bytes[6], bytes[7], bytes[8]],
+            },
+            0x08 => PanelLayout::Horizon {
+                groups: [
+                    PanelGroup {
+                        count: bytes[8],
+                        panel_width: bytes[10],
+                    },
+                    PanelGroup {
+                        count: bytes[9],
+                        panel_width: bytes[11],
+                    },
+                ],
+            },
+            family => unreachable!("Unknown sign family {:#04X}", family),
+        }
+    }
+
+    /// Returns the number of bits used to store each column of pixels, as declared in the configuration.
+    ///
+    /// Max3000 signs declare this in byte 9 of their configuration (either 8 or 16). Horizon signs
+    /// don't declare it, so `None` is returned for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::SignType;
+    /// assert_eq!(Some(8), SignType::Max3000Side90x7.bits_per_column());
+    /// assert_eq!(Some(16), SignType::Max3000Rear30x10.bits_per_column());
+    /// assert_eq!(None, SignType::HorizonSide96x8.bits_per_column());
+    /// ```
+    pub fn bits_per_column(self) -> Option<u32> {
+        let bytes = self.to_bytes();
+        (bytes[0] == 0x04).then_some(u32::from(bytes[9]))
+    }
+
+    /// Returns the raw value of byte 12 of a Horizon sign's configuration, or `None` for other families.
+    ///
+    /// The meaning of this byte is not yet known; it is zero for most Horizon signs but `0x04`
+    /// for the 40 × 12 dash sign. It is exposed so that variants can be cataloged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::SignType;
+    /// assert_eq!(Some(0x04), SignType::HorizonDash40x12.horizon_flags());
+    /// assert_eq!(Some(0x00), SignType::HorizonSide96x8.horizon_flags());
+    /// assert_eq!(None, SignType::Max3000Side90x7.horizon_flags());
+    /// ```
+    pub fn horizon_flags(self) -> Option<u8> {
+        let bytes = self.to_bytes();
+        (bytes[0] == 0x08).then_some(bytes[12])
+    }
+
+    /// Returns the [`SendData`](Message::SendData) message that transmits this sign type's configuration.
+    ///
+    /// This is exactly what is sent to configure a sign, so it can be compared against a capture from a real ODK
+    /// without running a live session. Convert it into a [`Frame`](crate::Frame) to see the bytes on the wire.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Frame, Message, Offset, SignType};
+    /// let message = SignType::Max3000Side90x7.config_message();
+    /// assert!(matches!(message, Message::SendData(Offset(0), _)));
+    ///
+    /// let frame = Frame::from(message);
+    /// assert_eq!(SignType::Max3000Side90x7.to_bytes(), &frame.data()[..]);
+    /// ```
+    pub fn config_message(self) -> Message<'static> {
+        // Safe to unwrap as the configuration is always 16 bytes.
+        Message::SendData(Offset(0), Data::try_new(self.to_bytes()).unwrap())
+    }
+
     /// Gets the 16-byte configuration data for this sign type.
     ///
     /// # Examples
@@ -231,11 +696,175 @@ impl SignType {
     }
 }
 
+/// Formats a field-by-field breakdown of raw configuration data for [`SignType::describe_config`].
+struct ConfigDescription<'a>(&'a [u8]);
+
+impl ConfigDescription<'_> {
+    /// Writes a single labeled byte, if present.
+    fn field(&self, f: &mut Formatter<'_>, index: usize, label: fmt::Arguments<'_>) -> fmt::Result {
+        match self.0.get(index) {
+            Some(byte) => writeln!(f, "{:>2}  0x{:02X}  {}", index, byte, label),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes a byte that is normally zero, flagging it if it isn't.
+    fn zero_field(&self, f: &mut Formatter<'_>, index: usize, label: &str) -> fmt::Result {
+        match self.0.get(index) {
+            Some(0) | None => self.field(f, index, format_args!("{}", label)),
+            Some(_) => self.field(f, index, format_args!("{} [unexpected: normally 0x00]", label)),
+        }
+    }
+
+    /// Returns the byte at `index`, or zero if the data is too short.
+    fn byte(&self, index: usize) -> u8 {
+        self.0.get(index).copied().unwrap_or(0)
+    }
+}
+
+impl Display for ConfigDescription<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let bytes = self.0;
+        if bytes.len() != 16 {
+            writeln!(f, "Warning: expected 16 bytes, got {}", bytes.len())?;
+        }
+        match SignType::from_bytes(bytes) {
+            Ok(sign_type) => writeln!(f, "Recognized as {} ({})", sign_type.name(), sign_type)?,
+            Err(_) => writeln!(f, "Not a known sign type")?,
+        }
+
+        match bytes.first() {
+            Some(0x04) => {
+                self.field(f, 0, format_args!("Family: Max3000"))?;
+                self.field(f, 1, format_args!("Sign type ID"))?;
+                self.zero_field(f, 2, "Reserved")?;
+                self.field(f, 3, format_args!("Unknown"))?;
+                self.field(f, 4, format_args!("Height: {}", self.byte(4)))?;
+                for (segment, index) in (5..9).enumerate() {
+                    self.field(f, index, format_args!("Segment {} width: {}", segment + 1, self.byte(index)))?;
+                }
+                let bits_note = if matches!(self.byte(9), 8 | 16) {
+                    ""
+                } else {
+                    " [unexpected: normally 8 or 16]"
+                };
+                self.field(f, 9, format_args!("Bits per column: {}{}", self.byte(9), bits_note))?;
+                for index in 10..16 {
+                    self.zero_field(f, index, "Unused")?;
+                }
+
+                let width: u32 = (5..9).map(|index| u32::from(self.byte(index))).sum();
+                writeln!(f, "Total width: {}", width)?;
+            }
+
+            Some(0x08) => {
+                self.field(f, 0, format_args!("Family: Horizon"))?;
+                self.field(f, 1, format_args!("Sign type ID"))?;
+                self.zero_field(f, 2, "Reserved")?;
+                self.field(f, 3, format_args!("Unknown"))?;
+                self.field(f, 4, format_args!("Unknown"))?;
+                self.field(f, 5, format_args!("Height: {}", self.byte(5)))?;
+                self.zero_field(f, 6, "Reserved")?;
+                self.field(f, 7, format_args!("Width: {}", self.byte(7)))?;
+                self.field(f, 8, format_args!("Panel group 1 count: {}", self.byte(8)))?;
+                self.field(f, 9, format_args!("Panel group 2 count: {}", self.byte(9)))?;
+                self.field(f, 10, format_args!("Panel group 1 width: {}", self.byte(10)))?;
+                self.field(f, 11, format_args!("Panel group 2 width: {}", self.byte(11)))?;
+                self.field(f, 12, format_args!("Unknown (0x04 on the 40 × 12 dash sign, otherwise 0x00)"))?;
+                for index in 13..16 {
+                    self.zero_field(f, index, "Unused")?;
+                }
+
+                let panel_width =
+                    u32::from(self.byte(8)) * u32::from(self.byte(10)) + u32::from(self.byte(9)) * u32::from(self.byte(11));
+                write!(f, "Panel width: {}", panel_width)?;
+                if panel_width != u32::from(self.byte(7)) {
+                    write!(f, " [does not match declared width {}]", self.byte(7))?;
+                }
+                writeln!(f)?;
+            }
+
+            _ => {
+                for index in 0..bytes.len() {
+                    self.field(
+                        f,
+                        index,
+                        format_args!("{}", if index == 0 { "Family: unknown" } else { "Unknown" }),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for SignType {
+    /// Formats the sign type as a human-readable summary, e.g. `MAX3000 Side (90×7)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::SignType;
+    /// assert_eq!("MAX3000 Side (90×7)", SignType::Max3000Side90x7.to_string());
+    /// assert_eq!("Horizon Dash (40×12)", SignType::HorizonDash40x12.to_string());
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (family, position) = match self {
+            SignType::Max3000Front112x16 | SignType::Max3000Front98x16 => ("MAX3000", "Front"),
+            SignType::Max3000Side90x7 => ("MAX3000", "Side"),
+            SignType::Max3000Rear30x10 | SignType::Max3000Rear23x10 => ("MAX3000", "Rear"),
+            SignType::Max3000Dash30x7 => ("MAX3000", "Dash"),
+
+            SignType::HorizonFront160x16 | SignType::HorizonFront140x16 => ("Horizon", "Front"),
+            SignType::HorizonSide96x8 => ("Horizon", "Side"),
+            SignType::HorizonRear48x16 => ("Horizon", "Rear"),
+            SignType::HorizonDash40x12 => ("Horizon", "Dash"),
+        };
+        let (width, height) = self.dimensions();
+        write!(f, "{} {} ({}×{})", family, position, width, height)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Frame, MsgType, PageId};
     use std::error::Error;
 
+    #[test]
+    fn classify_configs_edge_cases() {
+        let side = SignType::Max3000Side90x7.to_bytes();
+        assert_eq!(ConfigRelation::SameModel, SignType::classify_configs(side, &side[..2]));
+        assert_eq!(ConfigRelation::SameFamily, SignType::classify_configs(side, &side[..1]));
+        assert_eq!(ConfigRelation::Different, SignType::classify_configs(side, &[]));
+        assert_eq!(ConfigRelation::Identical, SignType::classify_configs(&[], &[]));
+
+        for &a in SignType::ALL {
+            for &b in SignType::ALL {
+                let expected = if a == b {
+                    ConfigRelation::Identical
+                } else if a.to_bytes()[0] == b.to_bytes()[0] {
+                    ConfigRelation::SameFamily
+                } else {
+                    ConfigRelation::Different
+                };
+                assert_eq!(expected, SignType::classify_configs(a.to_bytes(), b.to_bytes()));
+            }
+        }
+    }
+
+    #[test]
+    fn config_message_matches_bytes() -> Result<(), Box<dyn Error>> {
+        for &sign_type in SignType::ALL {
+            let frame = Frame::from(sign_type.config_message());
+            assert_eq!(sign_type, SignType::from_bytes(frame.data())?);
+            assert_eq!(MsgType(0), frame.message_type());
+            assert_eq!(Address(0), frame.address());
+        }
+        Ok(())
+    }
+
     fn verify_roundtrip(sign_type: SignType, expected_bytes: &[u8]) -> Result<(), Box<dyn Error>> {
         let encoded = sign_type.to_bytes();
         assert_eq!(expected_bytes, encoded);
@@ -344,6 +973,104 @@ mod tests {
         assert_eq!((40, 12), SignType::HorizonDash40x12.dimensions());
     }
 
+    #[test]
+    fn name_roundtrip() -> Result<(), Box<dyn Error>> {
+        for &sign_type in SignType::ALL {
+            assert_eq!(format!("{:?}", sign_type), sign_type.name());
+            assert_eq!(sign_type, SignType::from_name(sign_type.name())?);
+            assert_eq!(sign_type, SignType::from_name(&sign_type.name().to_uppercase())?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn display_summary() {
+        let summaries: Vec<String> = SignType::ALL.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            vec![
+                "MAX3000 Front (112×16)",
+                "MAX3000 Front (98×16)",
+                "MAX3000 Side (90×7)",
+                "MAX3000 Rear (30×10)",
+                "MAX3000 Rear (23×10)",
+                "MAX3000 Dash (30×7)",
+                "Horizon Front (160×16)",
+                "Horizon Front (140×16)",
+                "Horizon Side (96×8)",
+                "Horizon Rear (48×16)",
+                "Horizon Dash (40×12)",
+            ],
+            summaries
+        );
+    }
+
+    #[test]
+    fn unknown_name_rejected() {
+        let error = SignType::from_name("Max3000").unwrap_err();
+        assert!(matches!(error, SignTypeError::UnknownName { ref name } if name == "Max3000"));
+    }
+
+    #[test]
+    fn dimensions_roundtrip() -> Result<(), Box<dyn Error>> {
+        for &sign_type in SignType::ALL {
+            let (width, height) = sign_type.dimensions();
+            assert_eq!(sign_type, SignType::from_dimensions(width, height)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_dimensions_rejected() {
+        let error = SignType::from_dimensions(90, 8).unwrap_err();
+        assert!(matches!(error, SignTypeError::UnknownDimensions { width: 90, height: 8 }));
+    }
+
+    #[test]
+    fn ambiguous_dimensions_rejected() {
+        let sign_types = [
+            SignType::Max3000Side90x7,
+            SignType::Max3000Dash30x7,
+            SignType::Max3000Side90x7,
+        ];
+        let error = SignType::from_dimensions_in(&sign_types, 90, 7).unwrap_err();
+        assert!(matches!(
+            error,
+            SignTypeError::AmbiguousDimensions { width: 90, height: 7, ref candidates }
+                if candidates == &[SignType::Max3000Side90x7, SignType::Max3000Side90x7]
+        ));
+    }
+
+    #[test]
+    fn parse_spec() -> Result<(), Box<dyn Error>> {
+        assert_eq!(
+            (Address(3), SignType::Max3000Side90x7),
+            SignType::from_spec("3:Max3000Side90x7")?
+        );
+        assert_eq!(
+            (Address(126), SignType::HorizonRear48x16),
+            SignType::from_spec(" 126 : HorizonRear48x16 ")?
+        );
+
+        assert!(matches!(
+            SignType::from_spec("3-Max3000Side90x7"),
+            Err(SignTypeError::InvalidSpec { .. })
+        ));
+        assert!(matches!(
+            SignType::from_spec("x:Max3000Side90x7"),
+            Err(SignTypeError::InvalidSpec { .. })
+        ));
+        assert!(matches!(
+            SignType::from_spec("70000:Max3000Side90x7"),
+            Err(SignTypeError::InvalidSpec { .. })
+        ));
+        assert!(matches!(
+            SignType::from_spec("3:Nope"),
+            Err(SignTypeError::UnknownName { .. })
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn unknown_type_rejected() {
         let data = vec![
@@ -362,6 +1089,50 @@ mod tests {
         assert!(matches!(error, SignTypeError::UnknownConfig { .. }));
     }
 
+    #[test]
+    fn describe_known_configs() {
+        for &sign_type in SignType::ALL {
+            let description = SignType::describe_config(sign_type.to_bytes());
+            assert!(description.starts_with(&format!("Recognized as {}", sign_type.name())));
+            assert!(!description.contains("unexpected"), "{}", description);
+            assert!(!description.contains("does not match"), "{}", description);
+        }
+    }
+
+    #[test]
+    fn describe_flags_anomalies() {
+        let data = [
+            0x08, 0xBA, 0x01, 0x06, 0x8C, 0x0C, 0x00, 0x28, 0x01, 0x00, 0x20, 0x00, 0x04, 0x00, 0x00, 0x00,
+        ];
+        let description = SignType::describe_config(&data);
+        assert!(description.starts_with("Not a known sign type\n"));
+        assert!(description.contains(" 2  0x01  Reserved [unexpected: normally 0x00]\n"));
+        assert!(description.contains("Panel width: 32 [does not match declared width 40]"));
+
+        let description = SignType::describe_config(&[0x10, 0x01]);
+        assert!(description.starts_with("Warning: expected 16 bytes, got 2\n"));
+        assert!(description.contains(" 0  0x10  Family: unknown\n 1  0x01  Unknown\n"));
+    }
+
+    #[test]
+    fn bits_per_column_matches_height() {
+        for &sign_type in SignType::ALL {
+            if let Some(bits) = sign_type.bits_per_column() {
+                let (_, height) = sign_type.dimensions();
+                assert_eq!(height.div_ceil(8) * 8, bits, "{:?}", sign_type);
+            }
+        }
+    }
+
+    #[test]
+    fn horizon_byte_12_ignored() -> Result<(), Box<dyn Error>> {
+        let data = vec![
+            0x08, 0xB9, 0x00, 0x06, 0x8C, 0x0C, 0x00, 0x28, 0x01, 0x00, 0x28, 0x00, 0x07, 0x00, 0x00, 0x00,
+        ];
+        assert_eq!(SignType::HorizonDash40x12, SignType::from_bytes(&data)?);
+        Ok(())
+    }
+
     #[test]
     fn unknown_max3000_rejected() {
         let data = vec![
@@ -400,4 +1171,35 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn fits() {
+        let page = Page::new(PageId(1), 30, 10);
+        assert!(SignType::Max3000Rear30x10.fits(&page));
+        assert!(!SignType::Max3000Rear23x10.fits(&page));
+        assert!(!SignType::Max3000Dash30x7.fits(&page));
+    }
+
+    #[test]
+    fn panel_layout_matches_width() {
+        for &sign_type in SignType::ALL {
+            let layout = sign_type.panel_layout();
+            assert_eq!(sign_type.dimensions().0, layout.width(), "{:?}", sign_type);
+        }
+    }
+
+    #[test]
+    fn panel_layout_skips_unused_panels() {
+        let layout = SignType::Max3000Side90x7.panel_layout();
+        assert_eq!(
+            PanelLayout::Max3000 {
+                segment_widths: [30, 30, 30, 0]
+            },
+            layout
+        );
+        assert_eq!(vec![0..30, 30..60, 60..90], layout.panel_columns());
+
+        let layout = SignType::HorizonDash40x12.panel_layout();
+        assert_eq!(vec![0..40], layout.panel_columns());
+    }
 }
@@ -0,0 +1,280 @@
+use alloc::boxed::Box;
+use core::error::Error;
+
+use thiserror::Error;
+
+use crate::{Address, Message, SignBus, SignType};
+
+/// Errors related to [`TeeSignBus`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum TeeSignBusError {
+    /// The primary and secondary buses responded differently to the same message.
+    #[error("Sign bus responses differ: Primary {:?}, secondary {:?}", primary, secondary)]
+    ResponseMismatch {
+        /// The response from the primary bus.
+        primary: Option<Message<'static>>,
+
+        /// The response from the secondary bus.
+        secondary: Option<Message<'static>>,
+    },
+
+    /// The secondary bus failed to process a message.
+    #[error("Secondary sign bus failed to process message")]
+    Secondary {
+        /// The underlying error.
+        #[from]
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+/// A [`SignBus`] that forwards each message to two buses.
+///
+/// The primary bus is authoritative and its response is returned. The secondary bus receives the same messages,
+/// which is useful for mirroring real hardware to a `VirtualSignBus` for visualization. By default, the secondary
+/// bus's responses and errors are ignored; use [`with_comparison`](Self::with_comparison) to check that both buses agree.
+///
+/// # Examples
+///
+/// ```
+/// use flipdot_core::{Address, Message, PageFlipStyle, SignBus, State, TeeSignBus};
+/// use flipdot_testing::{VirtualSign, VirtualSignBus};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// #
+/// let primary = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+/// let mirror = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+/// let mut bus = TeeSignBus::new(primary, mirror).with_comparison(true);
+///
+/// let response = bus.process_message(Message::Hello(Address(3)))?;
+/// assert_eq!(Some(Message::ReportState(Address(3), State::Unconfigured)), response);
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TeeSignBus<P, S> {
+    primary: P,
+    secondary: S,
+    compare: bool,
+}
+
+impl<P: SignBus, S: SignBus> TeeSignBus<P, S> {
+    /// Creates a new `TeeSignBus` that forwards messages to `primary` and `secondary`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, PageFlipStyle, TeeSignBus};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// let primary = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let mirror = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let bus = TeeSignBus::new(primary, mirror);
+    /// ```
+    pub fn new(primary: P, secondary: S) -> Self {
+        TeeSignBus {
+            primary,
+            secondary,
+            compare: false,
+        }
+    }
+
+    /// Sets whether the secondary bus's responses are checked against the primary's.
+    ///
+    /// When enabled, [`process_message`](SignBus::process_message) fails with [`TeeSignBusError::ResponseMismatch`]
+    /// if the responses differ, or [`TeeSignBusError::Secondary`] if the secondary bus fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message, PageFlipStyle, SignBus, TeeSignBus};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// let primary = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let mirror = VirtualSignBus::new(vec![VirtualSign::new(Address(4), PageFlipStyle::Manual)]);
+    /// let mut bus = TeeSignBus::new(primary, mirror).with_comparison(true);
+    /// assert!(bus.process_message(Message::Hello(Address(3))).is_err());
+    /// ```
+    pub fn with_comparison(mut self, compare: bool) -> Self {
+        self.compare = compare;
+        self
+    }
+
+    /// Returns a reference to the primary bus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, PageFlipStyle, TeeSignBus};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// let primary = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let mirror = VirtualSignBus::new(vec![VirtualSign::new(Address(4), PageFlipStyle::Manual)]);
+    /// let bus = TeeSignBus::new(primary, mirror);
+    /// assert_eq!(Address(3), bus.primary().sign(0).address());
+    /// ```
+    pub fn primary(&self) -> &P {
+        &self.primary
+    }
+
+    /// Returns a reference to the secondary bus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, PageFlipStyle, TeeSignBus};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// let primary = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let mirror = VirtualSignBus::new(vec![VirtualSign::new(Address(4), PageFlipStyle::Manual)]);
+    /// let bus = TeeSignBus::new(primary, mirror);
+    /// assert_eq!(Address(4), bus.secondary().sign(0).address());
+    /// ```
+    pub fn secondary(&self) -> &S {
+        &self.secondary
+    }
+
+    /// Consumes the `TeeSignBus` and returns the primary and secondary buses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, PageFlipStyle, TeeSignBus};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// let primary = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let mirror = VirtualSignBus::new(vec![VirtualSign::new(Address(4), PageFlipStyle::Manual)]);
+    /// let (primary, mirror) = TeeSignBus::new(primary, mirror).into_inner();
+    /// ```
+    pub fn into_inner(self) -> (P, S) {
+        (self.primary, self.secondary)
+    }
+}
+
+impl<P: SignBus, S: SignBus> SignBus for TeeSignBus<P, S> {
+    /// Forwards a message to both buses and returns the primary bus's response.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying error if the primary bus fails. If comparison is enabled, also returns:
+    /// * [`TeeSignBusError::Secondary`] if the secondary bus fails.
+    /// * [`TeeSignBusError::ResponseMismatch`] if the two buses respond differently.
+    fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
+        let primary: Option<Message<'static>> = self.primary.process_message(message.clone())?;
+        let secondary: Result<Option<Message<'static>>, _> = self.secondary.process_message(message);
+
+        if self.compare {
+            let secondary = secondary.map_err(TeeSignBusError::from)?;
+            if primary != secondary {
+                return Err(TeeSignBusError::ResponseMismatch { primary, secondary }.into());
+            }
+        }
+
+        Ok(primary)
+    }
+
+    /// Returns the sign type recognized by the sign at `address` on the primary bus.
+    fn recognized_sign_type(&self, address: Address) -> Option<SignType> {
+        self.primary.recognized_sign_type(address)
+    }
+
+    /// Returns `Some(true)` if either bus communicates with real hardware, or `Some(false)` if both say they don't.
+    fn is_physical(&self) -> Option<bool> {
+        match (self.primary.is_physical(), self.secondary.is_physical()) {
+            (Some(true), _) | (_, Some(true)) => Some(true),
+            (Some(false), Some(false)) => Some(false),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, State};
+
+    /// Bus that records messages and responds with a fixed result.
+    #[derive(Debug)]
+    struct FixedSignBus {
+        response: Option<Message<'static>>,
+        fail: bool,
+        received: Vec<Message<'static>>,
+    }
+
+    impl FixedSignBus {
+        fn new(response: Option<Message<'static>>, fail: bool) -> Self {
+            FixedSignBus {
+                response,
+                fail,
+                received: vec![],
+            }
+        }
+    }
+
+    impl SignBus for FixedSignBus {
+        fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
+            if let Message::QueryState(address) = message {
+                self.received.push(Message::QueryState(address));
+            }
+            if self.fail {
+                Err("Dummy sign bus error")?;
+            }
+            Ok(self.response.clone())
+        }
+    }
+
+    const SHOWN: Option<Message<'static>> = Some(Message::ReportState(Address(3), State::PageShown));
+    const LOADED: Option<Message<'static>> = Some(Message::ReportState(Address(3), State::PageLoaded));
+
+    #[test]
+    fn forwards_to_both() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut bus = TeeSignBus::new(FixedSignBus::new(SHOWN, false), FixedSignBus::new(LOADED, false));
+        assert_eq!(SHOWN, bus.process_message(Message::QueryState(Address(3)))?);
+
+        let (primary, secondary) = bus.into_inner();
+        assert_eq!(vec![Message::QueryState(Address(3))], primary.received);
+        assert_eq!(vec![Message::QueryState(Address(3))], secondary.received);
+        Ok(())
+    }
+
+    #[test]
+    fn is_physical_unknown_by_default() {
+        let bus = TeeSignBus::new(FixedSignBus::new(SHOWN, false), FixedSignBus::new(LOADED, false));
+        assert_eq!(None, bus.is_physical());
+    }
+
+    #[test]
+    fn secondary_error_ignored() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut bus = TeeSignBus::new(FixedSignBus::new(SHOWN, false), FixedSignBus::new(None, true));
+        assert_eq!(SHOWN, bus.process_message(Message::QueryState(Address(3)))?);
+        Ok(())
+    }
+
+    #[test]
+    fn primary_error_propagates() {
+        let mut bus = TeeSignBus::new(FixedSignBus::new(None, true), FixedSignBus::new(SHOWN, false));
+        assert!(bus.process_message(Message::QueryState(Address(3))).is_err());
+        assert!(bus.secondary().received.is_empty());
+    }
+
+    #[test]
+    fn comparison_detects_mismatch() {
+        let mut bus = TeeSignBus::new(FixedSignBus::new(SHOWN, false), FixedSignBus::new(LOADED, false)).with_comparison(true);
+        let error = bus.process_message(Message::QueryState(Address(3))).unwrap_err();
+        let error = error.downcast::<TeeSignBusError>().unwrap();
+        assert!(matches!(
+            *error,
+            TeeSignBusError::ResponseMismatch { ref primary, ref secondary } if *primary == SHOWN && *secondary == LOADED
+        ));
+    }
+
+    #[test]
+    fn comparison_reports_secondary_error() {
+        let mut bus = TeeSignBus::new(FixedSignBus::new(SHOWN, false), FixedSignBus::new(SHOWN, true)).with_comparison(true);
+        let error = bus.process_message(Message::QueryState(Address(3))).unwrap_err();
+        let error = error.downcast::<TeeSignBusError>().unwrap();
+        assert!(matches!(*error, TeeSignBusError::Secondary { .. }));
+    }
+
+    #[test]
+    fn comparison_passes_when_equal() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut bus = TeeSignBus::new(FixedSignBus::new(SHOWN, false), FixedSignBus::new(SHOWN, false)).with_comparison(true);
+        assert_eq!(SHOWN, bus.process_message(Message::QueryState(Address(3)))?);
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use std::error::Error;
+use std::io;
+use std::time::Duration;
+
+use log::debug;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio_serial::{DataBits, FlowControl, Parity, SerialPortBuilderExt, SerialStream, StopBits};
+
+use flipdot_core::{AsyncSignBus, Frame, FrameError, Message};
+
+use crate::serial_sign_bus::{delay_after_receive, delay_after_send};
+
+/// How long to wait for a sign to respond before giving up.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// An implementation of [`AsyncSignBus`] that communicates with one or more signs over serial
+/// without blocking the calling thread.
+///
+/// Only available with the `tokio` feature enabled. Otherwise behaves identically to
+/// [`SerialSignBus`](crate::SerialSignBus), including logging messages and responses using the [`log`] crate.
+///
+/// # Examples
+///
+/// ```no_run
+/// use flipdot_core::{Address, AsyncSignBus, Message};
+/// use flipdot_serial::AsyncSerialSignBus;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// #
+/// let mut bus = AsyncSerialSignBus::open("/dev/ttyUSB0")?;
+/// let response = bus.process_message(Message::Hello(Address(3))).await?;
+/// #
+/// # Ok(()) }
+/// ```
+///
+/// [`log`]: https://crates.io/crates/log
+#[derive(Debug)]
+pub struct AsyncSerialSignBus<P: AsyncRead + AsyncWrite + Unpin + Send> {
+    port: P,
+}
+
+impl AsyncSerialSignBus<SerialStream> {
+    /// Opens the serial port at the given path and configures it appropriately for use with Luminator signs.
+    ///
+    /// Specifically, the signs require 8N1 format at 19200 baud. Must be called from within a Tokio runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`tokio_serial::Error`] if the serial port cannot be opened or configured.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use flipdot_serial::AsyncSerialSignBus;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = AsyncSerialSignBus::open("COM3")?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn open(path: &str) -> Result<Self, tokio_serial::Error> {
+        let port = tokio_serial::new(path, 19200)
+            .data_bits(DataBits::Eight)
+            .parity(Parity::None)
+            .stop_bits(StopBits::One)
+            .flow_control(FlowControl::None)
+            .open_native_async()?;
+        Ok(Self::new(port))
+    }
+}
+
+impl<P: AsyncRead + AsyncWrite + Unpin + Send> AsyncSerialSignBus<P> {
+    /// Creates a new `AsyncSerialSignBus` that communicates over the specified port.
+    ///
+    /// The port is used as-is, so it must already be configured correctly (see [`open`](AsyncSerialSignBus::open)).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use flipdot_serial::AsyncSerialSignBus;
+    /// use tokio_serial::SerialPortBuilderExt;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let port = tokio_serial::new("/dev/ttyUSB0", 19200).open_native_async()?;
+    /// let bus = AsyncSerialSignBus::new(port);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn new(port: P) -> Self {
+        AsyncSerialSignBus { port }
+    }
+
+    /// Returns a reference to the underlying serial port.
+    pub fn port(&self) -> &P {
+        &self.port
+    }
+
+    /// Reads a single frame, one byte at a time to avoid consuming any data past the end of it.
+    async fn read_frame(&mut self) -> Result<Frame<'static>, FrameError> {
+        let mut data = Vec::<u8>::new();
+        loop {
+            let byte = self.port.read_u8().await?;
+            data.push(byte);
+            if byte == b'\n' {
+                break;
+            }
+        }
+        Frame::from_bytes(&data)
+    }
+}
+
+impl<P: AsyncRead + AsyncWrite + Unpin + Send> AsyncSignBus for AsyncSerialSignBus<P> {
+    /// Handles a bus message by sending it to the serial port and reading a response if necessary.
+    async fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
+        debug!("Bus message: {}", message);
+
+        let response_expected = message.expects_response();
+        let delay = delay_after_send(&message);
+
+        let frame = Frame::from(message);
+        self.port
+            .write_all(&frame.to_bytes_with_newline())
+            .await
+            .map_err(FrameError::from)?;
+        self.port.flush().await.map_err(FrameError::from)?;
+
+        if let Some(duration) = delay {
+            tokio::time::sleep(duration).await;
+        }
+
+        if response_expected {
+            let frame = tokio::time::timeout(READ_TIMEOUT, self.read_frame())
+                .await
+                .map_err(|_| FrameError::from(io::Error::from(io::ErrorKind::TimedOut)))??;
+            let message = Message::from(frame);
+            debug!(" Sign reply: {}", message);
+
+            if let Some(duration) = delay_after_receive(&message) {
+                tokio::time::sleep(duration).await;
+            }
+
+            Ok(Some(message))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flipdot_core::{Address, Data, Offset, State};
+    use tokio::runtime::Runtime;
+
+    fn runtime() -> Runtime {
+        tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap()
+    }
+
+    #[test]
+    fn request_and_response() -> Result<(), Box<dyn Error + Send + Sync>> {
+        runtime().block_on(async {
+            let (port, mut sign) = tokio::io::duplex(256);
+            let mut bus = AsyncSerialSignBus::new(port);
+
+            sign.write_all(b":010003040FE9\r\n").await?;
+            let response = bus.process_message(Message::Hello(Address(3))).await?;
+            assert_eq!(Some(Message::ReportState(Address(3), State::Unconfigured)), response);
+
+            let mut sent = [0; 15];
+            let _ = sign.read_exact(&mut sent).await?;
+            assert_eq!(b":01000302FFFB\r\n", &sent);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn no_response_expected() -> Result<(), Box<dyn Error + Send + Sync>> {
+        runtime().block_on(async {
+            let (port, _sign) = tokio::io::duplex(256);
+            let mut bus = AsyncSerialSignBus::new(port);
+            let response = bus.process_message(Message::SendData(Offset(0), Data::from(&[1, 2]))).await?;
+            assert_eq!(None, response);
+            Ok(())
+        })
+    }
+}
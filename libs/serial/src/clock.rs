@@ -0,0 +1,41 @@
+use std::thread;
+use std::time::Duration;
+
+/// A source of delays for pacing bus communication.
+///
+/// [`SerialSignBus`](crate::SerialSignBus) waits between certain messages to avoid overwhelming the signs.
+/// It uses [`SystemClock`] by default, but another implementation can be supplied in order to observe or
+/// skip those delays, e.g. in tests.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use flipdot_serial::Clock;
+///
+/// /// Clock that records requested delays instead of sleeping.
+/// #[derive(Debug, Default)]
+/// struct FakeClock {
+///     sleeps: Vec<Duration>,
+/// }
+///
+/// impl Clock for FakeClock {
+///     fn sleep(&mut self, duration: Duration) {
+///         self.sleeps.push(duration);
+///     }
+/// }
+/// ```
+pub trait Clock {
+    /// Waits for the given length of time.
+    fn sleep(&mut self, duration: Duration);
+}
+
+/// A [`Clock`] that blocks the current thread using [`thread::sleep`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn sleep(&mut self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
@@ -6,6 +6,9 @@
 //! However, you can use the [`configure_port`] function to configure serial port appropriately
 //! if you're doing custom lower-level communication.
 //!
+//! With the `tokio` feature enabled, `AsyncSerialSignBus` provides an asynchronous alternative to
+//! [`SerialSignBus`] for use with the Tokio runtime.
+//!
 //! Intended only for hobbyist and educational purposes. Not affiliated with Luminator in any way.
 //!
 //! # Examples
@@ -39,8 +42,14 @@
     unused_results
 )]
 
+#[cfg(feature = "tokio")]
+mod async_serial_sign_bus;
+mod clock;
 mod serial_port;
 mod serial_sign_bus;
 
+#[cfg(feature = "tokio")]
+pub use self::async_serial_sign_bus::AsyncSerialSignBus;
+pub use self::clock::{Clock, SystemClock};
 pub use self::serial_port::configure_port;
 pub use self::serial_sign_bus::SerialSignBus;
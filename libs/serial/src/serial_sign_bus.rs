@@ -1,13 +1,18 @@
 use std::error::Error;
-use std::thread;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::mem;
 use std::time::Duration;
 
-use log::debug;
+use log::{debug, warn};
 use serial_core::prelude::*;
+use serial_core::{PortSettings, SerialDevice};
 
-use flipdot_core::{Frame, Message, SignBus, State};
+use flipdot_core::{Data, Frame, FrameError, Message, Offset, SignBus, State};
 
 use crate::serial_port;
+use crate::{Clock, SystemClock};
 
 /// An implementation of [`SignBus`] that communicates with one or more signs over serial.
 ///
@@ -31,11 +36,19 @@ use crate::serial_port;
 ///
 /// [`log`]: https://crates.io/crates/log
 /// [`env_logger`]: https://crates.io/crates/env_logger
-#[derive(Debug, Eq, PartialEq, Hash)]
-pub struct SerialSignBus<P: SerialPort> {
+pub struct SerialSignBus<P: SerialPort, C: Clock = SystemClock> {
     port: P,
+    clock: C,
+    inter_byte_delay: Option<Duration>,
+    chunk_callback: Option<Box<ChunkCallback>>,
+    pending_chunks: Vec<(Offset, Vec<u8>)>,
+    suppress_echo: bool,
+    read_buffer: Vec<u8>,
 }
 
+/// Callback invoked after each [`SendData`](Message::SendData) message is written.
+type ChunkCallback = dyn FnMut(usize, usize) + Send;
+
 impl<P: SerialPort> SerialSignBus<P> {
     /// Creates a new `SerialSignBus` that communicates over the specified serial port.
     ///
@@ -56,37 +69,335 @@ impl<P: SerialPort> SerialSignBus<P> {
     /// ```
     pub fn try_new(mut port: P) -> Result<Self, serial_core::Error> {
         serial_port::configure_port(&mut port, Duration::from_secs(5))?;
-        Ok(SerialSignBus { port })
+        Ok(SerialSignBus {
+            port,
+            clock: SystemClock,
+            inter_byte_delay: None,
+            chunk_callback: None,
+            pending_chunks: Vec::new(),
+            suppress_echo: false,
+            read_buffer: Vec::new(),
+        })
+    }
+}
+
+impl<P: SerialPort, C: Clock> SerialSignBus<P, C> {
+    /// Replaces the [`Clock`] used to wait between messages.
+    ///
+    /// By default, [`SystemClock`] is used to actually sleep. Supplying a different clock allows tests to
+    /// verify the requested delays without waiting for them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// use flipdot_serial::{Clock, SerialSignBus};
+    ///
+    /// #[derive(Debug, Default)]
+    /// struct FakeClock {
+    ///     sleeps: Vec<Duration>,
+    /// }
+    ///
+    /// impl Clock for FakeClock {
+    ///     fn sleep(&mut self, duration: Duration) {
+    ///         self.sleeps.push(duration);
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let port = serial::open("/dev/ttyUSB0")?;
+    /// let bus = SerialSignBus::try_new(port)?.with_clock(FakeClock::default());
+    /// assert!(bus.clock().sleeps.is_empty());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn with_clock<C2: Clock>(self, clock: C2) -> SerialSignBus<P, C2> {
+        SerialSignBus {
+            port: self.port,
+            clock,
+            inter_byte_delay: self.inter_byte_delay,
+            chunk_callback: self.chunk_callback,
+            pending_chunks: self.pending_chunks,
+            suppress_echo: self.suppress_echo,
+            read_buffer: self.read_buffer,
+        }
+    }
+
+    /// Writes frames one byte at a time, waiting `delay` between bytes, or restores bulk writes if `None`.
+    ///
+    /// By default, each frame is written to the port in a single call. Some very old signs drop bytes
+    /// when they arrive back-to-back, which a short delay between individual bytes (on the order of
+    /// tens to hundreds of microseconds) can avoid. This slows communication considerably, so only use it
+    /// if needed. The delay is requested from the bus's [`Clock`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// use flipdot_serial::SerialSignBus;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let port = serial::open("/dev/ttyUSB0")?;
+    /// let bus = SerialSignBus::try_new(port)?.with_inter_byte_delay(Some(Duration::from_micros(200)));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn with_inter_byte_delay(mut self, delay: Option<Duration>) -> Self {
+        self.inter_byte_delay = delay;
+        self
+    }
+
+    /// Sets a callback to be invoked after each [`SendData`](Message::SendData) message is written to the port.
+    ///
+    /// The callback receives the 0-based index of the chunk within the current transfer and the total
+    /// number of chunks in it. The callback runs before the bus's own post-send delay, so it can be used
+    /// to add extra pacing between chunks or for logging.
+    ///
+    /// Since the total isn't known until the transfer's [`DataChunksSent`](Message::DataChunksSent) message,
+    /// setting a callback makes the bus hold each `SendData` message until then and write them all at once.
+    /// The sign sees the same traffic, but an error writing a chunk is reported by the `DataChunksSent` message.
+    /// Held chunks are discarded (with a warning) if any other message arrives before `DataChunksSent`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::thread;
+    /// # use std::time::Duration;
+    /// use flipdot_serial::SerialSignBus;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let port = serial::open("/dev/ttyUSB0")?;
+    /// let bus = SerialSignBus::try_new(port)?.with_chunk_callback(|index, total| {
+    ///     println!("Wrote chunk {} of {}", index + 1, total);
+    ///     thread::sleep(Duration::from_millis(10 * (index as u64 % 4)));
+    /// });
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn with_chunk_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(usize, usize) + Send + 'static,
+    {
+        self.chunk_callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Enables or disables discarding echoes of the frames this bus writes.
+    ///
+    /// On a half-duplex RS-485 link without proper direction control, every frame written to the port
+    /// is read straight back, which otherwise shows up as baffling unexpected responses. With echo
+    /// suppression enabled, the bus reads a frame after each write and discards it if it is identical to
+    /// the one just written. If the frame read is something else, it is taken as the sign's response
+    /// (or discarded with a warning if no response was expected). Only enable this if your adapter
+    /// actually echoes: otherwise, each message that doesn't expect a response waits for the port's read
+    /// timeout before logging a warning and carrying on.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use flipdot_serial::SerialSignBus;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let port = serial::open("/dev/ttyUSB0")?;
+    /// let bus = SerialSignBus::try_new(port)?.with_echo_suppression(true);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn with_echo_suppression(mut self, enabled: bool) -> Self {
+        self.suppress_echo = enabled;
+        self
     }
 
     /// Returns a reference to the underlying serial port.
     pub fn port(&self) -> &P {
         &self.port
     }
+
+    /// Returns a reference to the [`Clock`] used to wait between messages.
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+}
+
+impl<P: SerialPort + SerialDevice, C: Clock> SerialSignBus<P, C> {
+    /// Reads back the settings currently in effect on the serial port.
+    ///
+    /// Some inexpensive USB serial adapters silently ignore parts of the configuration (notably the baud rate),
+    /// which leads to mysterious communication failures. Comparing these settings against the 8N1 19200 baud
+    /// format the signs require allows detecting this up front.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`serial_core::Error`] if the settings cannot be read, or if the port does not
+    /// report one of them.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use flipdot_serial::SerialSignBus;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let port = serial::open("/dev/ttyUSB0")?;
+    /// let bus = SerialSignBus::try_new(port)?;
+    /// let settings = bus.port_settings()?;
+    /// if settings.baud_rate != serial::Baud19200 {
+    ///     eprintln!("Warning: serial adapter is using {:?} instead of 19200 baud", settings.baud_rate);
+    /// }
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn port_settings(&self) -> Result<PortSettings, serial_core::Error> {
+        fn missing(setting: &str) -> serial_core::Error {
+            serial_core::Error::new(
+                serial_core::ErrorKind::Io(io::ErrorKind::Other),
+                format!("Serial port did not report its {}", setting),
+            )
+        }
+
+        let settings = self.port.read_settings()?;
+        Ok(PortSettings {
+            baud_rate: settings.baud_rate().ok_or_else(|| missing("baud rate"))?,
+            char_size: settings.char_size().ok_or_else(|| missing("character size"))?,
+            parity: settings.parity().ok_or_else(|| missing("parity"))?,
+            stop_bits: settings.stop_bits().ok_or_else(|| missing("stop bits"))?,
+            flow_control: settings.flow_control().ok_or_else(|| missing("flow control"))?,
+        })
+    }
+}
+
+impl<P: SerialPort + Debug, C: Clock + Debug> Debug for SerialSignBus<P, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SerialSignBus")
+            .field("port", &self.port)
+            .field("clock", &self.clock)
+            .field("inter_byte_delay", &self.inter_byte_delay)
+            .field("chunk_callback", &self.chunk_callback.as_ref().map(|_| ".."))
+            .field("pending_chunks", &self.pending_chunks.len())
+            .field("suppress_echo", &self.suppress_echo)
+            .finish()
+    }
 }
 
-impl<P: SerialPort> SignBus for SerialSignBus<P> {
+/// Compares the port, clock, and settings; the chunk callback and any in-progress transfer are ignored.
+impl<P: SerialPort + PartialEq, C: Clock + PartialEq> PartialEq for SerialSignBus<P, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.port == other.port
+            && self.clock == other.clock
+            && self.inter_byte_delay == other.inter_byte_delay
+            && self.suppress_echo == other.suppress_echo
+    }
+}
+
+impl<P: SerialPort + Eq, C: Clock + Eq> Eq for SerialSignBus<P, C> {}
+
+impl<P: SerialPort + Hash, C: Clock + Hash> Hash for SerialSignBus<P, C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.port.hash(state);
+        self.clock.hash(state);
+        self.inter_byte_delay.hash(state);
+        self.suppress_echo.hash(state);
+    }
+}
+
+impl<P: SerialPort, C: Clock> SerialSignBus<P, C> {
+    /// Writes `frame` to the port, returning the sign's response if it arrived in place of an expected echo.
+    fn write_frame(&mut self, frame: &Frame<'_>, response_expected: bool) -> Result<Option<Frame<'static>>, FrameError> {
+        match self.inter_byte_delay {
+            Some(delay) => {
+                for (i, byte) in frame.to_bytes_with_newline().into_iter().enumerate() {
+                    if i > 0 {
+                        self.clock.sleep(delay);
+                    }
+                    self.port.write_all(&[byte])?;
+                }
+            }
+            None => frame.write(&mut self.port)?,
+        }
+
+        // With echo suppression, a frame that isn't our own echo is the sign's response.
+        if self.suppress_echo {
+            match Frame::read_into(&mut self.port, &mut self.read_buffer) {
+                Ok(echo) if echo == *frame => debug!(" Discarded echo"),
+                Ok(echo) if response_expected => return Ok(Some(echo)),
+                Ok(echo) => warn!("Expected echo of {} but got {}", frame, echo),
+                Err(FrameError::Io { ref source }) if !response_expected && source.kind() == io::ErrorKind::TimedOut => {
+                    warn!("Timed out waiting for echo of {}", frame);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Writes the held [`SendData`](Message::SendData) messages, invoking the chunk callback after each.
+    fn write_pending_chunks(&mut self) -> Result<(), FrameError> {
+        let chunks = mem::take(&mut self.pending_chunks);
+        let total = chunks.len();
+        for (index, (offset, data)) in chunks.into_iter().enumerate() {
+            let message = Message::SendData(offset, Data::try_new(data)?);
+            let delay = delay_after_send(&message);
+            let _ = self.write_frame(&Frame::from(message), false)?;
+
+            if let Some(ref mut callback) = self.chunk_callback {
+                callback(index, total);
+            }
+            if let Some(duration) = delay {
+                self.clock.sleep(duration);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<P: SerialPort, C: Clock> SignBus for SerialSignBus<P, C> {
     /// Handles a bus message by sending it to the serial port and reading a response if necessary.
     fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
         debug!("Bus message: {}", message);
 
-        let response_expected = response_expected(&message);
+        if self.chunk_callback.is_some() {
+            match message {
+                Message::SendData(offset, data) => {
+                    self.pending_chunks.push((offset, data.get().to_vec()));
+                    return Ok(None);
+                }
+                Message::DataChunksSent(_) => self.write_pending_chunks()?,
+                _ => {
+                    if !self.pending_chunks.is_empty() {
+                        warn!(
+                            "Discarding {} data chunk(s) not followed by DataChunksSent",
+                            self.pending_chunks.len()
+                        );
+                        self.pending_chunks.clear();
+                    }
+                }
+            }
+        }
+
+        let response_expected = message.expects_response();
         let delay = delay_after_send(&message);
 
         let frame = Frame::from(message);
-        frame.write(&mut self.port)?;
+        let early_response = self.write_frame(&frame, response_expected)?;
 
         if let Some(duration) = delay {
-            thread::sleep(duration);
+            self.clock.sleep(duration);
         }
 
         if response_expected {
-            let frame = Frame::read(&mut self.port)?;
+            let frame = match early_response {
+                Some(frame) => frame,
+                None => Frame::read_into(&mut self.port, &mut self.read_buffer)?,
+            };
             let message = Message::from(frame);
             debug!(" Sign reply: {}", message);
 
             if let Some(duration) = delay_after_receive(&message) {
-                thread::sleep(duration);
+                self.clock.sleep(duration);
             }
 
             Ok(Some(message))
@@ -94,20 +405,15 @@ impl<P: SerialPort> SignBus for SerialSignBus<P> {
             Ok(None)
         }
     }
-}
 
-/// Determines whether we need to listen for a response to the given message.
-fn response_expected(message: &Message<'_>) -> bool {
-    // A sign is only expected to reply to messages that query its state or request
-    // that it perform an operation.
-    matches!(
-        *message,
-        Message::Hello(_) | Message::QueryState(_) | Message::RequestOperation(_, _)
-    )
+    /// Always returns `Some(true)`, since messages are sent to real signs over the serial port.
+    fn is_physical(&self) -> Option<bool> {
+        Some(true)
+    }
 }
 
 /// Returns the length of time to delay after sending a message.
-fn delay_after_send(message: &Message<'_>) -> Option<Duration> {
+pub(crate) fn delay_after_send(message: &Message<'_>) -> Option<Duration> {
     match *message {
         // When sending data, this delay is necessary to avoid overloading the receiving sign.
         Message::SendData(_, _) => Some(Duration::from_millis(30)),
@@ -116,7 +422,7 @@ fn delay_after_send(message: &Message<'_>) -> Option<Duration> {
 }
 
 /// Returns the length of time to delay after receiving a response.
-fn delay_after_receive(message: &Message<'_>) -> Option<Duration> {
+pub(crate) fn delay_after_receive(message: &Message<'_>) -> Option<Duration> {
     match *message {
         // When loading or showing a page, we wait for the sign to finish the operation, which can take
         // a second or more depending on how many dots need to flip. This delay prevents us from spamming
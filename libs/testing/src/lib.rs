@@ -14,14 +14,13 @@
 //! ```no_run
 //! use flipdot_core::PageFlipStyle;
 //! use flipdot_serial::SerialSignBus;
-//! use flipdot_testing::{Address, Odk, VirtualSign, VirtualSignBus};
+//! use flipdot_testing::{Address, Odk, VirtualSignBus};
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! #
 //! // Populate bus with signs from addresses 2 to 126
 //! // (which seems to be the possible range for actual signs).
-//! let signs = (2..127).map(Address).map(|addr| VirtualSign::new(addr, PageFlipStyle::Manual));
-//! let bus = VirtualSignBus::new(signs);
+//! let bus = VirtualSignBus::with_addresses((2..127).map(Address), PageFlipStyle::Manual);
 //!
 //! // Hook up ODK to virtual bus.
 //! let port = serial::open("COM3")?;
@@ -51,10 +50,16 @@
     unused_results
 )]
 
+mod message_pattern;
 mod odk;
+mod session;
+mod timestamped_frame;
 mod virtual_sign_bus;
 
-pub use self::odk::{Odk, OdkError};
-pub use self::virtual_sign_bus::{VirtualSign, VirtualSignBus};
+pub use self::message_pattern::MessagePattern;
+pub use self::odk::{Direction, Odk, OdkError};
+pub use self::session::{Session, SessionError};
+pub use self::timestamped_frame::TimestampedFrame;
+pub use self::virtual_sign_bus::{VirtualSign, VirtualSignBus, VirtualSignBusError, VirtualSignSnapshot};
 
 pub use flipdot_core::Address;
@@ -0,0 +1,145 @@
+use flipdot_core::{Address, Frame, Message, MsgType};
+
+/// A template that [`Message`]s can be matched against, optionally ignoring the address or data.
+///
+/// Matching is performed on the underlying [`Frame`] representation. This is useful when scripting
+/// expected bus traffic in tests that should not depend on particular sign addresses.
+///
+/// Note that for [`SendData`](Message::SendData) and [`DataChunksSent`](Message::DataChunksSent), the offset
+/// and chunk count are stored in the frame's address field and so are ignored by [`any_address`](Self::any_address).
+/// Similarly, [`Hello`](Message::Hello), [`QueryState`](Message::QueryState), and [`Goodbye`](Message::Goodbye)
+/// share a message type and are distinguished only by their data, as are the various states and operations
+/// of [`ReportState`](Message::ReportState), [`RequestOperation`](Message::RequestOperation),
+/// and [`AckOperation`](Message::AckOperation), so [`any_data`](Self::any_data) will match all of them.
+///
+/// # Examples
+///
+/// ```
+/// use flipdot_core::{Address, Message, Operation, State};
+/// use flipdot_testing::MessagePattern;
+///
+/// let pattern = MessagePattern::new(Message::QueryState(Address(0))).any_address();
+/// assert!(pattern.matches(&Message::QueryState(Address(3))));
+/// assert!(!pattern.matches(&Message::Hello(Address(3))));
+///
+/// let pattern = MessagePattern::new(Message::ReportState(Address(3), State::Unconfigured)).any_data();
+/// assert!(pattern.matches(&Message::ReportState(Address(3), State::PageShown)));
+/// assert!(!pattern.matches(&Message::ReportState(Address(4), State::PageShown)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MessagePattern {
+    message_type: MsgType,
+    address: Option<Address>,
+    data: Option<Vec<u8>>,
+}
+
+impl MessagePattern {
+    /// Creates a new `MessagePattern` that matches only the given message exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message};
+    /// # use flipdot_testing::MessagePattern;
+    /// let pattern = MessagePattern::new(Message::Goodbye(Address(3)));
+    /// assert!(pattern.matches(&Message::Goodbye(Address(3))));
+    /// assert!(!pattern.matches(&Message::Goodbye(Address(4))));
+    /// ```
+    pub fn new(message: Message<'_>) -> Self {
+        let frame = Frame::from(message);
+        MessagePattern {
+            message_type: frame.message_type(),
+            address: Some(frame.address()),
+            data: Some(frame.data().to_vec()),
+        }
+    }
+
+    /// Makes the pattern match messages with any address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message};
+    /// # use flipdot_testing::MessagePattern;
+    /// let pattern = MessagePattern::new(Message::Hello(Address(0))).any_address();
+    /// assert!(pattern.matches(&Message::Hello(Address(17))));
+    /// ```
+    pub fn any_address(mut self) -> Self {
+        self.address = None;
+        self
+    }
+
+    /// Makes the pattern match messages with any data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message, Operation};
+    /// # use flipdot_testing::MessagePattern;
+    /// let pattern = MessagePattern::new(Message::AckOperation(Address(3), Operation::StartReset)).any_data();
+    /// assert!(pattern.matches(&Message::AckOperation(Address(3), Operation::FinishReset)));
+    /// ```
+    pub fn any_data(mut self) -> Self {
+        self.data = None;
+        self
+    }
+
+    /// Returns whether the given message matches this pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message};
+    /// # use flipdot_testing::MessagePattern;
+    /// let pattern = MessagePattern::new(Message::PixelsComplete(Address(3)));
+    /// assert!(pattern.matches(&Message::PixelsComplete(Address(3))));
+    /// ```
+    pub fn matches(&self, message: &Message<'_>) -> bool {
+        let frame = Frame::from(message.clone());
+        frame.message_type() == self.message_type
+            && self.address.is_none_or(|address| address == frame.address())
+            && self.data.as_ref().is_none_or(|data| data[..] == frame.data()[..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flipdot_core::{ChunkCount, Data, Offset, Operation, State};
+
+    #[test]
+    fn exact_match() {
+        let pattern = MessagePattern::new(Message::RequestOperation(Address(3), Operation::ReceivePixels));
+        assert!(pattern.matches(&Message::RequestOperation(Address(3), Operation::ReceivePixels)));
+        assert!(!pattern.matches(&Message::RequestOperation(Address(4), Operation::ReceivePixels)));
+        assert!(!pattern.matches(&Message::RequestOperation(Address(3), Operation::ReceiveConfig)));
+        assert!(!pattern.matches(&Message::AckOperation(Address(3), Operation::ReceivePixels)));
+    }
+
+    #[test]
+    fn wildcard_address() {
+        let pattern = MessagePattern::new(Message::ReportState(Address(3), State::PageLoaded)).any_address();
+        assert!(pattern.matches(&Message::ReportState(Address(3), State::PageLoaded)));
+        assert!(pattern.matches(&Message::ReportState(Address(99), State::PageLoaded)));
+        assert!(!pattern.matches(&Message::ReportState(Address(99), State::PageShown)));
+
+        let pattern = MessagePattern::new(Message::DataChunksSent(ChunkCount(1))).any_address();
+        assert!(pattern.matches(&Message::DataChunksSent(ChunkCount(6))));
+    }
+
+    #[test]
+    fn wildcard_data() {
+        let pattern = MessagePattern::new(Message::SendData(Offset(16), Data::from(&[0x01, 0x02]))).any_data();
+        assert!(pattern.matches(&Message::SendData(Offset(16), Data::try_new(vec![0xFF; 16]).unwrap())));
+        assert!(!pattern.matches(&Message::SendData(Offset(32), Data::from(&[0x01, 0x02]))));
+    }
+
+    #[test]
+    fn wildcard_both() {
+        let pattern = MessagePattern::new(Message::QueryState(Address(3))).any_address().any_data();
+        assert!(pattern.matches(&Message::QueryState(Address(7))));
+        assert!(pattern.matches(&Message::Hello(Address(7))));
+        assert!(pattern.matches(&Message::Goodbye(Address(7))));
+        assert!(!pattern.matches(&Message::PixelsComplete(Address(7))));
+    }
+}
@@ -1,9 +1,15 @@
-use std::time::Duration;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::time::{Duration, Instant};
 
 use serial_core::prelude::*;
 use thiserror::Error;
 
-use flipdot_core::{Frame, Message, SignBus};
+use flipdot_core::{Address, Frame, FrameError, Message, SignBus};
+
+use crate::TimestampedFrame;
 
 /// Errors related to [`Odk`]s.
 #[derive(Debug, Error)]
@@ -22,7 +28,7 @@ pub enum OdkError {
     Communication {
         /// The underlying communication error.
         #[from]
-        source: flipdot_core::FrameError,
+        source: FrameError,
     },
 }
 
@@ -36,14 +42,13 @@ pub enum OdkError {
 /// ```no_run
 /// use flipdot_core::PageFlipStyle;
 /// use flipdot_serial::SerialSignBus;
-/// use flipdot_testing::{Address, Odk, VirtualSign, VirtualSignBus};
+/// use flipdot_testing::{Address, Odk, VirtualSignBus};
 ///
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// #
 /// // Populate bus with signs from addresses 2 to 126
 /// // (which seems to be the possible range for actual signs).
-/// let signs = (2..127).map(Address).map(|addr| VirtualSign::new(addr, PageFlipStyle::Manual));
-/// let bus = VirtualSignBus::new(signs);
+/// let bus = VirtualSignBus::with_addresses((2..127).map(Address), PageFlipStyle::Manual);
 ///
 /// // Hook up ODK to virtual bus.
 /// let port = serial::open("/dev/ttyUSB0")?;
@@ -55,10 +60,35 @@ pub enum OdkError {
 /// #
 /// # Ok(()) }
 /// ```
-#[derive(Debug, PartialEq, Eq, Hash)]
 pub struct Odk<P: SerialPort, B: SignBus> {
     port: P,
     bus: B,
+    filter: Option<Box<MessageFilter>>,
+    address_map: HashMap<Address, Address>,
+    reverse_address_map: HashMap<Address, Address>,
+    seen_addresses: HashSet<Address>,
+    unknown_handler: Option<Box<UnknownHandler>>,
+    frame_handler: Option<Box<FrameHandler>>,
+    read_buffer: Vec<u8>,
+}
+
+/// Function that can inspect, transform, or suppress messages passing through an [`Odk`].
+type MessageFilter = dyn FnMut(Direction, Message<'static>) -> Option<Message<'static>>;
+
+/// Function that is notified of frames from an [`Odk`] that don't correspond to a known message.
+type UnknownHandler = dyn FnMut(&Frame<'_>);
+
+/// Function that is notified of every frame read from or written to an [`Odk`].
+type FrameHandler = dyn FnMut(TimestampedFrame);
+
+/// The direction a message is traveling through an [`Odk`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Sent by the ODK, to be forwarded to the bus.
+    OdkToSign,
+
+    /// Sent in response by the bus, to be forwarded back to the ODK.
+    SignToOdk,
 }
 
 impl<P: SerialPort, B: SignBus> Odk<P, B> {
@@ -88,7 +118,158 @@ impl<P: SerialPort, B: SignBus> Odk<P, B> {
     /// `RUST_LOG=debug` to watch the bus messages go by.
     pub fn try_new(mut port: P, bus: B) -> Result<Self, serial_core::Error> {
         flipdot_serial::configure_port(&mut port, Duration::from_secs(10))?;
-        Ok(Odk { port, bus })
+        Ok(Odk {
+            port,
+            bus,
+            filter: None,
+            address_map: HashMap::new(),
+            reverse_address_map: HashMap::new(),
+            seen_addresses: HashSet::new(),
+            unknown_handler: None,
+            frame_handler: None,
+            read_buffer: Vec::new(),
+        })
+    }
+
+    /// Create a new `Odk` that connects the specified serial port and bus, passing each message through a filter.
+    ///
+    /// The filter is called with every message read from the ODK before it is forwarded to the bus,
+    /// and with every response from the bus before it is sent back to the ODK. It can return the message
+    /// unchanged, substitute a different one, or return [`None`] to suppress it entirely. This is useful
+    /// for studying how real controllers and signs react to altered traffic.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`serial_core::Error`] if the serial port cannot be configured.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use flipdot_core::{Message, PageFlipStyle};
+    /// # use flipdot_serial::SerialSignBus;
+    /// # use flipdot_testing::{Address, Direction, Odk, VirtualSign, VirtualSignBus};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let port = serial::open("COM3")?;
+    ///
+    /// // Pretend the sign never hears the ODK say goodbye.
+    /// let odk = Odk::with_filter(port, bus, |direction, message| match (direction, message) {
+    ///     (Direction::OdkToSign, Message::Goodbye(_)) => None,
+    ///     (_, message) => Some(message),
+    /// })?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn with_filter<F>(port: P, bus: B, filter: F) -> Result<Self, serial_core::Error>
+    where
+        F: FnMut(Direction, Message<'static>) -> Option<Message<'static>> + 'static,
+    {
+        let mut odk = Self::try_new(port, bus)?;
+        odk.set_filter(filter);
+        Ok(odk)
+    }
+
+    /// Create a new `Odk` that connects the specified serial port and bus, rewriting sign addresses in between.
+    ///
+    /// Each message from the ODK addressed to a key of `map` is forwarded to the bus addressed to the corresponding
+    /// value instead, and responses from that address are mapped back before being returned to the ODK. Addresses
+    /// not in the map pass through unchanged. This allows pointing an ODK configured for one address at a
+    /// differently-addressed virtual sign. The map should be one-to-one so that responses can be mapped back
+    /// unambiguously.
+    ///
+    /// If a filter is also in use, it sees messages as the ODK sends and receives them, i.e. with the original addresses.
+    ///
+    /// # Errors
+    ///
+    /// Returns the underlying [`serial_core::Error`] if the serial port cannot be configured.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, Odk, VirtualSign, VirtualSignBus};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(5), PageFlipStyle::Manual)]);
+    /// let port = serial::open("COM3")?;
+    ///
+    /// // ODK talks to sign 3, which is answered by the virtual sign at address 5.
+    /// let map = HashMap::from([(Address(3), Address(5))]);
+    /// let odk = Odk::with_address_map(port, bus, map)?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn with_address_map(port: P, bus: B, map: HashMap<Address, Address>) -> Result<Self, serial_core::Error> {
+        let mut odk = Self::try_new(port, bus)?;
+        odk.set_address_map(map);
+        Ok(odk)
+    }
+
+    /// Passes each message through `filter`, replacing any previously set filter.
+    ///
+    /// See [`with_filter`](Self::with_filter) for how the filter is applied. Takes effect starting
+    /// with the next message processed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use flipdot_core::{Message, PageFlipStyle};
+    /// # use flipdot_testing::{Address, Direction, Odk, VirtualSign, VirtualSignBus};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let port = serial::open("COM3")?;
+    /// let mut odk = Odk::try_new(port, bus)?;
+    /// for _ in 0..100 {
+    ///     odk.process_message()?;
+    /// }
+    ///
+    /// // Once things are up and running, stop the sign from hearing the ODK say goodbye.
+    /// odk.set_filter(|direction, message| match (direction, message) {
+    ///     (Direction::OdkToSign, Message::Goodbye(_)) => None,
+    ///     (_, message) => Some(message),
+    /// });
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn set_filter<F>(&mut self, filter: F)
+    where
+        F: FnMut(Direction, Message<'static>) -> Option<Message<'static>> + 'static,
+    {
+        self.filter = Some(Box::new(filter));
+    }
+
+    /// Rewrites sign addresses according to `map`, replacing any previously set map.
+    ///
+    /// See [`with_address_map`](Self::with_address_map) for how the map is applied. Takes effect starting
+    /// with the next message processed; an empty map turns address rewriting off.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, Odk, VirtualSign, VirtualSignBus};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(5), PageFlipStyle::Manual)]);
+    /// let port = serial::open("COM3")?;
+    /// let mut odk = Odk::try_new(port, bus)?;
+    ///
+    /// // Point the ODK's sign 3 at the virtual sign at address 5.
+    /// odk.set_address_map(HashMap::from([(Address(3), Address(5))]));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn set_address_map(&mut self, map: HashMap<Address, Address>) {
+        self.reverse_address_map = map.iter().map(|(&from, &to)| (to, from)).collect();
+        self.address_map = map;
     }
 
     /// Reads the next frame from the ODK over the serial port, forwards it
@@ -120,16 +301,296 @@ impl<P: SerialPort, B: SignBus> Odk<P, B> {
     /// ```
     pub fn process_message(&mut self) -> Result<(), OdkError> {
         let response = {
-            let frame = Frame::read(&mut self.port)?;
+            let frame = Frame::read_into(&mut self.port, &mut self.read_buffer)?;
+            if let Some(handler) = &mut self.frame_handler {
+                handler(TimestampedFrame::now(Direction::OdkToSign, frame.clone()));
+            }
             let message = Message::from(frame);
-            self.bus.process_message(message)?
+            if let Some(address) = message_address(&message) {
+                let _ = self.seen_addresses.insert(address);
+            }
+            if let (Message::Unknown(frame), Some(handler)) = (&message, &mut self.unknown_handler) {
+                handler(frame);
+            }
+            match self.apply_filter(Direction::OdkToSign, message) {
+                Some(message) => self.bus.process_message(remap_address(message, &self.address_map))?,
+                None => None,
+            }
         };
 
-        if let Some(message) = response {
+        let response = response.map(|message| remap_address(message, &self.reverse_address_map));
+        if let Some(message) = response.and_then(|message| self.apply_filter(Direction::SignToOdk, message)) {
             let frame = Frame::from(message);
             frame.write(&mut self.port)?;
+            if let Some(handler) = &mut self.frame_handler {
+                handler(TimestampedFrame::now(Direction::SignToOdk, frame));
+            }
         }
 
         Ok(())
     }
+
+    /// Processes exactly `count` messages from the ODK, as if by calling [`process_message`](Self::process_message)
+    /// that many times.
+    ///
+    /// # Errors
+    ///
+    /// Stops at and returns the first error from [`process_message`](Self::process_message).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, Odk, VirtualSign, VirtualSignBus};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let port = serial::open("/dev/ttyUSB0")?;
+    /// let mut odk = Odk::try_new(port, bus)?;
+    /// odk.process_messages(1000)?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn process_messages(&mut self, count: usize) -> Result<(), OdkError> {
+        for _ in 0..count {
+            self.process_message()?;
+        }
+        Ok(())
+    }
+
+    /// Processes messages from the ODK until `deadline` has passed.
+    ///
+    /// Timing out while waiting for a message is not an error here; it simply gives the loop a chance
+    /// to check the deadline. Since each read can block for up to the port's timeout, this may return
+    /// somewhat after `deadline`.
+    ///
+    /// # Errors
+    ///
+    /// Stops at and returns the first error from [`process_message`](Self::process_message),
+    /// other than a read timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::time::{Duration, Instant};
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, Odk, VirtualSign, VirtualSignBus};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let port = serial::open("/dev/ttyUSB0")?;
+    /// let mut odk = Odk::try_new(port, bus)?;
+    /// odk.process_until(Instant::now() + Duration::from_secs(60))?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn process_until(&mut self, deadline: Instant) -> Result<(), OdkError> {
+        while Instant::now() < deadline {
+            match self.process_message() {
+                Err(OdkError::Communication {
+                    source: FrameError::Io { source },
+                }) if source.kind() == io::ErrorKind::TimedOut => {}
+                result => result?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Registers a function to be called with each frame from the ODK that decodes to [`Message::Unknown`].
+    ///
+    /// Unknown messages are still forwarded to the bus as usual; this simply provides a way to log or
+    /// collect them, since they represent undocumented parts of the protocol. The handler is called
+    /// before any filter, and replaces any previously registered handler.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, Odk, VirtualSign, VirtualSignBus};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let port = serial::open("COM3")?;
+    /// let mut odk = Odk::try_new(port, bus)?;
+    /// odk.on_unknown(|frame| println!("Unknown frame: {}", frame));
+    /// loop {
+    ///     odk.process_message()?;
+    /// }
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn on_unknown<F>(&mut self, handler: F)
+    where
+        F: FnMut(&Frame<'_>) + 'static,
+    {
+        self.unknown_handler = Some(Box::new(handler));
+    }
+
+    /// Registers a function to be called with every frame read from or written to the ODK, stamped with the time.
+    ///
+    /// Frames from the ODK are reported as soon as they are read, before any filter or address mapping;
+    /// responses are reported right after they are written. This captures the timing between messages,
+    /// which is often the key to diagnosing hardware issues. The handler replaces any previously registered one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, Odk, VirtualSign, VirtualSignBus};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let port = serial::open("COM3")?;
+    /// let mut odk = Odk::try_new(port, bus)?;
+    /// let mut start = None;
+    /// odk.on_frame(move |frame| {
+    ///     let start = *start.get_or_insert(frame.at);
+    ///     println!("{}", frame.display_since(start));
+    /// });
+    /// loop {
+    ///     odk.process_message()?;
+    /// }
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn on_frame<F>(&mut self, handler: F)
+    where
+        F: FnMut(TimestampedFrame) + 'static,
+    {
+        self.frame_handler = Some(Box::new(handler));
+    }
+
+    /// Returns the set of sign addresses the ODK has sent messages to so far.
+    ///
+    /// Addresses are recorded as the ODK sent them, before any filtering or address mapping.
+    /// When bridging an ODK to a bus that answers on every address, this reveals which signs
+    /// the controller is configured to look for.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, Odk, VirtualSignBus};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::with_addresses((2..127).map(Address), PageFlipStyle::Manual);
+    /// let port = serial::open("COM3")?;
+    /// let mut odk = Odk::try_new(port, bus)?;
+    /// for _ in 0..100 {
+    ///     odk.process_message()?;
+    /// }
+    /// println!("ODK polled {:?}", odk.seen_addresses());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn seen_addresses(&self) -> &HashSet<Address> {
+        &self.seen_addresses
+    }
+
+    /// Passes a message through the filter, if any.
+    fn apply_filter(&mut self, direction: Direction, message: Message<'static>) -> Option<Message<'static>> {
+        match self.filter {
+            Some(ref mut filter) => filter(direction, message),
+            None => Some(message),
+        }
+    }
+}
+
+/// Returns the sign address a message is directed to or from, if it carries one.
+fn message_address(message: &Message<'_>) -> Option<Address> {
+    match *message {
+        Message::Hello(address)
+        | Message::QueryState(address)
+        | Message::ReportState(address, _)
+        | Message::RequestOperation(address, _)
+        | Message::AckOperation(address, _)
+        | Message::PixelsComplete(address)
+        | Message::Goodbye(address) => Some(address),
+        _ => None,
+    }
+}
+
+/// Rewrites the sign address of a message according to `map`, leaving unmapped addresses alone.
+fn remap_address(message: Message<'static>, map: &HashMap<Address, Address>) -> Message<'static> {
+    let remap = |address| map.get(&address).copied().unwrap_or(address);
+    match message {
+        Message::Hello(address) => Message::Hello(remap(address)),
+        Message::QueryState(address) => Message::QueryState(remap(address)),
+        Message::ReportState(address, state) => Message::ReportState(remap(address), state),
+        Message::RequestOperation(address, operation) => Message::RequestOperation(remap(address), operation),
+        Message::AckOperation(address, operation) => Message::AckOperation(remap(address), operation),
+        Message::PixelsComplete(address) => Message::PixelsComplete(remap(address)),
+        Message::Goodbye(address) => Message::Goodbye(remap(address)),
+        message => message,
+    }
+}
+
+impl<P: SerialPort + Debug, B: SignBus + Debug> Debug for Odk<P, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Odk")
+            .field("port", &self.port)
+            .field("bus", &self.bus)
+            .field("filter", &self.filter.as_ref().map(|_| ".."))
+            .field("address_map", &self.address_map)
+            .field("seen_addresses", &self.seen_addresses)
+            .field("unknown_handler", &self.unknown_handler.as_ref().map(|_| ".."))
+            .field("frame_handler", &self.frame_handler.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// Compares the port, bus, and address mappings; filters, handlers, and observed traffic are ignored.
+impl<P: SerialPort + PartialEq, B: SignBus + PartialEq> PartialEq for Odk<P, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.port == other.port && self.bus == other.bus && self.address_map == other.address_map
+    }
+}
+
+impl<P: SerialPort + Eq, B: SignBus + Eq> Eq for Odk<P, B> {}
+
+impl<P: SerialPort + Hash, B: SignBus + Hash> Hash for Odk<P, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // HashMap isn't hashable, so the address mappings are left out; equal values still hash equally.
+        self.port.hash(state);
+        self.bus.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flipdot_core::{ChunkCount, Operation, State};
+
+    #[test]
+    fn remap_address_rewrites_mapped_addresses() {
+        let map = HashMap::from([(Address(3), Address(5))]);
+        assert_eq!(Message::Hello(Address(5)), remap_address(Message::Hello(Address(3)), &map));
+        assert_eq!(
+            Message::ReportState(Address(5), State::PageShown),
+            remap_address(Message::ReportState(Address(3), State::PageShown), &map)
+        );
+        assert_eq!(
+            Message::AckOperation(Address(5), Operation::ShowLoadedPage),
+            remap_address(Message::AckOperation(Address(3), Operation::ShowLoadedPage), &map)
+        );
+        assert_eq!(
+            Message::Goodbye(Address(4)),
+            remap_address(Message::Goodbye(Address(4)), &map)
+        );
+    }
+
+    #[test]
+    fn remap_address_ignores_non_address_fields() {
+        // The chunk count shares the address field on the wire but is not an address.
+        let map = HashMap::from([(Address(3), Address(5))]);
+        assert_eq!(
+            Message::DataChunksSent(ChunkCount(3)),
+            remap_address(Message::DataChunksSent(ChunkCount(3)), &map)
+        );
+    }
 }
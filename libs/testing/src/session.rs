@@ -0,0 +1,321 @@
+use std::fmt::{self, Display, Formatter};
+use std::io::{BufRead, Write};
+
+use thiserror::Error;
+
+use flipdot_core::{Frame, FrameError, Message};
+
+/// Errors related to loading and saving [`Session`]s.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum SessionError {
+    /// Failed reading or writing a session transcript.
+    #[error("Failed reading/writing a session")]
+    Io {
+        /// The underlying I/O error.
+        #[from]
+        source: std::io::Error,
+    },
+
+    /// A line of the session did not start with a recognized marker.
+    #[error("Session line {} does not start with \"> \" or \"< \"", line)]
+    InvalidLine {
+        /// The 1-based line number.
+        line: usize,
+    },
+
+    /// A response line appeared without a preceding request, or followed another response.
+    #[error("Session line {} is a response with no pending request", line)]
+    UnexpectedResponse {
+        /// The 1-based line number.
+        line: usize,
+    },
+
+    /// A line of the session did not contain a valid frame.
+    #[error("Session line {} contains an invalid frame", line)]
+    Frame {
+        /// The 1-based line number.
+        line: usize,
+
+        /// The underlying frame error.
+        #[source]
+        source: FrameError,
+    },
+}
+
+/// A captured session of bus traffic, represented as a sequence of requests and their responses.
+///
+/// Each exchange pairs a request from the controller with the sign's response, if any.
+/// Sessions can be saved to and loaded from a simple line-based text format, where each
+/// request is a line starting with `> ` and each response is a line starting with `< `,
+/// followed by the message's frame in Intel HEX. Blank lines are ignored.
+///
+/// Formatting a session with [`Display`] produces a human-readable transcript.
+///
+/// # Examples
+///
+/// ```
+/// use flipdot_core::{Address, Message, State};
+/// use flipdot_testing::Session;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// #
+/// let mut session = Session::new();
+/// session.push(Message::Hello(Address(3)), Some(Message::ReportState(Address(3), State::Unconfigured)));
+/// session.push(Message::Goodbye(Address(3)), None);
+///
+/// let mut saved = Vec::new();
+/// session.save(&mut saved)?;
+/// assert_eq!(session, Session::load(&mut &saved[..])?);
+///
+/// println!("{}", session);
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Session {
+    exchanges: Vec<(Message<'static>, Option<Message<'static>>)>,
+}
+
+impl Session {
+    /// Creates a new empty `Session`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_testing::Session;
+    /// let session = Session::new();
+    /// assert!(session.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Appends an exchange consisting of a request and its optional response.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message};
+    /// # use flipdot_testing::Session;
+    /// let mut session = Session::new();
+    /// session.push(Message::Goodbye(Address(3)), None);
+    /// assert_eq!(1, session.len());
+    /// ```
+    pub fn push(&mut self, request: Message<'static>, response: Option<Message<'static>>) {
+        self.exchanges.push((request, response));
+    }
+
+    /// Returns the exchanges in the order they occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message};
+    /// # use flipdot_testing::Session;
+    /// let mut session = Session::new();
+    /// session.push(Message::Goodbye(Address(3)), None);
+    /// assert_eq!(&[(Message::Goodbye(Address(3)), None)], session.exchanges());
+    /// ```
+    pub fn exchanges(&self) -> &[(Message<'static>, Option<Message<'static>>)] {
+        &self.exchanges
+    }
+
+    /// Returns the number of exchanges in the session.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_testing::Session;
+    /// assert_eq!(0, Session::new().len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.exchanges.len()
+    }
+
+    /// Returns `true` if the session contains no exchanges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_testing::Session;
+    /// assert!(Session::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.exchanges.is_empty()
+    }
+
+    /// Writes the session to `writer` in the line-based text format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SessionError::Io`] if the write fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message};
+    /// # use flipdot_testing::Session;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let mut session = Session::new();
+    /// session.push(Message::Goodbye(Address(3)), None);
+    ///
+    /// let mut saved = Vec::new();
+    /// session.save(&mut saved)?;
+    /// assert_eq!(b"> :0100030255A5\r\n", &saved[..]);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn save<W: Write>(&self, writer: &mut W) -> Result<(), SessionError> {
+        for (request, response) in &self.exchanges {
+            write_message(writer, b"> ", request)?;
+            if let Some(response) = response {
+                write_message(writer, b"< ", response)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads a session in the line-based text format from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SessionError::Io`] if the read fails.
+    /// * [`SessionError::InvalidLine`] if a non-blank line does not start with `> ` or `< `.
+    /// * [`SessionError::UnexpectedResponse`] if a response does not immediately follow a request.
+    /// * [`SessionError::Frame`] if a line does not contain a valid frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Message};
+    /// # use flipdot_testing::Session;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let session = Session::load(&mut &b"> :0100030255A5\r\n"[..])?;
+    /// assert_eq!(&[(Message::Goodbye(Address(3)), None)], session.exchanges());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn load<R: BufRead>(reader: &mut R) -> Result<Self, SessionError> {
+        let mut session = Session::new();
+        let mut awaiting_response = false;
+
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (is_request, frame) = if let Some(frame) = line.strip_prefix("> ") {
+                (true, frame)
+            } else if let Some(frame) = line.strip_prefix("< ") {
+                (false, frame)
+            } else {
+                return Err(SessionError::InvalidLine { line: line_number });
+            };
+
+            let frame = Frame::from_bytes(frame.trim().as_bytes()).map_err(|source| SessionError::Frame {
+                line: line_number,
+                source,
+            })?;
+            let message = Message::from(frame);
+
+            if is_request {
+                session.push(message, None);
+                awaiting_response = true;
+            } else if awaiting_response {
+                if let Some((_, response)) = session.exchanges.last_mut() {
+                    *response = Some(message);
+                }
+                awaiting_response = false;
+            } else {
+                return Err(SessionError::UnexpectedResponse { line: line_number });
+            }
+        }
+
+        Ok(session)
+    }
+}
+
+impl Display for Session {
+    /// Formats the session as a readable transcript, one message per line.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (request, response) in &self.exchanges {
+            writeln!(f, "{}", request)?;
+            match response {
+                Some(response) => writeln!(f, "{}", response)?,
+                None => writeln!(f, "    (no response)")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes a single message as a frame line with the given marker prefix.
+fn write_message<W: Write>(writer: &mut W, marker: &[u8], message: &Message<'_>) -> Result<(), SessionError> {
+    let frame = Frame::from(message.clone());
+    writer.write_all(marker)?;
+    writer.write_all(&frame.to_bytes_with_newline())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flipdot_core::{Address, State};
+
+    fn sample() -> Session {
+        let mut session = Session::new();
+        session.push(
+            Message::QueryState(Address(3)),
+            Some(Message::ReportState(Address(3), State::Unconfigured)),
+        );
+        session.push(Message::Goodbye(Address(3)), None);
+        session
+    }
+
+    #[test]
+    fn roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+        let session = sample();
+        let mut saved = Vec::new();
+        session.save(&mut saved)?;
+        assert_eq!(session, Session::load(&mut &saved[..])?);
+        Ok(())
+    }
+
+    #[test]
+    fn load_ignores_blank_lines() -> Result<(), Box<dyn std::error::Error>> {
+        let session = Session::load(&mut &b"\r\n> :0100030255A5\r\n\r\n"[..])?;
+        assert_eq!(&[(Message::Goodbye(Address(3)), None)], session.exchanges());
+        Ok(())
+    }
+
+    #[test]
+    fn load_errors() {
+        let error = Session::load(&mut &b"? :0100030255A5\n"[..]).unwrap_err();
+        assert!(matches!(error, SessionError::InvalidLine { line: 1 }));
+
+        let error = Session::load(&mut &b"< :0100030255A5\n"[..]).unwrap_err();
+        assert!(matches!(error, SessionError::UnexpectedResponse { line: 1 }));
+
+        let error = Session::load(&mut &b"> :0100030255A5\n< :0100030255A5\n< :0100030255A5\n"[..]).unwrap_err();
+        assert!(matches!(error, SessionError::UnexpectedResponse { line: 3 }));
+
+        let error = Session::load(&mut &b"> :0100030255A5\n> :0100\n"[..]).unwrap_err();
+        assert!(matches!(error, SessionError::Frame { line: 2, .. }));
+    }
+
+    #[test]
+    fn display_transcript() {
+        let transcript = sample().to_string();
+        let lines: Vec<&str> = transcript.lines().collect();
+        assert_eq!(4, lines.len());
+        assert_eq!(Message::QueryState(Address(3)).to_string(), lines[0]);
+        assert_eq!("    (no response)", lines[3]);
+    }
+}
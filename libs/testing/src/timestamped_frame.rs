@@ -0,0 +1,109 @@
+use std::fmt::{self, Display, Formatter};
+use std::time::Instant;
+
+use flipdot_core::Frame;
+
+use crate::Direction;
+
+/// A [`Frame`] that passed through an [`Odk`](crate::Odk), along with when and in which direction.
+///
+/// Useful for diagnosing timing-sensitive problems, such as how long a sign takes to finish
+/// loading or showing a page. Use [`Odk::on_frame`](crate::Odk::on_frame) to receive them.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use flipdot_core::{Address, Data, Frame, MsgType};
+/// use flipdot_testing::{Direction, TimestampedFrame};
+///
+/// let start = Instant::now();
+/// let stamped = TimestampedFrame {
+///     at: start + Duration::from_millis(1500),
+///     direction: Direction::OdkToSign,
+///     frame: Frame::new(Address(3), MsgType(2), Data::from(&[0xFF])),
+/// };
+/// assert_eq!(
+///     "+  1500.000 ms  ODK -> sign  Type 02 | Addr 0003 | Data FF ",
+///     stamped.display_since(start).to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TimestampedFrame {
+    /// When the frame was read from or written to the ODK.
+    pub at: Instant,
+
+    /// Which way the frame was traveling.
+    pub direction: Direction,
+
+    /// The frame itself.
+    pub frame: Frame<'static>,
+}
+
+impl TimestampedFrame {
+    /// Creates a new `TimestampedFrame` stamped with the current time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Instant;
+    /// # use flipdot_core::{Address, Data, Frame, MsgType};
+    /// # use flipdot_testing::{Direction, TimestampedFrame};
+    /// let before = Instant::now();
+    /// let stamped = TimestampedFrame::now(Direction::SignToOdk, Frame::new(Address(3), MsgType(4), Data::from(&[0x0F])));
+    /// assert!(stamped.at >= before);
+    /// ```
+    pub fn now(direction: Direction, frame: Frame<'static>) -> Self {
+        TimestampedFrame {
+            at: Instant::now(),
+            direction,
+            frame,
+        }
+    }
+
+    /// Returns a [`Display`] implementation that formats the frame with its time relative to `start`.
+    ///
+    /// `start` is typically the time of the first frame in a capture. Frames from before `start`
+    /// are shown at zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Data, Frame, MsgType};
+    /// # use flipdot_testing::{Direction, TimestampedFrame};
+    /// let frames = vec![
+    ///     TimestampedFrame::now(Direction::OdkToSign, Frame::new(Address(3), MsgType(3), Data::from(&[]))),
+    ///     TimestampedFrame::now(Direction::SignToOdk, Frame::new(Address(3), MsgType(4), Data::from(&[0x0F]))),
+    /// ];
+    /// for frame in &frames {
+    ///     println!("{}", frame.display_since(frames[0].at));
+    /// }
+    /// ```
+    pub fn display_since(&self, start: Instant) -> impl Display + '_ {
+        RelativeFrame { frame: self, start }
+    }
+}
+
+/// Formats a [`TimestampedFrame`] relative to a starting time.
+#[derive(Debug)]
+struct RelativeFrame<'a> {
+    frame: &'a TimestampedFrame,
+    start: Instant,
+}
+
+impl Display for RelativeFrame<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let elapsed = self.frame.at.saturating_duration_since(self.start);
+        let direction = match self.frame.direction {
+            Direction::OdkToSign => "ODK -> sign",
+            Direction::SignToOdk => "sign -> ODK",
+        };
+        write!(
+            f,
+            "+{:>10.3} ms  {}  {}",
+            elapsed.as_secs_f64() * 1000.0,
+            direction,
+            self.frame.frame
+        )
+    }
+}
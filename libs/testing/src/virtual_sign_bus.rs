@@ -1,9 +1,23 @@
+use std::collections::HashSet;
 use std::error::Error;
 use std::mem;
 
 use log::{debug, info, warn};
-
-use flipdot_core::{Address, ChunkCount, Message, Offset, Operation, Page, PageFlipStyle, SignBus, SignType, State};
+use thiserror::Error;
+
+use flipdot_core::{Address, ChunkCount, Message, Offset, Operation, Page, PageFlipStyle, PageId, SignBus, SignType, State};
+
+/// Errors related to [`VirtualSignBus`]es.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum VirtualSignBusError {
+    /// More than one [`VirtualSign`] was given the same address.
+    #[error("Multiple virtual signs share the same address: {:?}", addresses)]
+    DuplicateAddresses {
+        /// The addresses that were used more than once, in order of first duplication.
+        addresses: Vec<Address>,
+    },
+}
 
 /// Mock implementation of a bus containing one or more signs.
 ///
@@ -47,6 +61,12 @@ pub struct VirtualSignBus<'a> {
 impl<'a> VirtualSignBus<'a> {
     /// Creates a new `VirtualSignBus` with the specified virtual signs.
     ///
+    /// # Panics
+    ///
+    /// Panics if more than one sign has the same address, which would be a misconfiguration
+    /// on a real bus. Use [`try_new`](Self::try_new) to handle this as an error instead, or
+    /// [`new_allow_duplicates`](Self::new_allow_duplicates) to deliberately simulate an address collision.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -63,6 +83,71 @@ impl<'a> VirtualSignBus<'a> {
     /// # Ok(()) }
     /// ```
     pub fn new<I>(signs: I) -> Self
+    where
+        I: IntoIterator<Item = VirtualSign<'a>>,
+    {
+        match Self::try_new(signs) {
+            Ok(bus) => bus,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Creates a new `VirtualSignBus` with the specified virtual signs, ensuring their addresses are unique.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VirtualSignBusError::DuplicateAddresses`] if more than one sign has the same address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, VirtualSign, VirtualSignBus};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::try_new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])?;
+    ///
+    /// let duplicates = vec![VirtualSign::new(Address(3), PageFlipStyle::Manual), VirtualSign::new(Address(3), PageFlipStyle::Manual)];
+    /// assert!(VirtualSignBus::try_new(duplicates).is_err());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn try_new<I>(signs: I) -> Result<Self, VirtualSignBusError>
+    where
+        I: IntoIterator<Item = VirtualSign<'a>>,
+    {
+        let bus = Self::new_allow_duplicates(signs);
+
+        let mut seen = HashSet::new();
+        let mut duplicates = vec![];
+        for sign in &bus.signs {
+            if !seen.insert(sign.address()) && !duplicates.contains(&sign.address()) {
+                duplicates.push(sign.address());
+            }
+        }
+
+        if duplicates.is_empty() {
+            Ok(bus)
+        } else {
+            Err(VirtualSignBusError::DuplicateAddresses { addresses: duplicates })
+        }
+    }
+
+    /// Creates a new `VirtualSignBus` with the specified virtual signs, even if some share the same address.
+    ///
+    /// Messages for a shared address will be handled by whichever of those signs appears first.
+    /// This is useful for simulating an address collision, but is otherwise most likely a mistake.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, VirtualSign, VirtualSignBus};
+    /// let signs = vec![VirtualSign::new(Address(3), PageFlipStyle::Manual), VirtualSign::new(Address(3), PageFlipStyle::Automatic)];
+    /// let bus = VirtualSignBus::new_allow_duplicates(signs);
+    /// assert_eq!(bus.sign(0).address(), bus.sign(1).address());
+    /// ```
+    pub fn new_allow_duplicates<I>(signs: I) -> Self
     where
         I: IntoIterator<Item = VirtualSign<'a>>,
     {
@@ -71,6 +156,30 @@ impl<'a> VirtualSignBus<'a> {
         }
     }
 
+    /// Creates a new `VirtualSignBus` with a [`VirtualSign`] for each of the given addresses, all using the same page flip style.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any address appears more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, VirtualSignBus};
+    /// // Populate bus with signs from addresses 2 to 126
+    /// // (which seems to be the possible range for actual signs).
+    /// let bus = VirtualSignBus::with_addresses((2..127).map(Address), PageFlipStyle::Manual);
+    /// assert_eq!(Address(2), bus.sign(0).address());
+    /// assert_eq!(Address(126), bus.sign(124).address());
+    /// ```
+    pub fn with_addresses<I>(addresses: I, flip_style: PageFlipStyle) -> Self
+    where
+        I: IntoIterator<Item = Address>,
+    {
+        Self::new(addresses.into_iter().map(|address| VirtualSign::new(address, flip_style)))
+    }
+
     /// Returns a reference to the [`VirtualSign`] at a specific index matching the original order passed to [`new`](Self::new).
     ///
     /// Useful when writing tests in order to verify properties of an individual sign.
@@ -88,6 +197,24 @@ impl<'a> VirtualSignBus<'a> {
     pub fn sign(&self, index: usize) -> &VirtualSign<'a> {
         &self.signs[index]
     }
+
+    /// Returns a mutable reference to the [`VirtualSign`] at a specific index matching the original order passed to [`new`](Self::new).
+    ///
+    /// Useful when writing tests in order to change the behavior of an individual sign partway through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, VirtualSign, VirtualSignBus};
+    /// let signs = vec![VirtualSign::new(Address(5), PageFlipStyle::Manual)];
+    /// let mut bus = VirtualSignBus::new(signs);
+    /// bus.sign_mut(0).set_flip_style(PageFlipStyle::Automatic);
+    /// assert_eq!(PageFlipStyle::Automatic, bus.sign(0).flip_style());
+    /// ```
+    pub fn sign_mut(&mut self, index: usize) -> &mut VirtualSign<'a> {
+        &mut self.signs[index]
+    }
 }
 
 impl SignBus for VirtualSignBus<'_> {
@@ -103,6 +230,19 @@ impl SignBus for VirtualSignBus<'_> {
         }
         Ok(None)
     }
+
+    /// Returns the [`SignType`] decoded by the virtual sign at `address`, if it exists and has been configured.
+    fn recognized_sign_type(&self, address: Address) -> Option<SignType> {
+        self.signs
+            .iter()
+            .find(|sign| sign.address() == address)
+            .and_then(VirtualSign::sign_type)
+    }
+
+    /// Always returns `Some(false)`, since the signs are simulated.
+    fn is_physical(&self) -> Option<bool> {
+        Some(false)
+    }
 }
 
 /// Mock implementation of a single sign on a [`VirtualSignBus`].
@@ -120,11 +260,14 @@ pub struct VirtualSign<'a> {
     flip_style: PageFlipStyle,
     state: State,
     pages: Vec<Page<'a>>,
+    current_page: usize,
     pending_data: Vec<u8>,
     data_chunks: u16,
     width: u32,
     height: u32,
     sign_type: Option<SignType>,
+    log_pages: bool,
+    strict_config: bool,
 }
 
 impl VirtualSign<'_> {
@@ -145,14 +288,60 @@ impl VirtualSign<'_> {
             flip_style,
             state: State::Unconfigured,
             pages: vec![],
+            current_page: 0,
             pending_data: vec![],
             data_chunks: 0,
             width: 0,
             height: 0,
             sign_type: None,
+            log_pages: true,
+            strict_config: false,
         }
     }
 
+    /// Sets whether the sign logs the contents of each page it receives.
+    ///
+    /// By default, received pages are logged as ASCII art at the `info` level, which can be noisy
+    /// when simulating many signs at once. Disabling this leaves the rest of the logging unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, VirtualSign, VirtualSignBus};
+    /// let signs = (2..127).map(Address).map(|addr| VirtualSign::new(addr, PageFlipStyle::Manual).with_log_pages(false));
+    /// let bus = VirtualSignBus::new(signs);
+    /// ```
+    pub fn with_log_pages(mut self, log_pages: bool) -> Self {
+        self.log_pages = log_pages;
+        self
+    }
+
+    /// Sets whether the sign rejects configurations that do not match a known [`SignType`].
+    ///
+    /// By default, the sign accepts any configuration it can extract dimensions from, even if the
+    /// specific type is not recognized. In strict mode, it instead transitions to [`State::ConfigFailed`],
+    /// which is useful for testing how a controller handles a sign that rejects its configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{Address, Data, Message, Offset, Operation, PageFlipStyle, State};
+    /// # use flipdot_testing::VirtualSign;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let mut sign = VirtualSign::new(Address(3), PageFlipStyle::Manual).with_strict_config(true);
+    /// let _ = sign.process_message(&Message::RequestOperation(Address(3), Operation::ReceiveConfig));
+    /// let _ = sign.process_message(&Message::SendData(Offset(0), Data::try_new(vec![0x04; 16])?));
+    /// assert_eq!(State::ConfigFailed, sign.state());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn with_strict_config(mut self, strict_config: bool) -> Self {
+        self.strict_config = strict_config;
+        self
+    }
+
     /// Returns the sign's address.
     ///
     /// # Examples
@@ -167,6 +356,38 @@ impl VirtualSign<'_> {
         self.address
     }
 
+    /// Returns the sign's page flip style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, VirtualSign};
+    /// let sign = VirtualSign::new(Address(22), PageFlipStyle::Manual);
+    /// assert_eq!(PageFlipStyle::Manual, sign.flip_style());
+    /// ```
+    pub fn flip_style(&self) -> PageFlipStyle {
+        self.flip_style
+    }
+
+    /// Changes the sign's page flip style.
+    ///
+    /// The new style takes effect the next time the sign finishes receiving pages;
+    /// pages that are already loaded continue to behave according to the previous style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, VirtualSign};
+    /// let mut sign = VirtualSign::new(Address(22), PageFlipStyle::Manual);
+    /// sign.set_flip_style(PageFlipStyle::Automatic);
+    /// assert_eq!(PageFlipStyle::Automatic, sign.flip_style());
+    /// ```
+    pub fn set_flip_style(&mut self, flip_style: PageFlipStyle) {
+        self.flip_style = flip_style;
+    }
+
     /// Returns the sign's current state.
     ///
     /// # Examples
@@ -216,6 +437,93 @@ impl VirtualSign<'_> {
         &self.pages
     }
 
+    /// Returns the sign's current [`Page`] with the given ID, if any.
+    ///
+    /// If more than one page has the same ID, the first one received is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{PageFlipStyle, PageId};
+    /// # use flipdot_testing::{Address, VirtualSign};
+    /// let sign = VirtualSign::new(Address(1), PageFlipStyle::Manual);
+    /// assert_eq!(None, sign.page(PageId(1)));
+    /// ```
+    pub fn page(&self, id: PageId) -> Option<&Page<'_>> {
+        self.pages.iter().find(|page| page.id() == id)
+    }
+
+    /// Returns the sign's current [`Page`]s sorted by [`PageId`], the order in which the sign cycles through them.
+    ///
+    /// Unlike [`pages`](Self::pages), which preserves the order the pages were received in, this reflects
+    /// how the message will actually be displayed. Comparing the two can reveal a controller sending pages
+    /// out of order. Pages with the same ID keep their received order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, VirtualSign};
+    /// let sign = VirtualSign::new(Address(1), PageFlipStyle::Manual);
+    /// assert!(sign.pages_in_order().is_empty());
+    /// ```
+    pub fn pages_in_order(&self) -> Vec<&Page<'_>> {
+        let mut pages: Vec<&Page<'_>> = self.pages.iter().collect();
+        pages.sort_by_key(|page| page.id().0);
+        pages
+    }
+
+    /// Returns the ID and number of lit pixels of each of the sign's current [`Page`]s.
+    ///
+    /// Provides a compact overview of the sign's contents for monitoring, as opposed to logging each page in full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::PageFlipStyle;
+    /// # use flipdot_testing::{Address, VirtualSign};
+    /// let sign = VirtualSign::new(Address(1), PageFlipStyle::Manual);
+    /// assert!(sign.page_summaries().is_empty());
+    /// ```
+    pub fn page_summaries(&self) -> Vec<(PageId, u32)> {
+        self.pages.iter().map(|page| (page.id(), page.lit_count())).collect()
+    }
+
+    /// Returns a snapshot of the sign's current state for diagnostic purposes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot_core::{PageFlipStyle, State};
+    /// # use flipdot_testing::{Address, VirtualSign, VirtualSignSnapshot};
+    /// let sign = VirtualSign::new(Address(3), PageFlipStyle::Manual);
+    /// let expected = VirtualSignSnapshot {
+    ///     address: Address(3),
+    ///     state: State::Unconfigured,
+    ///     sign_type: None,
+    ///     width: 0,
+    ///     height: 0,
+    ///     page_count: 0,
+    ///     current_page_index: None,
+    /// };
+    /// assert_eq!(expected, sign.snapshot());
+    /// ```
+    pub fn snapshot(&self) -> VirtualSignSnapshot {
+        VirtualSignSnapshot {
+            address: self.address,
+            state: self.state,
+            sign_type: self.sign_type,
+            width: self.width,
+            height: self.height,
+            page_count: self.pages.len(),
+            current_page_index: if self.pages.is_empty() {
+                None
+            } else {
+                Some(self.current_page)
+            },
+        }
+    }
+
     /// Handle a bus message, updating our state accordingly.
     ///
     /// # Examples
@@ -257,7 +565,7 @@ impl VirtualSign<'_> {
             _ => {}
         };
 
-        Message::ReportState(self.address, state)
+        state.expected_report(self.address)
     }
 
     /// Handles `RequestOperation` messages for `ReceiveConfig`.
@@ -265,7 +573,7 @@ impl VirtualSign<'_> {
         match self.state {
             State::Unconfigured | State::ConfigFailed => {
                 self.state = State::ConfigInProgress;
-                Some(Message::AckOperation(self.address, Operation::ReceiveConfig))
+                Some(Operation::ReceiveConfig.expected_ack(self.address))
             }
             _ => None,
         }
@@ -291,6 +599,11 @@ impl VirtualSign<'_> {
                 None => warn!("Please report unknown configuration {:?}", data),
             }
 
+            if self.sign_type.is_none() && self.strict_config {
+                self.state = State::ConfigFailed;
+                return None;
+            }
+
             self.width = u32::from(width);
             self.height = u32::from(height);
             self.data_chunks += 1;
@@ -312,14 +625,16 @@ impl VirtualSign<'_> {
                 State::PixelsInProgress => self.state = State::PixelsReceived,
                 _ => {}
             }
+            self.flush_pixels();
         } else {
             match self.state {
                 State::ConfigInProgress => self.state = State::ConfigFailed,
                 State::PixelsInProgress => self.state = State::PixelsFailed,
                 _ => {}
             }
+            // Don't keep a possibly incomplete page around from a failed transfer.
+            self.pending_data.clear();
         }
-        self.flush_pixels();
         self.data_chunks = 0;
         None
     }
@@ -336,7 +651,7 @@ impl VirtualSign<'_> {
             | State::ShowingPages => {
                 self.state = State::PixelsInProgress;
                 self.pages.clear();
-                Some(Message::AckOperation(self.address, Operation::ReceivePixels))
+                Some(Operation::ReceivePixels.expected_ack(self.address))
             }
             _ => None,
         }
@@ -349,15 +664,18 @@ impl VirtualSign<'_> {
                 PageFlipStyle::Automatic => State::ShowingPages,
                 PageFlipStyle::Manual => State::PageLoaded,
             };
-            for page in &self.pages {
-                info!(
-                    "Vsign {:04X} Page {} ({} x {})\n{}",
-                    self.address.0,
-                    page.id(),
-                    page.width(),
-                    page.height(),
-                    page
-                );
+            self.current_page = 0;
+            if self.log_pages {
+                for page in &self.pages {
+                    info!(
+                        "Vsign {:04X} Page {} ({} x {})\n{}",
+                        self.address.0,
+                        page.id(),
+                        page.width(),
+                        page.height(),
+                        page
+                    );
+                }
             }
         }
         None
@@ -367,7 +685,7 @@ impl VirtualSign<'_> {
     fn show_loaded_page<'a>(&mut self) -> Option<Message<'a>> {
         if self.state == State::PageLoaded {
             self.state = State::PageShowInProgress;
-            Some(Message::AckOperation(self.address, Operation::ShowLoadedPage))
+            Some(Operation::ShowLoadedPage.expected_ack(self.address))
         } else {
             None
         }
@@ -377,7 +695,10 @@ impl VirtualSign<'_> {
     fn load_next_page<'a>(&mut self) -> Option<Message<'a>> {
         if self.state == State::PageShown {
             self.state = State::PageLoadInProgress;
-            Some(Message::AckOperation(self.address, Operation::LoadNextPage))
+            if !self.pages.is_empty() {
+                self.current_page = (self.current_page + 1) % self.pages.len();
+            }
+            Some(Operation::LoadNextPage.expected_ack(self.address))
         } else {
             None
         }
@@ -386,14 +707,14 @@ impl VirtualSign<'_> {
     /// Handles `RequestOperation` messages for `StartReset`.
     fn start_reset<'a>(&mut self) -> Message<'a> {
         self.state = State::ReadyToReset;
-        Message::AckOperation(self.address, Operation::StartReset)
+        Operation::StartReset.expected_ack(self.address)
     }
 
     /// Handles `RequestOperation` messages for `FinishReset`.
     fn finish_reset<'a>(&mut self) -> Option<Message<'a>> {
         if self.state == State::ReadyToReset {
             self.reset();
-            Some(Message::AckOperation(self.address, Operation::FinishReset))
+            Some(Operation::FinishReset.expected_ack(self.address))
         } else {
             None
         }
@@ -420,6 +741,7 @@ impl VirtualSign<'_> {
     fn reset(&mut self) {
         self.state = State::Unconfigured;
         self.pages.clear();
+        self.current_page = 0;
         self.pending_data.clear();
         self.data_chunks = 0;
         self.width = 0;
@@ -428,6 +750,36 @@ impl VirtualSign<'_> {
     }
 }
 
+/// A snapshot of the internal state of a [`VirtualSign`], as returned by [`VirtualSign::snapshot`].
+///
+/// Useful for asserting against several properties of a sign at once.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VirtualSignSnapshot {
+    /// The sign's address.
+    pub address: Address,
+
+    /// The sign's current state.
+    pub state: State,
+
+    /// The sign's configured type, if known.
+    pub sign_type: Option<SignType>,
+
+    /// The configured width in pixels, or 0 if not yet configured.
+    pub width: u32,
+
+    /// The configured height in pixels, or 0 if not yet configured.
+    pub height: u32,
+
+    /// The number of pages currently stored.
+    pub page_count: usize,
+
+    /// The index of the page currently loaded or shown, or [`None`] if there are no pages.
+    ///
+    /// Starts at 0 when pages are received and advances each time the next page is loaded, wrapping around
+    /// at the end. This is not tracked for signs that flip pages automatically and will remain 0.
+    pub current_page_index: Option<usize>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -643,6 +995,37 @@ mod tests {
         assert_eq!(Some(Message::ReportState(Address(3), State::Unconfigured)), response);
     }
 
+    #[test]
+    fn duplicate_addresses_rejected() {
+        let signs = vec![
+            VirtualSign::new(Address(3), PageFlipStyle::Manual),
+            VirtualSign::new(Address(5), PageFlipStyle::Manual),
+            VirtualSign::new(Address(3), PageFlipStyle::Manual),
+            VirtualSign::new(Address(7), PageFlipStyle::Manual),
+            VirtualSign::new(Address(7), PageFlipStyle::Manual),
+            VirtualSign::new(Address(3), PageFlipStyle::Manual),
+        ];
+
+        let error = VirtualSignBus::try_new(signs.clone()).unwrap_err();
+        assert!(matches!(
+            error,
+            VirtualSignBusError::DuplicateAddresses { ref addresses } if *addresses == [Address(3), Address(7)]
+        ));
+
+        let bus = VirtualSignBus::new_allow_duplicates(signs);
+        assert_eq!(Address(3), bus.sign(5).address());
+    }
+
+    #[test]
+    #[should_panic]
+    fn duplicate_addresses_panic() {
+        let signs = vec![
+            VirtualSign::new(Address(3), PageFlipStyle::Manual),
+            VirtualSign::new(Address(3), PageFlipStyle::Automatic),
+        ];
+        let _ = VirtualSignBus::new(signs);
+    }
+
     #[test]
     fn invalid_operations() {
         let mut sign = VirtualSign::new(Address(3), PageFlipStyle::Manual);
@@ -666,6 +1049,34 @@ mod tests {
         assert_eq!(None, response);
     }
 
+    #[test]
+    fn chunk_count_mismatch_discards_pixels() {
+        let mut sign = VirtualSign::new(Address(3), PageFlipStyle::Manual);
+
+        let response = sign.process_message(&Message::RequestOperation(Address(3), Operation::ReceiveConfig));
+        assert_eq!(Some(Message::AckOperation(Address(3), Operation::ReceiveConfig)), response);
+
+        let data = Data::try_new(SignType::Max3000Side90x7.to_bytes()).unwrap();
+        let _ = sign.process_message(&Message::SendData(Offset(0x00), data));
+        let _ = sign.process_message(&Message::DataChunksSent(ChunkCount(1)));
+
+        let response = sign.process_message(&Message::RequestOperation(Address(3), Operation::ReceivePixels));
+        assert_eq!(Some(Message::AckOperation(Address(3), Operation::ReceivePixels)), response);
+
+        let page = Page::new(PageId(0), 90, 7);
+        for (i, chunk) in page.as_bytes().chunks(16).enumerate() {
+            let response = sign.process_message(&Message::SendData(Offset((i * 16) as u16), Data::try_new(chunk).unwrap()));
+            assert_eq!(None, response);
+        }
+
+        let response = sign.process_message(&Message::DataChunksSent(ChunkCount(1)));
+        assert_eq!(None, response);
+
+        let response = sign.process_message(&Message::QueryState(Address(3)));
+        assert_eq!(Some(Message::ReportState(Address(3), State::PixelsFailed)), response);
+        assert!(sign.pages().is_empty());
+    }
+
     #[test]
     fn unknown_config() {
         let mut sign = VirtualSign::new(Address(3), PageFlipStyle::Manual);
@@ -696,6 +1107,42 @@ mod tests {
         assert_eq!(9, sign.height);
     }
 
+    #[test]
+    fn strict_unknown_config() {
+        let mut sign = VirtualSign::new(Address(3), PageFlipStyle::Manual).with_strict_config(true);
+
+        let response = sign.process_message(&Message::RequestOperation(Address(3), Operation::ReceiveConfig));
+        assert_eq!(Some(Message::AckOperation(Address(3), Operation::ReceiveConfig)), response);
+
+        let data = vec![
+            0x04, 0x99, 0x00, 0x0F, 0x09, 0x1C, 0x1C, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let response = sign.process_message(&Message::SendData(Offset(0x00), Data::try_new(data).unwrap()));
+        assert_eq!(None, response);
+
+        let response = sign.process_message(&Message::DataChunksSent(ChunkCount(1)));
+        assert_eq!(None, response);
+
+        let response = sign.process_message(&Message::QueryState(Address(3)));
+        assert_eq!(Some(Message::ReportState(Address(3), State::ConfigFailed)), response);
+
+        assert_eq!(None, sign.sign_type());
+        assert_eq!(0, sign.width);
+        assert_eq!(0, sign.height);
+
+        // Known configurations are still accepted.
+        let response = sign.process_message(&Message::RequestOperation(Address(3), Operation::ReceiveConfig));
+        assert_eq!(Some(Message::AckOperation(Address(3), Operation::ReceiveConfig)), response);
+
+        let data = Data::try_new(SignType::Max3000Side90x7.to_bytes()).unwrap();
+        let _ = sign.process_message(&Message::SendData(Offset(0x00), data));
+        let _ = sign.process_message(&Message::DataChunksSent(ChunkCount(1)));
+
+        let response = sign.process_message(&Message::QueryState(Address(3)));
+        assert_eq!(Some(Message::ReportState(Address(3), State::ConfigReceived)), response);
+        assert_eq!(Some(SignType::Max3000Side90x7), sign.sign_type());
+    }
+
     #[test]
     fn invalid_config() {
         let mut sign = VirtualSign::new(Address(3), PageFlipStyle::Manual);
@@ -0,0 +1,197 @@
+//! Checks that a [`SignBus`] implementation follows the sign protocol.
+//!
+//! The [`check`] function drives a sign through each of the standard handshake sequences using a
+//! [`Sign`] and reports any deviations from the expected responses. Each sequence is run independently,
+//! starting from a freshly configured sign where needed, so a single problem doesn't hide others.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//! use flipdot::{conformance, Address, PageFlipStyle, SignType};
+//! use flipdot_testing::{VirtualSign, VirtualSignBus};
+//!
+//! let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+//! let report = conformance::check(Rc::new(RefCell::new(bus)), Address(3), SignType::Max3000Side90x7);
+//! assert!(report.is_conformant(), "{:?}", report.deviations);
+//! ```
+
+use std::cell::RefCell;
+use std::iter;
+use std::rc::Rc;
+
+use crate::core::{Address, PageFlipStyle, SignBus, SignType};
+use crate::smoke_test::test_pages;
+use crate::{Sign, SignError};
+
+/// The handshake sequences exercised by [`check`], in order.
+const SEQUENCES: [Sequence; 5] = [
+    Sequence::Configure,
+    Sequence::SendPages,
+    Sequence::FlipPages,
+    Sequence::Reset,
+    Sequence::ShutDown,
+];
+
+/// A handshake sequence exercised by [`check`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Sequence {
+    /// Resetting the sign if necessary and sending its configuration.
+    Configure,
+
+    /// Sending pixel data for two pages.
+    SendPages,
+
+    /// Showing the first page, then loading and showing the second.
+    ///
+    /// Signs that flip pages automatically pass this sequence trivially.
+    FlipPages,
+
+    /// Resetting and reconfiguring an already configured sign.
+    Reset,
+
+    /// Shutting the sign down.
+    ShutDown,
+}
+
+impl Sequence {
+    /// Returns the sequences that must succeed to put the sign in the right state for this one.
+    fn prerequisites(self) -> &'static [Sequence] {
+        match self {
+            Sequence::Configure | Sequence::ShutDown => &[],
+            Sequence::SendPages | Sequence::Reset => &[Sequence::Configure],
+            Sequence::FlipPages => &[Sequence::Configure, Sequence::SendPages],
+        }
+    }
+}
+
+/// A departure from the expected protocol found by [`check`].
+#[derive(Debug)]
+pub struct Deviation {
+    /// The sequence during which the deviation occurred.
+    pub sequence: Sequence,
+
+    /// The error returned by [`Sign`] for that sequence.
+    pub error: SignError,
+}
+
+/// The results of a [`check`] run.
+#[derive(Debug)]
+pub struct ConformanceReport {
+    /// The sequences that completed as expected, in order.
+    pub passed: Vec<Sequence>,
+
+    /// The sequences that could not be run because one of their prerequisites failed.
+    pub skipped: Vec<Sequence>,
+
+    /// Every deviation found, in order.
+    pub deviations: Vec<Deviation>,
+
+    /// How the sign flips pages, if pages were sent successfully.
+    pub flip_style: Option<PageFlipStyle>,
+}
+
+impl ConformanceReport {
+    /// Returns `true` if every sequence ran and completed as expected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{conformance, Address, PageFlipStyle, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let bus = Rc::new(RefCell::new(bus));
+    /// assert!(conformance::check(bus.clone(), Address(3), SignType::Max3000Side90x7).is_conformant());
+    /// assert!(!conformance::check(bus.clone(), Address(4), SignType::Max3000Side90x7).is_conformant());
+    /// ```
+    pub fn is_conformant(&self) -> bool {
+        self.deviations.is_empty() && self.skipped.is_empty()
+    }
+}
+
+/// Drives the sign at `address` on `bus` through each standard handshake sequence and reports any deviations.
+///
+/// The sign is configured as `sign_type`. Each sequence first runs its prerequisites (e.g. configuring
+/// the sign before sending pages); if a prerequisite fails, the sequence is skipped. Every sequence is
+/// run with a fresh [`Sign`], so failed transfers are retried and waits are bounded by the default
+/// [`operation_timeout`](crate::SignConfig::operation_timeout).
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use flipdot::{conformance, Address, PageFlipStyle, SignType};
+/// use flipdot_testing::{VirtualSign, VirtualSignBus};
+///
+/// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+/// let report = conformance::check(Rc::new(RefCell::new(bus)), Address(3), SignType::Max3000Side90x7);
+///
+/// for deviation in &report.deviations {
+///     println!("{:?}: {}", deviation.sequence, deviation.error);
+/// }
+/// assert_eq!(Some(PageFlipStyle::Manual), report.flip_style);
+/// ```
+pub fn check(bus: Rc<RefCell<dyn SignBus>>, address: Address, sign_type: SignType) -> ConformanceReport {
+    let mut report = ConformanceReport {
+        passed: Vec::new(),
+        skipped: Vec::new(),
+        deviations: Vec::new(),
+        flip_style: None,
+    };
+
+    for sequence in SEQUENCES {
+        if sequence
+            .prerequisites()
+            .iter()
+            .any(|prerequisite| !report.passed.contains(prerequisite))
+        {
+            report.skipped.push(sequence);
+            continue;
+        }
+
+        let sign = Sign::new(bus.clone(), address, sign_type);
+        let mut flip_style = None;
+        let result = sequence
+            .prerequisites()
+            .iter()
+            .chain(iter::once(&sequence))
+            .try_for_each(|&step| {
+                let style = run(&sign, step, flip_style)?;
+                flip_style = flip_style.or(style);
+                Ok::<(), SignError>(())
+            });
+
+        match result {
+            Ok(()) => {
+                report.flip_style = report.flip_style.or(flip_style);
+                report.passed.push(sequence);
+            }
+            Err(error) => report.deviations.push(Deviation { sequence, error }),
+        }
+    }
+
+    report
+}
+
+/// Runs a single sequence, returning the sign's flip style if pages were sent.
+///
+/// `flip_style` is the flip style reported by an earlier [`Sequence::SendPages`], if any.
+fn run(sign: &Sign, sequence: Sequence, flip_style: Option<PageFlipStyle>) -> Result<Option<PageFlipStyle>, SignError> {
+    match sequence {
+        Sequence::Configure | Sequence::Reset => sign.configure().map(|()| None),
+        Sequence::SendPages => sign.send_pages(&test_pages(sign.sign_type())).map(Some),
+        Sequence::FlipPages => {
+            if flip_style == Some(PageFlipStyle::Manual) {
+                sign.show_loaded_page()?;
+                sign.load_next_page()?;
+                sign.show_loaded_page()?;
+            }
+            Ok(None)
+        }
+        Sequence::ShutDown => sign.shut_down().map(|()| None),
+    }
+}
@@ -84,9 +84,22 @@
 pub use flipdot_core as core;
 pub use flipdot_serial as serial;
 
+pub mod conformance;
+mod provision;
 mod sign;
+mod sign_config;
+mod sign_info;
+mod sign_message;
+mod sign_registry;
+mod smoke_test;
 
+pub use self::provision::provision;
 pub use self::sign::{Sign, SignError};
+pub use self::sign_config::SignConfig;
+pub use self::sign_info::SignInfo;
+pub use self::sign_message::{SignMessage, SignMessageError};
+pub use self::sign_registry::{SignRegistry, SignRegistryError};
+pub use self::smoke_test::{smoke_test, SmokeFailure, SmokeReport, SmokeStep};
 
 pub use crate::core::{Address, Page, PageFlipStyle, PageId, SignBus, SignType};
 pub use crate::serial::SerialSignBus;
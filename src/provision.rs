@@ -0,0 +1,52 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::core::{Address, Page, SignBus, SignType};
+use crate::{Sign, SignError};
+
+/// Configures and sends pages to each of several signs on a shared bus, continuing past failures.
+///
+/// Each entry in `items` gives a sign's address, its type, and the pages to send it. The signs are
+/// handled in order; for each one, [`Sign::configure`] is called, followed by [`Sign::send_pages`].
+/// A failure on one sign does not prevent the rest from being provisioned.
+///
+/// Returns the outcome for each sign, in the same order as `items`. Pages are only loaded, not shown;
+/// signs that flip pages manually still need [`Sign::show_loaded_page`] afterward.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use flipdot::{provision, Address, Page, PageFlipStyle, PageId, SignType};
+/// use flipdot_testing::VirtualSignBus;
+///
+/// let bus = VirtualSignBus::with_addresses([Address(1), Address(3)], PageFlipStyle::Manual);
+/// let bus = Rc::new(RefCell::new(bus));
+///
+/// let items = [
+///     (Address(1), SignType::Max3000Front112x16, vec![Page::new(PageId(1), 112, 16)]),
+///     (Address(2), SignType::Max3000Rear30x10, vec![Page::new(PageId(1), 30, 10)]),
+///     (Address(3), SignType::Max3000Side90x7, vec![Page::new(PageId(1), 90, 7)]),
+/// ];
+///
+/// for (address, result) in provision(bus.clone(), &items) {
+///     match result {
+///         Ok(()) => println!("Sign {} ready", address),
+///         Err(e) => println!("Sign {} failed: {}", address, e),
+///     }
+/// }
+/// ```
+pub fn provision(
+    bus: Rc<RefCell<dyn SignBus>>,
+    items: &[(Address, SignType, Vec<Page<'_>>)],
+) -> Vec<(Address, Result<(), SignError>)> {
+    items
+        .iter()
+        .map(|(address, sign_type, pages)| {
+            let sign = Sign::new(bus.clone(), *address, *sign_type);
+            let result = sign.configure().and_then(|_| sign.send_pages(pages)).map(|_| ());
+            (*address, result)
+        })
+        .collect()
+}
@@ -1,11 +1,15 @@
-use std::cell::RefCell;
-use std::iter;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 
-use log::warn;
+use log::{debug, warn};
 use thiserror::Error;
 
-use crate::core::{Address, ChunkCount, Data, Message, Offset, Operation, Page, PageFlipStyle, PageId, SignBus, SignType, State};
+use crate::{SignConfig, SignInfo, SignMessage};
+
+use crate::core::{Address, Message, Operation, Page, PageFlipStyle, PageId, SignBus, SignType, State};
 
 /// Errors related to [`Sign`]s.
 #[derive(Debug, Error)]
@@ -32,6 +36,51 @@ pub enum SignError {
         /// The actual response received.
         actual: String,
     },
+
+    /// No pages were provided to send to the sign.
+    #[error("No pages were provided to send to the sign")]
+    NoPages,
+
+    /// A page to update did not match any page previously sent to the sign.
+    #[error("No page with ID {} was previously sent to the sign", id)]
+    UnknownPage {
+        /// The ID of the page to update.
+        id: PageId,
+    },
+
+    /// A [`SignMessage`] was intended for a different type of sign.
+    #[error("Sign message is for a {:?} sign, but this sign is a {:?}", actual, expected)]
+    SignTypeMismatch {
+        /// The type of this sign.
+        expected: SignType,
+
+        /// The sign type of the message.
+        actual: SignType,
+    },
+
+    /// A page's byte layout did not match the number of bits per column the sign expects.
+    #[error("Page {} uses {} bits per column, but the sign expects {}", id, actual, expected)]
+    BitsPerColumnMismatch {
+        /// The ID of the offending page.
+        id: PageId,
+
+        /// The number of bits per column declared by the sign type.
+        expected: u32,
+
+        /// The number of bits per column implied by the page's height.
+        actual: u32,
+    },
+
+    /// No known sign type's configuration was accepted while probing.
+    #[error("Sign did not accept the configuration for any known sign type")]
+    NoTypeAccepted,
+
+    /// An operation did not complete within the configured [`operation_timeout`](SignConfig::operation_timeout).
+    #[error("Sign operation did not complete within {:?}", timeout)]
+    Timeout {
+        /// The timeout that was exceeded.
+        timeout: Duration,
+    },
 }
 
 /// A single sign on an associated bus.
@@ -82,6 +131,10 @@ pub struct Sign {
     address: Address,
     sign_type: SignType,
     bus: Rc<RefCell<dyn SignBus>>,
+    config: SignConfig,
+    flip_style: Cell<Option<PageFlipStyle>>,
+    sent_pages: RefCell<Vec<Vec<u8>>>,
+    last_retry_count: Cell<u32>,
 }
 
 impl Sign {
@@ -106,7 +159,70 @@ impl Sign {
     /// # Ok(()) }
     /// ```
     pub fn new(bus: Rc<RefCell<dyn SignBus>>, address: Address, sign_type: SignType) -> Self {
-        Sign { address, sign_type, bus }
+        Self::with_config(bus, address, sign_type, SignConfig::default())
+    }
+
+    /// Creates a new `Sign` like [`new`](Self::new), but with custom [`SignConfig`] settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use std::time::Duration;
+    /// # use flipdot::{Address, PageId, Sign, SignConfig, SignType};
+    /// # use flipdot_testing::VirtualSignBus;
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> { Rc::new(RefCell::new(VirtualSignBus::new(vec![]))) }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let config = SignConfig::default().with_shutdown_lockout(Duration::from_secs(40));
+    /// let sign = Sign::with_config(bus.clone(), Address(3), SignType::Max3000Side90x7, config);
+    /// assert_eq!(config, *sign.config());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn with_config(bus: Rc<RefCell<dyn SignBus>>, address: Address, sign_type: SignType, config: SignConfig) -> Self {
+        Sign {
+            address,
+            sign_type,
+            bus,
+            config,
+            flip_style: Cell::new(None),
+            sent_pages: RefCell::new(Vec::new()),
+            last_retry_count: Cell::new(0),
+        }
+    }
+
+    /// Creates a new `Sign` at a different address on the same bus, with the same type and [`SignConfig`].
+    ///
+    /// This is convenient when several signs of the same type share a bus. The new `Sign` starts fresh:
+    /// nothing it learns (such as the page flip style) is shared with this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, Sign, SignType};
+    /// # use flipdot_testing::VirtualSignBus;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::with_addresses([Address(3), Address(4)], PageFlipStyle::Manual);
+    /// let bus = Rc::new(RefCell::new(bus));
+    ///
+    /// let first = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// let second = first.with_address(Address(4));
+    /// assert_eq!(Address(4), second.address());
+    /// assert_eq!(SignType::Max3000Side90x7, second.sign_type());
+    /// second.configure()?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn with_address(&self, address: Address) -> Sign {
+        Self::with_config(self.bus.clone(), address, self.sign_type, self.config)
     }
 
     /// Returns the sign's address.
@@ -157,6 +273,30 @@ impl Sign {
         self.sign_type
     }
 
+    /// Returns the sign's configuration settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageId, Sign, SignConfig, SignType};
+    /// # use flipdot_testing::VirtualSignBus;
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> { Rc::new(RefCell::new(VirtualSignBus::new(vec![]))) }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// assert_eq!(SignConfig::default(), *sign.config());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn config(&self) -> &SignConfig {
+        &self.config
+    }
+
     /// Returns the width in pixels of the sign's display area.
     ///
     /// # Examples
@@ -205,6 +345,155 @@ impl Sign {
         self.sign_type.dimensions().1
     }
 
+    /// Returns the width and height in pixels of the sign's display area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageId, Sign, SignType};
+    /// # use flipdot_testing::VirtualSignBus;
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> { Rc::new(RefCell::new(VirtualSignBus::new(vec![]))) }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// let (width, height) = sign.dimensions();
+    /// assert_eq!((90, 7), (width, height));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn dimensions(&self) -> (u32, u32) {
+        self.sign_type.dimensions()
+    }
+
+    /// Returns the operations that have an effect on this sign.
+    ///
+    /// Signs that flip pages automatically ignore [`Operation::ShowLoadedPage`] and [`Operation::LoadNextPage`],
+    /// so those are omitted once [`send_pages`](Self::send_pages) has determined that the sign uses
+    /// [`PageFlipStyle::Automatic`]. Until then, all operations are assumed to be supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, PageId, Sign, SignType};
+    /// # use flipdot::core::Operation;
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Automatic)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// assert!(sign.supported_operations().contains(&Operation::ShowLoadedPage));
+    ///
+    /// sign.configure()?;
+    /// sign.send_pages(&[sign.create_page(PageId(1))])?;
+    /// assert!(!sign.supported_operations().contains(&Operation::ShowLoadedPage));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn supported_operations(&self) -> &[Operation] {
+        const ALL: &[Operation] = &[
+            Operation::ReceiveConfig,
+            Operation::ReceivePixels,
+            Operation::ShowLoadedPage,
+            Operation::LoadNextPage,
+            Operation::StartReset,
+            Operation::FinishReset,
+        ];
+
+        const AUTOMATIC: &[Operation] = &[
+            Operation::ReceiveConfig,
+            Operation::ReceivePixels,
+            Operation::StartReset,
+            Operation::FinishReset,
+        ];
+
+        match self.flip_style.get() {
+            Some(PageFlipStyle::Automatic) => AUTOMATIC,
+            Some(PageFlipStyle::Manual) | None => ALL,
+        }
+    }
+
+    /// Returns how the sign flips pages, if known.
+    ///
+    /// This is `None` until pages have been sent successfully with [`send_pages`](Self::send_pages)
+    /// (or a related method), after which it remembers the [`PageFlipStyle`] that was returned.
+    /// Since the flip style is a property of the sign itself, it is retained across reconfiguration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, PageId, Sign, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// assert_eq!(None, sign.known_flip_style());
+    ///
+    /// sign.configure()?;
+    /// sign.send_pages(&[sign.create_page(PageId(1))])?;
+    /// assert_eq!(Some(PageFlipStyle::Manual), sign.known_flip_style());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn known_flip_style(&self) -> Option<PageFlipStyle> {
+        self.flip_style.get()
+    }
+
+    /// Returns how many times the most recent configuration or pixel data transfer had to be retried.
+    ///
+    /// Transfers that the sign reports as failed are retried automatically, so an operation can
+    /// succeed even though the bus is unreliable. A nonzero count here is an early warning of
+    /// cabling or noise problems. This is `0` before any transfer has been attempted, and is updated
+    /// by [`configure`](Self::configure), [`send_pages`](Self::send_pages), and related methods
+    /// whether they succeed or fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, PageId, Sign, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// sign.configure()?;
+    /// sign.send_pages(&[sign.create_page(PageId(1))])?;
+    /// if sign.last_retry_count() > 0 {
+    ///     println!("Pages needed {} retries; check the wiring", sign.last_retry_count());
+    /// }
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn last_retry_count(&self) -> u32 {
+        self.last_retry_count.get()
+    }
+
     /// Creates a page with the given ID that matches the sign's dimensions.
     ///
     /// # Examples
@@ -249,6 +538,8 @@ impl Sign {
     /// * [`SignError::Bus`] if the underlying bus failed to process a message.
     /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
     ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    /// * [`SignError::Timeout`] if the operation did not complete within the configured
+    ///   [`operation_timeout`](SignConfig::operation_timeout).
     ///
     /// # Examples
     ///
@@ -273,21 +564,94 @@ impl Sign {
     /// ```
     pub fn configure(&self) -> Result<(), SignError> {
         self.ensure_unconfigured()?;
+        self.send_config()
+    }
 
-        let config = self.sign_type.to_bytes();
-        self.send_data(
-            &iter::once(config),
-            Operation::ReceiveConfig,
-            State::ConfigReceived,
-            State::ConfigFailed,
-        )
+    /// Configures the sign like [`configure`](Self::configure), then returns the [`SignType`] the sign recognized, if known.
+    ///
+    /// Real signs cannot report this, so `None` is returned unless the bus can provide it
+    /// (see [`SignBus::recognized_sign_type`]). `VirtualSignBus` from [`flipdot-testing`] returns
+    /// the type its virtual sign decoded from the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`configure`](Self::configure).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, Sign, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let bus = Rc::new(RefCell::new(bus));
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// assert_eq!(Some(SignType::Max3000Side90x7), sign.configure_verbose()?);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`flipdot-testing`]: https://docs.rs/flipdot_testing
+    pub fn configure_verbose(&self) -> Result<Option<SignType>, SignError> {
+        self.configure()?;
+        Ok(self.bus.borrow().recognized_sign_type(self.address))
+    }
+
+    /// Determines the type of the sign at `address` by trying to configure it as each known [`SignType`] in turn.
+    ///
+    /// The sign is reset before each attempt, and the first type whose configuration it accepts is returned
+    /// (refined by [`SignBus::recognized_sign_type`] if the bus can report it). The sign is left configured
+    /// as that type. Note that the protocol gives a sign no way to report its own model, and real signs may
+    /// accept configurations that don't quite match, so the result should be confirmed by sending a test
+    /// page. Since each rejected type is retried like any other failed transfer, probing can take a while.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignError::NoTypeAccepted`] if the sign rejected every known configuration.
+    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
+    /// * [`SignError::Timeout`] if a transfer was not accepted within the default operation timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, Sign, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let bus = Rc::new(RefCell::new(bus));
+    /// let sign_type = Sign::probe_type(bus.clone(), Address(3))?;
+    /// let sign = Sign::new(bus.clone(), Address(3), sign_type);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn probe_type(bus: Rc<RefCell<dyn SignBus>>, address: Address) -> Result<SignType, SignError> {
+        for &candidate in SignType::ALL {
+            let sign = Sign::new(bus.clone(), address, candidate);
+            match sign.configure() {
+                Ok(()) => return Ok(bus.borrow().recognized_sign_type(address).unwrap_or(candidate)),
+                Err(SignError::UnexpectedResponse { .. }) => {
+                    debug!("Sign {:04X} rejected configuration for {:?}", address.0, candidate);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(SignError::NoTypeAccepted)
     }
 
     /// Opens communications with the sign and sends the necessary configuration if needed.
     ///
     /// This or [`configure`](Self::configure) must be called first before communicating with the sign.
     /// If the sign has already been configured and is in a state where it can receive pages,
-    /// nothing will happen. Otherwise, it will be reset and its page memory will be cleared.
+    /// nothing will happen, so the current display is preserved. If the sign is unconfigured or a previous
+    /// configuration attempt failed, the configuration is sent directly without first resetting the sign.
+    /// Otherwise (e.g. partway through a reset or data transfer), it will be reset and its page memory will be cleared.
     ///
     /// Use this if you are confident that the sign is already in a good state and doesn't need a full reset
     /// (e.g. updating periodically via a cron job).
@@ -298,6 +662,8 @@ impl Sign {
     /// * [`SignError::Bus`] if the underlying bus failed to process a message.
     /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
     ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    /// * [`SignError::Timeout`] if the operation did not complete within the configured
+    ///   [`operation_timeout`](SignConfig::operation_timeout).
     ///
     /// # Examples
     ///
@@ -323,17 +689,17 @@ impl Sign {
     pub fn configure_if_needed(&self) -> Result<(), SignError> {
         let response = self.send_message(Message::Hello(self.address))?;
         match response {
-            Some(Message::ReportState(address, State::ConfigReceived))
-            | Some(Message::ReportState(address, State::ShowingPages))
-            | Some(Message::ReportState(address, State::PageLoaded))
-            | Some(Message::ReportState(address, State::PageShowInProgress))
-            | Some(Message::ReportState(address, State::PageShown))
-            | Some(Message::ReportState(address, State::PageLoadInProgress))
-                if address == self.address => {}
-
-            _ => self.configure()?,
+            Some(Message::ReportState(address, state)) if address == self.address && can_receive_pages(state) => Ok(()),
+
+            Some(Message::ReportState(address, State::Unconfigured))
+            | Some(Message::ReportState(address, State::ConfigFailed))
+                if address == self.address =>
+            {
+                self.send_config()
+            }
+
+            _ => self.configure(),
         }
-        Ok(())
     }
 
     /// Sends one or more pages of pixel data to the sign.
@@ -341,12 +707,24 @@ impl Sign {
     /// Can be called at any time after [`configure`](Self::configure). Replaces any pages that had been previously sent.
     /// Upon return, the first page will be loaded and ready to be shown.
     ///
+    /// Any iterator of page references is accepted, even a one-shot one. The references are
+    /// collected up front so the data can be resent if the transfer needs to be retried.
+    ///
+    /// A copy of every page's data is kept afterward so [`update_page`](Self::update_page) can resend them,
+    /// so memory use grows with the number and size of the pages. The copy is released when the sign is
+    /// next configured or shut down.
+    ///
     /// # Errors
     ///
     /// Returns:
+    /// * [`SignError::NoPages`] if `pages` is empty. Nothing is sent in this case.
+    /// * [`SignError::BitsPerColumnMismatch`] if a page's height implies a different byte layout than
+    ///   the sign type declares. Nothing is sent in this case.
     /// * [`SignError::Bus`] if the underlying bus failed to process a message.
     /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
     ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    /// * [`SignError::Timeout`] if the operation did not complete within the configured
+    ///   [`operation_timeout`](SignConfig::operation_timeout).
     ///
     /// # Examples
     ///
@@ -378,34 +756,42 @@ impl Sign {
     pub fn send_pages<'a, I>(&self, pages: I) -> Result<PageFlipStyle, SignError>
     where
         I: IntoIterator<Item = &'a Page<'a>>,
-        <I as IntoIterator>::IntoIter: Clone,
     {
-        let data = pages.into_iter().map(Page::as_bytes);
-        self.send_data(&data, Operation::ReceivePixels, State::PixelsReceived, State::PixelsFailed)?;
+        let pages: Vec<&Page<'a>> = pages.into_iter().collect();
+        if pages.is_empty() {
+            return Err(SignError::NoPages);
+        }
+        for page in &pages {
+            self.check_bits_per_column(page)?;
+        }
 
-        self.send_message_expect_response(Message::PixelsComplete(self.address), &None)?;
+        let data: Vec<&[u8]> = pages.into_iter().map(Page::as_bytes).collect();
 
-        let response = self.send_message(Message::QueryState(self.address))?;
-        match response {
-            Some(Message::ReportState(address, state)) if address == self.address && state == State::ShowingPages => {
-                Ok(PageFlipStyle::Automatic)
-            }
-            _ => Ok(PageFlipStyle::Manual),
-        }
+        let flip_style = self.send_page_data(&data)?;
+        *self.sent_pages.borrow_mut() = data.into_iter().map(<[u8]>::to_vec).collect();
+        Ok(flip_style)
     }
 
-    /// Loads the next page into memory.
-    ///
-    /// Once a page has been shown, this is called to prepare the next page to be shown.
+    /// Replaces a single previously sent page, identified by its ID.
     ///
-    /// If [`send_pages`](Self::send_pages) returned [`PageFlipStyle::Automatic`], you should not call this function since the sign will show and flip pages itself.
+    /// The protocol has no known way to write one page in isolation: the sign discards its page memory whenever
+    /// new pixel data arrives. This therefore re-sends all the pages from the last call to
+    /// [`send_pages`](Self::send_pages), with the page matching `page`'s ID swapped out. It saves
+    /// regenerating the other pages, not bus traffic. Upon return, the first page will be loaded
+    /// and ready to be shown, as with [`send_pages`](Self::send_pages).
     ///
     /// # Errors
     ///
     /// Returns:
+    /// * [`SignError::UnknownPage`] if no page with the same ID was sent since the sign was last configured
+    ///   or shut down. Nothing is sent in this case.
+    /// * [`SignError::BitsPerColumnMismatch`] if a page's height implies a different byte layout than
+    ///   the sign type declares. Nothing is sent in this case.
     /// * [`SignError::Bus`] if the underlying bus failed to process a message.
     /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
     ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    /// * [`SignError::Timeout`] if the operation did not complete within the configured
+    ///   [`operation_timeout`](SignConfig::operation_timeout).
     ///
     /// # Examples
     ///
@@ -424,34 +810,178 @@ impl Sign {
     /// let bus = get_bus();
     /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
     /// sign.configure()?;
+    /// sign.send_pages(&[sign.create_page(PageId(1)), sign.create_page(PageId(2))])?;
     ///
-    /// let pages = [sign.create_page(PageId(1)), sign.create_page(PageId(2))];
-    /// if sign.send_pages(&pages)? == PageFlipStyle::Manual {
-    ///     sign.show_loaded_page()?;
-    ///
-    ///     sign.load_next_page()?;
-    ///     // Page 1 is now shown and page 2 is loaded.
-    /// }
+    /// let mut page = sign.create_page(PageId(2));
+    /// page.set_pixel(0, 0, true);
+    /// sign.update_page(&page)?;
+    /// // Page 1 is unchanged and page 2 has been replaced.
     /// #
     /// # Ok(()) }
     /// ```
-    pub fn load_next_page(&self) -> Result<(), SignError> {
-        self.switch_page(State::PageLoaded, State::PageShown, Operation::LoadNextPage)
+    pub fn update_page(&self, page: &Page<'_>) -> Result<PageFlipStyle, SignError> {
+        self.check_bits_per_column(page)?;
+        let mut pages = self.sent_pages.borrow().clone();
+        let slot = pages
+            .iter_mut()
+            .find(|bytes| bytes[0] == page.id().0)
+            .ok_or(SignError::UnknownPage { id: page.id() })?;
+        *slot = page.as_bytes().to_vec();
+
+        let data: Vec<&[u8]> = pages.iter().map(Vec::as_slice).collect();
+        let flip_style = self.send_page_data(&data)?;
+        *self.sent_pages.borrow_mut() = pages;
+        Ok(flip_style)
     }
 
-    /// Shows the currently loaded page on the display.
-    ///
-    /// Once a page has been loaded (either via [`send_pages`](Self::send_pages) or [`load_next_page`](Self::load_next_page)), this method will make it visible.
-    ///
-    /// If [`send_pages`](Self::send_pages) returned [`PageFlipStyle::Automatic`], you should not call this function since the sign will show and flip pages itself.
-    ///
-    /// # Errors
-    ///
-    /// Returns:
-    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
-    /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
-    ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
-    ///
+    /// Fails with [`SignError::BitsPerColumnMismatch`] if `page` is laid out differently than the sign type declares.
+    fn check_bits_per_column(&self, page: &Page<'_>) -> Result<(), SignError> {
+        let actual = page.bytes_per_column() as u32 * 8;
+        match self.sign_type.bits_per_column() {
+            Some(expected) if expected != actual => Err(SignError::BitsPerColumnMismatch {
+                id: page.id(),
+                expected,
+                actual,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Sends the raw bytes of one or more pages and reports how the sign will flip them.
+    fn send_page_data(&self, data: &[&[u8]]) -> Result<PageFlipStyle, SignError> {
+        self.send_data(
+            data,
+            Operation::ReceivePixels,
+            State::PixelsInProgress,
+            State::PixelsReceived,
+            State::PixelsFailed,
+        )?;
+
+        self.send_message_expect_response(Message::PixelsComplete(self.address), &None)?;
+
+        let response = self.send_message(Message::QueryState(self.address))?;
+        let flip_style = match response {
+            Some(Message::ReportState(address, state)) if address == self.address && state == State::ShowingPages => {
+                PageFlipStyle::Automatic
+            }
+            _ => PageFlipStyle::Manual,
+        };
+        self.flip_style.set(Some(flip_style));
+        Ok(flip_style)
+    }
+
+    /// Sends the pages of a saved [`SignMessage`] to the sign.
+    ///
+    /// Behaves like [`send_pages`](Self::send_pages), but first verifies that the message was created
+    /// for this type of sign.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignError::SignTypeMismatch`] if the message is for a different type of sign. Nothing is sent in this case.
+    /// * [`SignError::NoPages`] if the message has no pages. Nothing is sent in this case.
+    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
+    /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
+    ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    /// * [`SignError::Timeout`] if the operation did not complete within the configured
+    ///   [`operation_timeout`](SignConfig::operation_timeout).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::cell::RefCell;
+    /// # use std::fs::File;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, Sign, SignMessage, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// sign.configure()?;
+    ///
+    /// let message = SignMessage::load(&mut File::open("message.bin")?)?;
+    /// sign.send_message_file(&message)?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn send_message_file(&self, message: &SignMessage) -> Result<PageFlipStyle, SignError> {
+        if message.sign_type != self.sign_type {
+            return Err(SignError::SignTypeMismatch {
+                expected: self.sign_type,
+                actual: message.sign_type,
+            });
+        }
+        self.send_pages(&message.pages)
+    }
+
+    /// Loads the next page into memory.
+    ///
+    /// Once a page has been shown, this is called to prepare the next page to be shown.
+    ///
+    /// If [`send_pages`](Self::send_pages) returned [`PageFlipStyle::Automatic`], you should not call this function since the sign will show and flip pages itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
+    /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
+    ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    /// * [`SignError::Timeout`] if the operation did not complete within the configured
+    ///   [`operation_timeout`](SignConfig::operation_timeout).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, PageId, Sign, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// sign.configure()?;
+    ///
+    /// let pages = [sign.create_page(PageId(1)), sign.create_page(PageId(2))];
+    /// if sign.send_pages(&pages)? == PageFlipStyle::Manual {
+    ///     sign.show_loaded_page()?;
+    ///
+    ///     sign.load_next_page()?;
+    ///     // Page 1 is now shown and page 2 is loaded.
+    /// }
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn load_next_page(&self) -> Result<(), SignError> {
+        self.switch_page(State::PageLoaded, State::PageShown, Operation::LoadNextPage)
+    }
+
+    /// Shows the currently loaded page on the display.
+    ///
+    /// Once a page has been loaded (either via [`send_pages`](Self::send_pages) or [`load_next_page`](Self::load_next_page)), this method will make it visible.
+    ///
+    /// If [`send_pages`](Self::send_pages) returned [`PageFlipStyle::Automatic`], you should not call this function since the sign will show and flip pages itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
+    /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
+    ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    /// * [`SignError::Timeout`] if the operation did not complete within the configured
+    ///   [`operation_timeout`](SignConfig::operation_timeout).
+    ///
     /// # Examples
     ///
     /// ```
@@ -482,6 +1012,347 @@ impl Sign {
         self.switch_page(State::PageShown, State::PageLoaded, Operation::ShowLoadedPage)
     }
 
+    /// Plays an animation of arbitrary length by sending `frames` to the sign in batches.
+    ///
+    /// Each batch of up to `batch` pages is sent with [`send_pages`](Self::send_pages) and then displayed
+    /// before the next batch is sent, so animations can be longer than the sign's page memory.
+    /// Each frame is shown for `frame_duration`. For signs using [`PageFlipStyle::Manual`], each page
+    /// is shown in turn with [`show_loaded_page`](Self::show_loaded_page) and [`load_next_page`](Self::load_next_page).
+    /// Signs using [`PageFlipStyle::Automatic`] flip pages on their own schedule, so this simply waits
+    /// `frame_duration` per page in the batch before continuing; `frame_duration` should match the sign's
+    /// [flip interval](PageFlipStyle::Automatic) to avoid cutting the batch short or repeating pages.
+    ///
+    /// Returns once the final batch has been displayed. Nothing is sent if `frames` is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
+    /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
+    ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    /// * [`SignError::Timeout`] if an operation did not complete within the configured
+    ///   [`operation_timeout`](SignConfig::operation_timeout).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use std::time::Duration;
+    /// # use flipdot::{Address, PageFlipStyle, PageId, Sign, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// sign.configure()?;
+    ///
+    /// // A dot moving across the whole display, eight frames at a time.
+    /// let frames = (0..sign.width()).map(|x| {
+    ///     let mut page = sign.create_page(PageId((x % 8) as u8));
+    ///     page.set_pixel(x, 3, true);
+    ///     page
+    /// });
+    /// # let frames = frames.take(10);
+    /// sign.stream_animation(frames, 8, Duration::from_millis(100))?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn stream_animation<'a, I>(&self, frames: I, batch: usize, frame_duration: Duration) -> Result<(), SignError>
+    where
+        I: IntoIterator<Item = Page<'a>>,
+    {
+        assert!(batch > 0, "Batch size must be at least 1");
+
+        let mut frames = frames.into_iter().peekable();
+        while frames.peek().is_some() {
+            let pages: Vec<Page<'a>> = frames.by_ref().take(batch).collect();
+            match self.send_pages(&pages)? {
+                PageFlipStyle::Manual => {
+                    for i in 0..pages.len() {
+                        if i > 0 {
+                            self.load_next_page()?;
+                        }
+                        self.show_loaded_page()?;
+                        thread::sleep(frame_duration);
+                    }
+                }
+
+                PageFlipStyle::Automatic => thread::sleep(frame_duration * pages.len() as u32),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sends `pages` and then keeps cycling through them until `stop` is set.
+    ///
+    /// For signs that flip pages manually, each page is shown for `per_page` before the next is loaded,
+    /// wrapping back to the first page after the last. `stop` is checked frequently while waiting, so
+    /// setting it from another thread (e.g. a signal handler) ends the loop promptly, leaving the current
+    /// page on display. Signs that flip pages automatically cycle on their own, so this returns as soon as
+    /// the pages are sent.
+    ///
+    /// Returns the [`PageFlipStyle`] reported when the pages were sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignError::NoPages`] if `pages` is empty. Nothing is sent in this case.
+    /// * [`SignError::BitsPerColumnMismatch`] if a page's height implies a different byte layout than
+    ///   the sign type declares. Nothing is sent in this case.
+    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
+    /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
+    ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    /// * [`SignError::Timeout`] if an operation did not complete within the configured
+    ///   [`operation_timeout`](SignConfig::operation_timeout).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use std::sync::atomic::AtomicBool;
+    /// # use std::time::Duration;
+    /// # use flipdot::{Address, PageFlipStyle, PageId, Sign, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// static STOP: AtomicBool = AtomicBool::new(false);
+    ///
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// sign.configure()?;
+    ///
+    /// let pages = [sign.create_page(PageId(1)), sign.create_page(PageId(2))];
+    /// // Runs until something else sets STOP.
+    /// sign.run_cycle(&pages, Duration::from_secs(5), &STOP)?;
+    /// sign.shut_down()?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn run_cycle<'a, I>(&self, pages: I, per_page: Duration, stop: &AtomicBool) -> Result<PageFlipStyle, SignError>
+    where
+        I: IntoIterator<Item = &'a Page<'a>>,
+    {
+        let flip_style = self.send_pages(pages)?;
+        if flip_style == PageFlipStyle::Manual {
+            self.show_loaded_page()?;
+            while sleep_unless_stopped(per_page, stop) {
+                self.load_next_page()?;
+                self.show_loaded_page()?;
+            }
+        }
+        Ok(flip_style)
+    }
+
+    /// Watches over a sign showing `pages`, restoring them if the sign resets unexpectedly, until `stop` is set.
+    ///
+    /// The pages should already have been sent. Every `poll`, the sign's state is queried; if it reports
+    /// [`Unconfigured`](State::Unconfigured) (e.g. after a power glitch), the sign is reconfigured and the pages
+    /// are sent again, showing the first one if the sign flips pages manually. Like [`run_cycle`](Self::run_cycle),
+    /// `stop` is checked frequently while waiting, so it can be set from another thread to end the loop promptly.
+    ///
+    /// Returns the number of times the sign had to be restored.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
+    /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
+    ///   to the protocol.
+    /// * Any error from [`configure`](Self::configure) or [`send_pages`](Self::send_pages) while restoring the sign.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use std::sync::atomic::AtomicBool;
+    /// # use std::time::Duration;
+    /// # use flipdot::{Address, PageFlipStyle, PageId, Sign, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Automatic)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// static STOP: AtomicBool = AtomicBool::new(false);
+    ///
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// sign.configure()?;
+    ///
+    /// let pages = [sign.create_page(PageId(1)), sign.create_page(PageId(2))];
+    /// sign.send_pages(&pages)?;
+    /// // Runs until something else sets STOP.
+    /// let restored = sign.maintain(&pages, Duration::from_secs(30), &STOP)?;
+    /// println!("Sign was restored {} times", restored);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn maintain(&self, pages: &[Page<'_>], poll: Duration, stop: &AtomicBool) -> Result<u32, SignError> {
+        let mut restored = 0;
+        while sleep_unless_stopped(poll, stop) {
+            if self.identify()?.state == State::Unconfigured {
+                warn!("Sign {:04X} reset unexpectedly; restoring its pages.", self.address.0);
+                self.configure()?;
+                if self.send_pages(pages)? == PageFlipStyle::Manual {
+                    self.show_loaded_page()?;
+                }
+                restored += 1;
+            }
+        }
+        Ok(restored)
+    }
+
+    /// Queries the sign and returns identifying information about it.
+    ///
+    /// The protocol has no known message for reporting firmware or version information, so this
+    /// only confirms that the sign responds and reports its current state; the remaining fields
+    /// of [`SignInfo`] come from the [`SignType`] this `Sign` was created with.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
+    /// * [`SignError::UnexpectedResponse`] if the sign did not report its state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, Sign, SignType};
+    /// # use flipdot::core::State;
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// let info = sign.identify()?;
+    /// assert_eq!(State::Unconfigured, info.state);
+    /// assert_eq!((90, 7), (info.width, info.height));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn identify(&self) -> Result<SignInfo, SignError> {
+        let response = self.send_message(Message::QueryState(self.address))?;
+        match response {
+            Some(Message::ReportState(address, state)) if address == self.address => {
+                let (width, height) = self.sign_type.dimensions();
+                Ok(SignInfo {
+                    address: self.address,
+                    sign_type: self.sign_type,
+                    width,
+                    height,
+                    config: self.sign_type.to_bytes(),
+                    state,
+                })
+            }
+
+            _ => Err(SignError::UnexpectedResponse {
+                expected: format!("Some(ReportState({:?}, _))", self.address),
+                actual: format!("{:?}", response),
+            }),
+        }
+    }
+
+    /// Queries the sign and returns it to a state where it can receive pages, resetting it only if necessary.
+    ///
+    /// Useful after an error (e.g. a failed [`send_pages`](Self::send_pages)) has left the sign partway
+    /// through a transfer. If the sign is already configured and able to receive pages, nothing is changed
+    /// and its current state is returned. If it is unconfigured or a previous configuration attempt failed,
+    /// the configuration is sent. Otherwise, the sign is fully reset and reconfigured, clearing its page memory.
+    /// In these latter cases, [`State::ConfigReceived`] is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
+    /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
+    ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    /// * [`SignError::Timeout`] if the operation did not complete within the configured
+    ///   [`operation_timeout`](SignConfig::operation_timeout).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, PageId, Sign, SignType};
+    /// # use flipdot::core::State;
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// sign.configure()?;
+    ///
+    /// let page = sign.create_page(PageId(1));
+    /// if sign.send_pages(&[page.clone()]).is_err() {
+    ///     // Get back to a known good state and try again.
+    ///     sign.recover()?;
+    ///     sign.send_pages(&[page])?;
+    /// }
+    /// # assert_eq!(State::PageLoaded, sign.recover()?);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn recover(&self) -> Result<State, SignError> {
+        let response = self.send_message(Message::QueryState(self.address))?;
+        let state = match response {
+            Some(Message::ReportState(address, state)) if address == self.address => state,
+
+            _ => {
+                return Err(SignError::UnexpectedResponse {
+                    expected: format!("Some(ReportState({:?}, _))", self.address),
+                    actual: format!("{:?}", response),
+                })
+            }
+        };
+
+        match state {
+            _ if can_receive_pages(state) => Ok(state),
+
+            State::Unconfigured | State::ConfigFailed => {
+                self.send_config()?;
+                Ok(State::ConfigReceived)
+            }
+
+            _ => {
+                self.configure()?;
+                Ok(State::ConfigReceived)
+            }
+        }
+    }
+
     /// Blanks the display and shuts the sign down.
     ///
     /// The sign will not be usable for 30 seconds after calling this method.
@@ -523,9 +1394,53 @@ impl Sign {
     /// # Ok(()) }
     /// ```
     pub fn shut_down(&self) -> Result<(), SignError> {
+        self.sent_pages.borrow_mut().clear();
         self.send_message_expect_response(Message::Goodbye(self.address), &None)
     }
 
+    /// Blanks the display and shuts the sign down, then blocks until it is usable again.
+    ///
+    /// Equivalent to [`shut_down`](Self::shut_down) followed by sleeping for the
+    /// [`shutdown_lockout`](SignConfig::shutdown_lockout) period (30 seconds by default).
+    /// This avoids confusing errors when reconfiguring the sign immediately afterward.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignError::Bus`] if the underlying bus failed to process a message.
+    /// * [`SignError::UnexpectedResponse`] if the sign did not send the expected response according
+    ///   to the protocol. In this case it is recommended to re-[`configure`](Self::configure) the sign and start over.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, PageId, Sign, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// #
+    /// # // Placeholder bus for expository purposes
+    /// # fn get_bus<'a>() -> Rc<RefCell<VirtualSignBus<'a>>> {
+    /// #     Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])))
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let bus = get_bus();
+    /// let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    /// sign.configure()?;
+    ///
+    /// sign.shut_down_and_wait()?;
+    /// // Sign is now blanked and can safely be reconfigured.
+    /// sign.configure()?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn shut_down_and_wait(&self) -> Result<(), SignError> {
+        self.shut_down()?;
+        thread::sleep(self.config.shutdown_lockout());
+        Ok(())
+    }
+
     /// Borrows the bus mutably and sends a message.
     ///
     /// Enforces that only leaf calls borrow the bus to avoid runtime errors,
@@ -551,7 +1466,9 @@ impl Sign {
     ///
     /// If it already is, nothing to do. Otherwise start or finish a reset as appropriate.
     /// This ensures that the sign is in a known good state before we begin configuring it.
+    /// Any pages remembered for [`update_page`](Self::update_page) are forgotten, since a reset clears them.
     fn ensure_unconfigured(&self) -> Result<(), SignError> {
+        self.sent_pages.borrow_mut().clear();
         let response = self.send_message(Message::Hello(self.address))?;
         match response {
             Some(Message::ReportState(address, State::Unconfigured)) if address == self.address => {}
@@ -559,78 +1476,97 @@ impl Sign {
             Some(Message::ReportState(address, State::ReadyToReset)) if address == self.address => {
                 self.send_message_expect_response(
                     Message::RequestOperation(self.address, Operation::FinishReset),
-                    &Some(Message::AckOperation(self.address, Operation::FinishReset)),
+                    &Some(Operation::FinishReset.expected_ack(self.address)),
                 )?;
 
                 self.send_message_expect_response(
                     Message::Hello(self.address),
-                    &Some(Message::ReportState(self.address, State::Unconfigured)),
+                    &Some(State::Unconfigured.expected_report(self.address)),
                 )?;
             }
 
             _ => {
                 self.send_message_expect_response(
                     Message::RequestOperation(self.address, Operation::StartReset),
-                    &Some(Message::AckOperation(self.address, Operation::StartReset)),
+                    &Some(Operation::StartReset.expected_ack(self.address)),
                 )?;
 
                 self.send_message_expect_response(
                     Message::Hello(self.address),
-                    &Some(Message::ReportState(self.address, State::ReadyToReset)),
+                    &Some(State::ReadyToReset.expected_report(self.address)),
                 )?;
 
                 self.send_message_expect_response(
                     Message::RequestOperation(self.address, Operation::FinishReset),
-                    &Some(Message::AckOperation(self.address, Operation::FinishReset)),
+                    &Some(Operation::FinishReset.expected_ack(self.address)),
                 )?;
 
                 self.send_message_expect_response(
                     Message::Hello(self.address),
-                    &Some(Message::ReportState(self.address, State::Unconfigured)),
+                    &Some(State::Unconfigured.expected_report(self.address)),
                 )?;
             }
         };
         Ok(())
     }
 
+    /// Sends the configuration data for our sign type.
+    ///
+    /// The sign must be in the `Unconfigured` or `ConfigFailed` state, so it has no pages to remember.
+    fn send_config(&self) -> Result<(), SignError> {
+        self.sent_pages.borrow_mut().clear();
+        let config = self.sign_type.to_bytes();
+        self.send_data(
+            &[config],
+            Operation::ReceiveConfig,
+            State::ConfigInProgress,
+            State::ConfigReceived,
+            State::ConfigFailed,
+        )
+    }
+
     /// Sends a chunk of data and verifies proper receipt with retries.
     ///
     /// Requests `operation` from the sign and fails if it does not acknowledge.
-    /// Sends `data` in 16-byte chunks, then queries the sign's state.
-    /// If `success`, we're done. If `failure`, repeat the process a fixed number
-    /// of times in case the data was corrupted in transit. Fails after exhausting
-    /// the retries or if any other state is reported.
-    fn send_data<'a, I>(&self, data: &I, operation: Operation, success: State, failure: State) -> Result<(), SignError>
-    where
-        I: Iterator<Item = &'a [u8]> + Clone,
-    {
+    /// Sends `data` in chunks of the configured size, then queries the sign's state,
+    /// continuing to poll while it reports `in_progress`. If `success`, we're done.
+    /// If `failure`, repeat the process a fixed number of times in case the data was
+    /// corrupted in transit. Fails after exhausting the retries or the operation timeout,
+    /// or if any other state is reported.
+    fn send_data(
+        &self,
+        data: &[&[u8]],
+        operation: Operation,
+        in_progress: State,
+        success: State,
+        failure: State,
+    ) -> Result<(), SignError> {
         const MAX_ATTEMPTS: u32 = 3;
+        let start = Instant::now();
         let mut attempts = 1;
+        self.last_retry_count.set(0);
         loop {
             self.send_message_expect_response(
                 Message::RequestOperation(self.address, operation),
-                &Some(Message::AckOperation(self.address, operation)),
+                &Some(operation.expected_ack(self.address)),
             )?;
 
-            let mut chunks_sent = 0;
-            for item in data.clone() {
-                for (i, chunk) in item.chunks(16).enumerate() {
-                    // Safe to unwrap the Data creation as the chunk will obviously always be less than 255 bytes.
-                    self.send_message_expect_response(
-                        Message::SendData(Offset((i * 16) as u16), Data::try_new(chunk).unwrap()),
-                        &None,
-                    )?;
-                    chunks_sent += 1;
-                }
+            for message in Message::data_transfer(data, self.config.chunk_size()) {
+                self.send_message_expect_response(message, &None)?;
             }
 
-            self.send_message_expect_response(Message::DataChunksSent(ChunkCount(chunks_sent)), &None)?;
+            let mut response = self.send_message(Message::QueryState(self.address))?;
+            while response == Some(in_progress.expected_report(self.address)) {
+                self.check_timeout(start)?;
+                response = self.send_message(Message::QueryState(self.address))?;
+            }
 
-            let response = self.send_message(Message::QueryState(self.address))?;
-            if response == Some(Message::ReportState(self.address, failure)) && attempts < MAX_ATTEMPTS {
+            if response == Some(failure.expected_report(self.address)) && attempts < MAX_ATTEMPTS {
+                self.check_timeout(start)?;
                 attempts += 1;
+                self.last_retry_count.set(attempts - 1);
             } else {
-                verify_response(&Some(Message::ReportState(self.address, success)), &response)?;
+                verify_response(&Some(success.expected_report(self.address)), &response)?;
                 break;
             }
         }
@@ -642,8 +1578,9 @@ impl Sign {
     ///
     /// Queries the sign's current state. If `target`, we're done. If `trigger`, request `operation`.
     /// Continue looping while the state is `PageLoadInProgress` or `PageShowInProgress`, waiting
-    /// to enter `target`. Fails if any other state is reported.
+    /// to enter `target`. Fails if any other state is reported or the operation timeout elapses.
     fn switch_page(&self, target: State, trigger: State, operation: Operation) -> Result<(), SignError> {
+        let start = Instant::now();
         loop {
             let response = self.send_message(Message::QueryState(self.address))?;
             match response {
@@ -659,7 +1596,7 @@ impl Sign {
                 Some(Message::ReportState(address, state)) if address == self.address && state == trigger => {
                     self.send_message_expect_response(
                         Message::RequestOperation(self.address, operation),
-                        &Some(Message::AckOperation(self.address, operation)),
+                        &Some(operation.expected_ack(self.address)),
                     )?;
                 }
 
@@ -674,6 +1611,17 @@ impl Sign {
                     })
                 }
             };
+
+            self.check_timeout(start)?;
+        }
+        Ok(())
+    }
+
+    /// Fails with [`SignError::Timeout`] if the operation that began at `start` has exceeded the configured timeout.
+    fn check_timeout(&self, start: Instant) -> Result<(), SignError> {
+        let timeout = self.config.operation_timeout();
+        if start.elapsed() >= timeout {
+            return Err(SignError::Timeout { timeout });
         }
         Ok(())
     }
@@ -690,3 +1638,35 @@ fn verify_response(expected: &Option<Message<'_>>, response: &Option<Message<'_>
         })
     }
 }
+
+/// Returns whether a sign in `state` has been configured and is ready to receive pages.
+fn can_receive_pages(state: State) -> bool {
+    matches!(
+        state,
+        State::ConfigReceived
+            | State::ShowingPages
+            | State::PageLoaded
+            | State::PageShowInProgress
+            | State::PageShown
+            | State::PageLoadInProgress
+    )
+}
+
+/// Sleeps for `duration` in short increments, returning `false` early if `stop` is set.
+///
+/// Returns `true` if the full duration elapsed without `stop` being set.
+fn sleep_unless_stopped(duration: Duration, stop: &AtomicBool) -> bool {
+    const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+    let start = Instant::now();
+    loop {
+        if stop.load(Ordering::Relaxed) {
+            return false;
+        }
+        let remaining = duration.saturating_sub(start.elapsed());
+        if remaining.is_zero() {
+            return true;
+        }
+        thread::sleep(remaining.min(POLL_INTERVAL));
+    }
+}
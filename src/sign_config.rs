@@ -0,0 +1,153 @@
+use std::time::Duration;
+
+use crate::core::Message;
+
+/// Tunable parameters that control how a [`Sign`](crate::Sign) communicates.
+///
+/// The defaults match the behavior observed from real ODKs and should work for most signs;
+/// they can be adjusted with the `with_*` methods and passed to [`Sign::with_config`](crate::Sign::with_config).
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use flipdot::SignConfig;
+///
+/// let config = SignConfig::default().with_shutdown_lockout(Duration::from_secs(45));
+/// assert_eq!(Duration::from_secs(45), config.shutdown_lockout());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SignConfig {
+    shutdown_lockout: Duration,
+    operation_timeout: Duration,
+    chunk_size: u8,
+}
+
+impl SignConfig {
+    /// Creates a new `SignConfig` with the default settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::SignConfig;
+    /// let config = SignConfig::new();
+    /// assert_eq!(SignConfig::default(), config);
+    /// ```
+    pub fn new() -> Self {
+        SignConfig {
+            shutdown_lockout: Duration::from_secs(30),
+            operation_timeout: Duration::from_secs(60),
+            chunk_size: Message::DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Returns how long a sign is unusable after being shut down.
+    ///
+    /// Defaults to 30 seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use flipdot::SignConfig;
+    /// let config = SignConfig::new();
+    /// assert_eq!(Duration::from_secs(30), config.shutdown_lockout());
+    /// ```
+    pub fn shutdown_lockout(&self) -> Duration {
+        self.shutdown_lockout
+    }
+
+    /// Sets how long [`Sign::shut_down_and_wait`](crate::Sign::shut_down_and_wait) waits
+    /// after shutting down a sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use flipdot::SignConfig;
+    /// let config = SignConfig::new().with_shutdown_lockout(Duration::from_secs(35));
+    /// assert_eq!(Duration::from_secs(35), config.shutdown_lockout());
+    /// ```
+    pub fn with_shutdown_lockout(mut self, lockout: Duration) -> Self {
+        self.shutdown_lockout = lockout;
+        self
+    }
+
+    /// Returns the maximum time a single operation (such as sending data or flipping a page)
+    /// may take before giving up with [`SignError::Timeout`](crate::SignError::Timeout).
+    ///
+    /// Defaults to 60 seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use flipdot::SignConfig;
+    /// let config = SignConfig::new();
+    /// assert_eq!(Duration::from_secs(60), config.operation_timeout());
+    /// ```
+    pub fn operation_timeout(&self) -> Duration {
+        self.operation_timeout
+    }
+
+    /// Sets the maximum time a single operation may take.
+    ///
+    /// This bounds the overall time spent retrying or waiting for a sign to finish,
+    /// in addition to any per-message timeout imposed by the bus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use flipdot::SignConfig;
+    /// let config = SignConfig::new().with_operation_timeout(Duration::from_secs(10));
+    /// assert_eq!(Duration::from_secs(10), config.operation_timeout());
+    /// ```
+    pub fn with_operation_timeout(mut self, timeout: Duration) -> Self {
+        self.operation_timeout = timeout;
+        self
+    }
+
+    /// Returns the maximum number of bytes sent in each [`SendData`](crate::core::Message::SendData) message.
+    ///
+    /// Defaults to 16, which matches the behavior of real ODKs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::SignConfig;
+    /// let config = SignConfig::new();
+    /// assert_eq!(16, config.chunk_size());
+    /// ```
+    pub fn chunk_size(&self) -> u8 {
+        self.chunk_size
+    }
+
+    /// Sets the maximum number of bytes sent in each [`SendData`](crate::core::Message::SendData) message.
+    ///
+    /// Useful for reproducing a controller that used a different chunk size or experimenting with throughput.
+    /// The size is limited to 255 bytes, the most a single frame can hold.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::SignConfig;
+    /// let config = SignConfig::new().with_chunk_size(32);
+    /// assert_eq!(32, config.chunk_size());
+    /// ```
+    pub fn with_chunk_size(mut self, chunk_size: u8) -> Self {
+        assert!(chunk_size > 0, "Chunk size must be at least 1 byte");
+        self.chunk_size = chunk_size;
+        self
+    }
+}
+
+impl Default for SignConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,27 @@
+use crate::core::{Address, SignType, State};
+
+/// Identifying information about a sign, as returned by [`Sign::identify`](crate::Sign::identify).
+///
+/// No protocol message is known for querying firmware or version information, so apart from
+/// the state, everything here is derived from the [`SignType`] the sign was created with.
+/// The configuration bytes are included to help catalog sign variants.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SignInfo {
+    /// The sign's address.
+    pub address: Address,
+
+    /// The sign's type.
+    pub sign_type: SignType,
+
+    /// The width of the sign's display in pixels.
+    pub width: u32,
+
+    /// The height of the sign's display in pixels.
+    pub height: u32,
+
+    /// The 16-byte configuration data sent to the sign.
+    pub config: &'static [u8],
+
+    /// The state the sign reported when queried.
+    pub state: State,
+}
@@ -0,0 +1,155 @@
+use std::io::{Read, Write};
+
+use thiserror::Error;
+
+use crate::core::{formats, FormatError, Page, PageError, SignType, SignTypeError};
+
+/// Errors related to reading and writing [`SignMessage`]s.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum SignMessageError {
+    /// Failed reading/writing the message data.
+    #[error("Failed reading/writing sign message data")]
+    Io {
+        /// The underlying I/O error.
+        #[from]
+        source: std::io::Error,
+    },
+
+    /// The stored sign configuration was invalid.
+    #[error("Invalid sign configuration in sign message")]
+    SignType {
+        /// The underlying sign type error.
+        #[from]
+        source: SignTypeError,
+    },
+
+    /// The stored pages were invalid or did not match the sign type.
+    #[error("Invalid pages in sign message")]
+    Format {
+        /// The underlying format error.
+        #[from]
+        source: FormatError,
+    },
+}
+
+/// A complete message for a particular type of sign, suitable for saving to a file and sending later.
+///
+/// # Examples
+///
+/// ```
+/// use flipdot::{Page, PageId, SignMessage, SignType};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// #
+/// let mut page = Page::new(PageId(1), 90, 7);
+/// page.set_pixel(0, 0, true);
+/// let message = SignMessage {
+///     sign_type: SignType::Max3000Side90x7,
+///     pages: vec![page],
+/// };
+///
+/// let mut file = Vec::new();
+/// message.save(&mut file)?;
+/// assert_eq!(message, SignMessage::load(&mut &file[..])?);
+/// #
+/// # Ok(()) }
+/// ```
+///
+/// # Format Details
+///
+/// The file consists of the 16-byte configuration data for the sign type (as returned by [`SignType::to_bytes`]),
+/// followed by the pages in the `.flipdot` container format described in [`flipdot_core::formats`](crate::core::formats).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SignMessage {
+    /// The type of sign the message is intended for.
+    pub sign_type: SignType,
+
+    /// The pages of the message, which must match the dimensions of the sign type.
+    pub pages: Vec<Page<'static>>,
+}
+
+impl SignMessage {
+    /// Writes the message to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignMessageError::Io`] if the data could not be written.
+    /// * [`SignMessageError::Format`] if any page does not match the dimensions of the sign type.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use flipdot::{Page, PageId, SignMessage, SignType};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let sign_type = SignType::Max3000Side90x7;
+    /// let (width, height) = sign_type.dimensions();
+    /// let message = SignMessage {
+    ///     sign_type,
+    ///     pages: vec![Page::new(PageId(1), width, height)],
+    /// };
+    /// message.save(&mut File::create("message.bin")?)?;
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn save<W: Write>(&self, writer: &mut W) -> Result<(), SignMessageError> {
+        self.check_dimensions()?;
+        writer.write_all(self.sign_type.to_bytes())?;
+        formats::write_flipdot(writer, &self.pages)?;
+        Ok(())
+    }
+
+    /// Reads a message previously written by [`save`](Self::save) from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// * [`SignMessageError::Io`] if the data could not be read.
+    /// * [`SignMessageError::SignType`] if the sign type is not recognized.
+    /// * [`SignMessageError::Format`] if the pages are invalid or do not match the dimensions of the sign type.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use flipdot::SignMessage;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let message = SignMessage::load(&mut File::open("message.bin")?)?;
+    /// println!("Loaded {} pages for {}", message.pages.len(), message.sign_type.name());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn load<R: Read>(reader: &mut R) -> Result<Self, SignMessageError> {
+        let mut config = [0; 16];
+        reader.read_exact(&mut config)?;
+        let sign_type = SignType::from_bytes(&config)?;
+        let pages = formats::read_flipdot(reader)?;
+
+        let message = SignMessage { sign_type, pages };
+        message.check_dimensions()?;
+        Ok(message)
+    }
+
+    /// Verifies that all pages have the same dimensions as the sign type.
+    fn check_dimensions(&self) -> Result<(), FormatError> {
+        let (width, height) = self.sign_type.dimensions();
+        match self
+            .pages
+            .iter()
+            .find(|page| (page.width(), page.height()) != (width, height))
+        {
+            Some(page) => Err(PageError::DimensionMismatch {
+                expected_width: width,
+                expected_height: height,
+                actual_width: page.width(),
+                actual_height: page.height(),
+            }
+            .into()),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use thiserror::Error;
+
+use crate::core::{Address, SignBus, SignType};
+use crate::{Sign, SignConfig};
+
+/// Errors related to [`SignRegistry`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum SignRegistryError {
+    /// No sign was registered with the requested name.
+    #[error("No sign registered with name {:?}", name)]
+    UnknownName {
+        /// The requested name.
+        name: String,
+    },
+}
+
+/// A mapping from human-friendly names to the address and type of each sign in an installation.
+///
+/// Lets application code refer to signs as e.g. `"front"` or `"side"` rather than by numeric address,
+/// and creates [`Sign`]s by name on a shared bus.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use flipdot::{Address, PageFlipStyle, SignRegistry, SignType};
+/// use flipdot_testing::VirtualSignBus;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// #
+/// let registry = SignRegistry::new()
+///     .with_sign("front", Address(1), SignType::Max3000Front112x16)
+///     .with_sign("side", Address(3), SignType::Max3000Side90x7);
+///
+/// let bus = VirtualSignBus::with_addresses([Address(1), Address(3)], PageFlipStyle::Manual);
+/// let bus = Rc::new(RefCell::new(bus));
+///
+/// let side = registry.sign("side", bus.clone())?;
+/// assert_eq!(Address(3), side.address());
+/// side.configure()?;
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct SignRegistry {
+    signs: BTreeMap<String, (Address, SignType)>,
+}
+
+impl SignRegistry {
+    /// Creates a new empty `SignRegistry`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::SignRegistry;
+    /// let registry = SignRegistry::new();
+    /// assert!(registry.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers a sign under `name`, replacing any sign previously registered with that name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::{Address, SignRegistry, SignType};
+    /// let registry = SignRegistry::new().with_sign("side", Address(3), SignType::Max3000Side90x7);
+    /// assert_eq!(Some((Address(3), SignType::Max3000Side90x7)), registry.get("side"));
+    /// ```
+    pub fn with_sign<S: Into<String>>(mut self, name: S, address: Address, sign_type: SignType) -> Self {
+        let _ = self.insert(name, address, sign_type);
+        self
+    }
+
+    /// Registers a sign under `name`, returning the previously registered address and type, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::{Address, SignRegistry, SignType};
+    /// let mut registry = SignRegistry::new();
+    /// assert_eq!(None, registry.insert("side", Address(3), SignType::Max3000Side90x7));
+    /// assert_eq!(
+    ///     Some((Address(3), SignType::Max3000Side90x7)),
+    ///     registry.insert("side", Address(4), SignType::Max3000Side90x7)
+    /// );
+    /// ```
+    pub fn insert<S: Into<String>>(&mut self, name: S, address: Address, sign_type: SignType) -> Option<(Address, SignType)> {
+        self.signs.insert(name.into(), (address, sign_type))
+    }
+
+    /// Removes the sign registered under `name`, returning its address and type if it was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::{Address, SignRegistry, SignType};
+    /// let mut registry = SignRegistry::new().with_sign("side", Address(3), SignType::Max3000Side90x7);
+    /// assert!(registry.remove("side").is_some());
+    /// assert!(registry.is_empty());
+    /// ```
+    pub fn remove(&mut self, name: &str) -> Option<(Address, SignType)> {
+        self.signs.remove(name)
+    }
+
+    /// Returns the address and type of the sign registered under `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::{Address, SignRegistry, SignType};
+    /// let registry = SignRegistry::new().with_sign("side", Address(3), SignType::Max3000Side90x7);
+    /// assert_eq!(Some((Address(3), SignType::Max3000Side90x7)), registry.get("side"));
+    /// assert_eq!(None, registry.get("rear"));
+    /// ```
+    pub fn get(&self, name: &str) -> Option<(Address, SignType)> {
+        self.signs.get(name).copied()
+    }
+
+    /// Returns the name of the sign registered with the given address, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::{Address, SignRegistry, SignType};
+    /// let registry = SignRegistry::new().with_sign("side", Address(3), SignType::Max3000Side90x7);
+    /// assert_eq!(Some("side"), registry.name_of(Address(3)));
+    /// assert_eq!(None, registry.name_of(Address(4)));
+    /// ```
+    pub fn name_of(&self, address: Address) -> Option<&str> {
+        self.signs
+            .iter()
+            .find(|(_, &(sign_address, _))| sign_address == address)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Returns an iterator over the registered names and their addresses and types, sorted by name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::{Address, SignRegistry, SignType};
+    /// let registry = SignRegistry::new()
+    ///     .with_sign("side", Address(3), SignType::Max3000Side90x7)
+    ///     .with_sign("front", Address(1), SignType::Max3000Front112x16);
+    /// let names: Vec<&str> = registry.iter().map(|(name, _, _)| name).collect();
+    /// assert_eq!(vec!["front", "side"], names);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Address, SignType)> {
+        self.signs
+            .iter()
+            .map(|(name, &(address, sign_type))| (name.as_str(), address, sign_type))
+    }
+
+    /// Returns the number of registered signs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::{Address, SignRegistry, SignType};
+    /// let registry = SignRegistry::new().with_sign("side", Address(3), SignType::Max3000Side90x7);
+    /// assert_eq!(1, registry.len());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.signs.len()
+    }
+
+    /// Returns `true` if no signs are registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use flipdot::SignRegistry;
+    /// assert!(SignRegistry::new().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.signs.is_empty()
+    }
+
+    /// Creates a [`Sign`] on `bus` for the sign registered under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SignRegistryError::UnknownName`] if no sign is registered under `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, SignRegistry, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let registry = SignRegistry::new().with_sign("side", Address(3), SignType::Max3000Side90x7);
+    /// let bus = Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])));
+    ///
+    /// let sign = registry.sign("side", bus.clone())?;
+    /// assert_eq!(SignType::Max3000Side90x7, sign.sign_type());
+    /// assert!(registry.sign("rear", bus.clone()).is_err());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn sign(&self, name: &str, bus: Rc<RefCell<dyn SignBus>>) -> Result<Sign, SignRegistryError> {
+        self.sign_with_config(name, bus, SignConfig::default())
+    }
+
+    /// Creates a [`Sign`] on `bus` with custom [`SignConfig`] for the sign registered under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SignRegistryError::UnknownName`] if no sign is registered under `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use std::time::Duration;
+    /// # use flipdot::{Address, PageFlipStyle, SignConfig, SignRegistry, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let registry = SignRegistry::new().with_sign("side", Address(3), SignType::Max3000Side90x7);
+    /// let bus = Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)])));
+    ///
+    /// let config = SignConfig::new().with_operation_timeout(Duration::from_secs(10));
+    /// let sign = registry.sign_with_config("side", bus.clone(), config)?;
+    /// assert_eq!(&config, sign.config());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn sign_with_config(
+        &self,
+        name: &str,
+        bus: Rc<RefCell<dyn SignBus>>,
+        config: SignConfig,
+    ) -> Result<Sign, SignRegistryError> {
+        let (address, sign_type) = self
+            .get(name)
+            .ok_or_else(|| SignRegistryError::UnknownName { name: name.into() })?;
+        Ok(Sign::with_config(bus, address, sign_type, config))
+    }
+
+    /// Creates a [`Sign`] on `bus` for every registered sign, paired with its name and sorted by name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{Address, PageFlipStyle, SignRegistry, SignType};
+    /// # use flipdot_testing::VirtualSignBus;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #
+    /// let registry = SignRegistry::new()
+    ///     .with_sign("front", Address(1), SignType::Max3000Front112x16)
+    ///     .with_sign("side", Address(3), SignType::Max3000Side90x7);
+    /// let bus = VirtualSignBus::with_addresses([Address(1), Address(3)], PageFlipStyle::Manual);
+    /// let bus = Rc::new(RefCell::new(bus));
+    ///
+    /// for (name, sign) in registry.signs(bus.clone()) {
+    ///     println!("Configuring {}", name);
+    ///     sign.configure()?;
+    /// }
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn signs(&self, bus: Rc<RefCell<dyn SignBus>>) -> Vec<(&str, Sign)> {
+        self.iter()
+            .map(|(name, address, sign_type)| (name, Sign::new(bus.clone(), address, sign_type)))
+            .collect()
+    }
+}
@@ -0,0 +1,190 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::mem;
+use std::rc::Rc;
+
+use crate::core::{Address, Message, Page, PageFlipStyle, PageId, SignBus, SignType, State};
+use crate::{Sign, SignError};
+
+/// The lifecycle steps run by [`smoke_test`], in order.
+const STEPS: [SmokeStep; 6] = [
+    SmokeStep::Configure,
+    SmokeStep::SendPages,
+    SmokeStep::ShowPage,
+    SmokeStep::LoadNextPage,
+    SmokeStep::ShowNextPage,
+    SmokeStep::ShutDown,
+];
+
+/// A step of the sign lifecycle exercised by [`smoke_test`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SmokeStep {
+    /// Resetting the sign if necessary and sending its configuration.
+    Configure,
+
+    /// Sending two pages of pixel data.
+    SendPages,
+
+    /// Showing the first page.
+    ShowPage,
+
+    /// Loading the second page.
+    LoadNextPage,
+
+    /// Showing the second page.
+    ShowNextPage,
+
+    /// Blanking the display and shutting the sign down.
+    ShutDown,
+}
+
+/// Why a [`smoke_test`] step failed.
+#[derive(Debug)]
+pub struct SmokeFailure {
+    /// The step that failed.
+    pub step: SmokeStep,
+
+    /// The error returned by [`Sign`] for that step.
+    pub reason: SignError,
+}
+
+/// The results of a [`smoke_test`] run.
+#[derive(Debug)]
+pub struct SmokeReport {
+    /// The steps that completed successfully, in order.
+    pub completed: Vec<SmokeStep>,
+
+    /// Every state the sign reported during the run, in order.
+    pub states: Vec<State>,
+
+    /// How the sign flips pages, if the pages were sent successfully.
+    ///
+    /// Signs that flip pages automatically skip the show and load steps.
+    pub flip_style: Option<PageFlipStyle>,
+
+    /// The step that failed, if any. No further steps are attempted after a failure.
+    pub failure: Option<SmokeFailure>,
+}
+
+impl SmokeReport {
+    /// Returns `true` if every step completed successfully.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// # use std::rc::Rc;
+    /// # use flipdot::{smoke_test, Address, PageFlipStyle, SignType};
+    /// # use flipdot_testing::{VirtualSign, VirtualSignBus};
+    /// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    /// let bus = Rc::new(RefCell::new(bus));
+    /// assert!(smoke_test(bus.clone(), Address(3), SignType::Max3000Side90x7).passed());
+    /// assert!(!smoke_test(bus.clone(), Address(4), SignType::Max3000Side90x7).passed());
+    /// ```
+    pub fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Drives the sign at `address` through a full lifecycle using a [`Sign`] and reports what happened.
+///
+/// The sign is configured as `sign_type`, sent two pages, shown the first page, loaded and shown the
+/// second page, and finally shut down. This makes it easy to check that a [`SignBus`] implementation
+/// behaves correctly end-to-end. Failed transfers are retried and waits are bounded by the default
+/// [`operation_timeout`](crate::SignConfig::operation_timeout), exactly as they would be for any other `Sign`.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use flipdot::core::State;
+/// use flipdot::{smoke_test, Address, PageFlipStyle, SignType, SmokeStep};
+/// use flipdot_testing::{VirtualSign, VirtualSignBus};
+///
+/// let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+/// let bus = Rc::new(RefCell::new(bus));
+/// let report = smoke_test(bus.clone(), Address(3), SignType::Max3000Side90x7);
+///
+/// assert!(report.passed(), "{:?}", report.failure);
+/// assert_eq!(Some(&SmokeStep::ShutDown), report.completed.last());
+/// assert!(report.states.contains(&State::PageShown));
+/// ```
+pub fn smoke_test(bus: Rc<RefCell<dyn SignBus>>, address: Address, sign_type: SignType) -> SmokeReport {
+    let recorder = Rc::new(RefCell::new(StateRecorder {
+        bus,
+        address,
+        states: Vec::new(),
+    }));
+    let sign = Sign::new(recorder.clone(), address, sign_type);
+    let mut completed = Vec::new();
+    let mut flip_style = None;
+    let pages = test_pages(sign_type);
+
+    let mut failure = None;
+    for step in STEPS {
+        let flips_automatically = flip_style == Some(PageFlipStyle::Automatic);
+        if flips_automatically && matches!(step, SmokeStep::ShowPage | SmokeStep::LoadNextPage | SmokeStep::ShowNextPage) {
+            continue;
+        }
+
+        let result = match step {
+            SmokeStep::Configure => sign.configure(),
+            SmokeStep::SendPages => sign.send_pages(&pages).map(|style| flip_style = Some(style)),
+            SmokeStep::ShowPage | SmokeStep::ShowNextPage => sign.show_loaded_page(),
+            SmokeStep::LoadNextPage => sign.load_next_page(),
+            SmokeStep::ShutDown => sign.shut_down(),
+        };
+
+        if let Err(reason) = result {
+            failure = Some(SmokeFailure { step, reason });
+            break;
+        }
+        completed.push(step);
+    }
+
+    let states = mem::take(&mut recorder.borrow_mut().states);
+    SmokeReport {
+        completed,
+        states,
+        flip_style,
+        failure,
+    }
+}
+
+/// Creates two distinguishable pages sized for `sign_type`.
+pub(crate) fn test_pages(sign_type: SignType) -> [Page<'static>; 2] {
+    let (width, height) = sign_type.dimensions();
+    let mut pages = [Page::new(PageId(0), width, height), Page::new(PageId(1), width, height)];
+    pages[0].set_pixel(0, 0, true);
+    pages[1].set_pixel(width - 1, height - 1, true);
+    pages
+}
+
+/// Forwards messages to another bus, recording every state reported by the sign at `address`.
+#[derive(Debug)]
+struct StateRecorder {
+    bus: Rc<RefCell<dyn SignBus>>,
+    address: Address,
+    states: Vec<State>,
+}
+
+impl SignBus for StateRecorder {
+    fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
+        let response = self.bus.borrow_mut().process_message(message)?;
+        if let Some(Message::ReportState(address, state)) = response {
+            if address == self.address {
+                self.states.push(state);
+            }
+        }
+        Ok(response)
+    }
+
+    fn recognized_sign_type(&self, address: Address) -> Option<SignType> {
+        self.bus.borrow().recognized_sign_type(address)
+    }
+
+    fn is_physical(&self) -> Option<bool> {
+        self.bus.borrow().is_physical()
+    }
+}
@@ -0,0 +1,81 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::rc::Rc;
+
+use flipdot::conformance::{self, Sequence};
+use flipdot::core::{Message, State};
+use flipdot::{Address, PageFlipStyle, SignBus, SignError, SignType};
+use flipdot_testing::{VirtualSign, VirtualSignBus};
+
+/// Wraps a `VirtualSignBus`, but incorrectly responds to `Goodbye`.
+#[derive(Debug)]
+struct ChattyGoodbyeBus<'a>(VirtualSignBus<'a>);
+
+impl SignBus for ChattyGoodbyeBus<'_> {
+    fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
+        match message {
+            Message::Goodbye(address) => Ok(Some(Message::ReportState(address, State::Unconfigured))),
+            message => self.0.process_message(message),
+        }
+    }
+}
+
+#[test]
+fn manual_sign_conforms() {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let report = conformance::check(Rc::new(RefCell::new(bus)), Address(3), SignType::Max3000Front98x16);
+
+    assert!(report.is_conformant(), "{:?}", report.deviations);
+    assert_eq!(
+        vec![
+            Sequence::Configure,
+            Sequence::SendPages,
+            Sequence::FlipPages,
+            Sequence::Reset,
+            Sequence::ShutDown,
+        ],
+        report.passed
+    );
+    assert_eq!(Some(PageFlipStyle::Manual), report.flip_style);
+}
+
+#[test]
+fn automatic_sign_conforms() {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Automatic)]);
+    let report = conformance::check(Rc::new(RefCell::new(bus)), Address(3), SignType::HorizonDash40x12);
+
+    assert!(report.is_conformant(), "{:?}", report.deviations);
+    assert_eq!(Some(PageFlipStyle::Automatic), report.flip_style);
+}
+
+#[test]
+fn missing_sign_skips_dependent_sequences() {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let report = conformance::check(Rc::new(RefCell::new(bus)), Address(4), SignType::Max3000Side90x7);
+
+    assert!(!report.is_conformant());
+    assert_eq!(vec![Sequence::ShutDown], report.passed);
+    assert_eq!(
+        vec![Sequence::SendPages, Sequence::FlipPages, Sequence::Reset],
+        report.skipped
+    );
+    assert_eq!(1, report.deviations.len());
+    assert_eq!(Sequence::Configure, report.deviations[0].sequence);
+    assert!(matches!(report.deviations[0].error, SignError::UnexpectedResponse { .. }));
+}
+
+#[test]
+fn unexpected_response_is_reported() {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = ChattyGoodbyeBus(bus);
+    let report = conformance::check(Rc::new(RefCell::new(bus)), Address(3), SignType::Max3000Side90x7);
+
+    assert_eq!(
+        vec![Sequence::Configure, Sequence::SendPages, Sequence::FlipPages, Sequence::Reset],
+        report.passed
+    );
+    assert!(report.skipped.is_empty());
+    assert_eq!(1, report.deviations.len());
+    assert_eq!(Sequence::ShutDown, report.deviations[0].sequence);
+    assert!(matches!(report.deviations[0].error, SignError::UnexpectedResponse { .. }));
+}
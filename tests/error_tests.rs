@@ -4,6 +4,7 @@ use std::fmt::Debug;
 use std::io::{self, Read};
 use std::iter;
 use std::rc::Rc;
+use std::time::Duration;
 
 use flipdot::core::*;
 use flipdot::serial::*;
@@ -18,7 +19,7 @@ struct ErrorReader {}
 
 impl Read for ErrorReader {
     fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
-        Err(io::Error::new(io::ErrorKind::Other, "Dummy read error"))
+        Err(io::Error::other("Dummy read error"))
     }
 }
 
@@ -42,7 +43,7 @@ impl ErrorSignBus {
 impl SignBus for ErrorSignBus {
     fn process_message<'a>(&mut self, _: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
         match self.failure {
-            BusFailure::Error => Err(format!("Dummy sign bus error"))?,
+            BusFailure::Error => Err("Dummy sign bus error")?,
             BusFailure::WrongMessage => Ok(Some(Message::Goodbye(Address(0)))),
         }
     }
@@ -57,8 +58,31 @@ fn format_errors() -> Result<(), Box<dyn Error>> {
     print_error("Wrong frame data size", Frame::from_bytes(b":01007F027E"));
     print_error("Wrong frame checksum", Frame::from_bytes(b":01007F02FF7E"));
     print_error("Wrong page data length", Page::from_bytes(90, 7, vec![1, 2, 3]));
-    print_error("Wrong config data length", SignType::from_bytes(&vec![1, 2, 3]));
-    print_error("Unknown config", SignType::from_bytes(&vec![0; 16]));
+    print_error(
+        "Page dimension mismatch",
+        Page::new(PageId(1), 90, 7).combine(&Page::new(PageId(1), 30, 7), BlitMode::Or),
+    );
+    print_error("No pages to union", Page::union(&[]));
+    print_error("Page too large", Page::new(PageId(1), 90, 7).embed_centered(PageId(1), 30, 7));
+    print_error("Invalid page sequence", PageId::from_sequence(0));
+    print_error(
+        "Too many pages",
+        Page::scroll_text(PageId(200), 90, 7, "Hello", &Font::ascii_5x7(), 1),
+    );
+    print_error("Bad .flipdot magic", formats::read_flipdot(&mut &b"NOTFLIPDOT"[..]));
+    print_error(
+        "Bad .flipdot dimensions",
+        formats::write_flipdot(&mut Vec::new(), &[Page::new(PageId(1), 300, 7)]),
+    );
+    print_error(
+        "Mismatched .flipdot pages",
+        formats::write_flipdot(&mut Vec::new(), &[Page::new(PageId(1), 90, 7), Page::new(PageId(1), 30, 7)]),
+    );
+    print_error("Wrong config data length", SignType::from_bytes(&[1, 2, 3]));
+    print_error("Unknown config", SignType::from_bytes(&[0; 16]));
+    print_error("Unknown sign name", SignType::from_name("Max3000"));
+    print_error("Unknown sign dimensions", SignType::from_dimensions(1, 1));
+    print_error("Invalid sign spec", SignType::from_spec("Max3000Side90x7"));
 
     // Serial
     print_error(
@@ -67,6 +91,17 @@ fn format_errors() -> Result<(), Box<dyn Error>> {
     );
 
     // Testing
+    print_error(
+        "Duplicate virtual sign addresses",
+        VirtualSignBus::try_new(vec![
+            VirtualSign::new(Address(3), PageFlipStyle::Manual),
+            VirtualSign::new(Address(3), PageFlipStyle::Manual),
+        ]),
+    );
+
+    print_error("Invalid session line", Session::load(&mut &b"? :0100030255A5\n"[..]));
+    print_error("Invalid session frame", Session::load(&mut &b"> :01\n"[..]));
+
     let mut odk = Odk::try_new(
         MockSerialPort::new(vec![], SerialFailure::Read),
         ErrorSignBus::new(BusFailure::Error),
@@ -88,6 +123,32 @@ fn format_errors() -> Result<(), Box<dyn Error>> {
     let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
     print_error("Sign wrong message", sign.configure());
 
+    print_error("Sign type not found", Sign::probe_type(bus.clone(), Address(3)));
+
+    let bus = Rc::new(RefCell::new(VirtualSignBus::new(vec![VirtualSign::new(
+        Address(3),
+        PageFlipStyle::Manual,
+    )])));
+    let config = SignConfig::new().with_operation_timeout(Duration::ZERO);
+    let sign = Sign::with_config(bus.clone(), Address(3), SignType::Max3000Side90x7, config);
+    sign.configure()?;
+    sign.send_pages(&[sign.create_page(PageId(1))])?;
+    print_error("Sign timeout", sign.show_loaded_page());
+    print_error("Sign no pages", sign.send_pages(&[]));
+    print_error("Sign unknown page", sign.update_page(&sign.create_page(PageId(2))));
+    print_error(
+        "Sign bits per column mismatch",
+        sign.send_pages(&[Page::new(PageId(1), 90, 16)]),
+    );
+
+    let message = SignMessage {
+        sign_type: SignType::Max3000Front112x16,
+        pages: vec![],
+    };
+    print_error("Sign type mismatch", sign.send_message_file(&message));
+    print_error("Invalid sign message", SignMessage::load(&mut &[0u8; 16][..]));
+    print_error("Unknown registered sign", SignRegistry::new().sign("front", bus.clone()));
+
     Ok(())
 }
 
@@ -9,6 +9,7 @@ pub enum SerialFailure {
     None,
     WriteSettings,
     Read,
+    TimeoutWhenEmpty,
 }
 
 /// Mock serial port implementation that reads data from a vector
@@ -45,7 +46,10 @@ impl MockSerialPort {
 impl Read for MockSerialPort {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         match self.failure {
-            SerialFailure::Read => Err(io::Error::new(io::ErrorKind::Other, "Dummy I/O error")),
+            SerialFailure::Read => Err(io::Error::other("Dummy I/O error")),
+            SerialFailure::TimeoutWhenEmpty if self.data.position() == self.data.get_ref().len() as u64 => {
+                Err(io::Error::new(io::ErrorKind::TimedOut, "Dummy timeout"))
+            }
             _ => self.data.read(buf),
         }
     }
@@ -0,0 +1,266 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::rc::Rc;
+use std::time::Instant;
+
+use flipdot::core::{ChunkCount, Data, Frame, Message, MsgType, Operation, State};
+use flipdot::{Address, PageFlipStyle, SignBus};
+use flipdot_testing::{Direction, Odk, VirtualSign, VirtualSignBus};
+
+mod mock_serial_port;
+use crate::mock_serial_port::{MockSerialPort, SerialFailure};
+
+fn odk_input(messages: &[Message<'_>]) -> Vec<u8> {
+    messages
+        .iter()
+        .flat_map(|message| Frame::from(message.clone()).to_bytes_with_newline())
+        .collect()
+}
+
+#[test]
+fn filter_transforms_messages() -> Result<(), Box<dyn Error>> {
+    let port = MockSerialPort::new(odk_input(&[Message::Hello(Address(3))]), SerialFailure::None);
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(5), PageFlipStyle::Manual)]);
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let seen_clone = seen.clone();
+    let mut odk = Odk::with_filter(port, bus, move |direction, message| {
+        seen_clone.borrow_mut().push((direction, message.clone()));
+        match message {
+            Message::Hello(Address(3)) => Some(Message::Hello(Address(5))),
+            Message::ReportState(Address(5), state) => Some(Message::ReportState(Address(3), state)),
+            message => Some(message),
+        }
+    })?;
+
+    odk.process_message()?;
+
+    let expected = vec![
+        (Direction::OdkToSign, Message::Hello(Address(3))),
+        (Direction::SignToOdk, Message::ReportState(Address(5), State::Unconfigured)),
+    ];
+    assert_eq!(expected, *seen.borrow());
+
+    Ok(())
+}
+
+#[test]
+fn filter_suppresses_messages() -> Result<(), Box<dyn Error>> {
+    let input = odk_input(&[Message::Hello(Address(3)), Message::Hello(Address(3))]);
+    let port = MockSerialPort::new(input, SerialFailure::None);
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+
+    let responses = Rc::new(RefCell::new(0));
+    let responses_clone = responses.clone();
+    let mut dropped = false;
+    let mut odk = Odk::with_filter(port, bus, move |direction, message| match direction {
+        Direction::OdkToSign if !dropped => {
+            dropped = true;
+            None
+        }
+        Direction::OdkToSign => Some(message),
+        Direction::SignToOdk => {
+            *responses_clone.borrow_mut() += 1;
+            Some(message)
+        }
+    })?;
+
+    // First message is dropped before reaching the bus, so no response is generated.
+    odk.process_message()?;
+    assert_eq!(0, *responses.borrow());
+
+    odk.process_message()?;
+    assert_eq!(1, *responses.borrow());
+
+    Ok(())
+}
+
+/// Bus that forwards to a `VirtualSignBus` and records the frames it receives.
+#[derive(Debug)]
+struct RecordingSignBus {
+    inner: VirtualSignBus<'static>,
+    received: Rc<RefCell<Vec<Vec<u8>>>>,
+}
+
+impl SignBus for RecordingSignBus {
+    fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
+        self.received.borrow_mut().push(Frame::from(message.clone()).to_bytes());
+        self.inner.process_message(message)
+    }
+}
+
+#[test]
+fn address_map_rewrites_addresses() -> Result<(), Box<dyn Error>> {
+    let input = odk_input(&[
+        Message::Hello(Address(3)),
+        Message::RequestOperation(Address(3), Operation::ReceiveConfig),
+        Message::Hello(Address(4)),
+    ]);
+    let port = MockSerialPort::new(input, SerialFailure::None);
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let bus = RecordingSignBus {
+        inner: VirtualSignBus::new(vec![
+            VirtualSign::new(Address(4), PageFlipStyle::Manual),
+            VirtualSign::new(Address(5), PageFlipStyle::Manual),
+        ]),
+        received: received.clone(),
+    };
+
+    let map = HashMap::from([(Address(3), Address(5))]);
+    let mut odk = Odk::with_address_map(port, bus, map)?;
+    odk.process_message()?;
+    odk.process_message()?;
+    odk.process_message()?;
+
+    let expected: Vec<Vec<u8>> = [
+        Message::Hello(Address(5)),
+        Message::RequestOperation(Address(5), Operation::ReceiveConfig),
+        Message::Hello(Address(4)),
+    ]
+    .into_iter()
+    .map(|message| Frame::from(message).to_bytes())
+    .collect();
+    assert_eq!(expected, *received.borrow());
+
+    Ok(())
+}
+
+#[test]
+fn address_map_and_filter_can_be_changed() -> Result<(), Box<dyn Error>> {
+    let input = odk_input(&[
+        Message::Hello(Address(3)),
+        Message::Hello(Address(3)),
+        Message::Hello(Address(3)),
+    ]);
+    let port = MockSerialPort::new(input, SerialFailure::None);
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let bus = RecordingSignBus {
+        inner: VirtualSignBus::new(vec![
+            VirtualSign::new(Address(3), PageFlipStyle::Manual),
+            VirtualSign::new(Address(5), PageFlipStyle::Manual),
+        ]),
+        received: received.clone(),
+    };
+
+    let mut odk = Odk::try_new(port, bus)?;
+    odk.process_message()?;
+
+    odk.set_address_map(HashMap::from([(Address(3), Address(5))]));
+    odk.process_message()?;
+
+    odk.set_address_map(HashMap::new());
+    odk.set_filter(|direction, message| match direction {
+        Direction::OdkToSign => None,
+        Direction::SignToOdk => Some(message),
+    });
+    odk.process_message()?;
+
+    let expected: Vec<Vec<u8>> = [Message::Hello(Address(3)), Message::Hello(Address(5))]
+        .into_iter()
+        .map(|message| Frame::from(message).to_bytes())
+        .collect();
+    assert_eq!(expected, *received.borrow());
+
+    Ok(())
+}
+
+#[test]
+fn seen_addresses_tracks_polled_signs() -> Result<(), Box<dyn Error>> {
+    let input = odk_input(&[
+        Message::Hello(Address(3)),
+        Message::QueryState(Address(7)),
+        Message::Hello(Address(3)),
+        Message::DataChunksSent(ChunkCount(1)),
+    ]);
+    let port = MockSerialPort::new(input, SerialFailure::None);
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+
+    let mut odk = Odk::try_new(port, bus)?;
+    assert!(odk.seen_addresses().is_empty());
+    for _ in 0..4 {
+        odk.process_message()?;
+    }
+
+    assert_eq!(&HashSet::from([Address(3), Address(7)]), odk.seen_addresses());
+
+    Ok(())
+}
+
+#[test]
+fn bounded_processing() -> Result<(), Box<dyn Error>> {
+    let input = odk_input(&[
+        Message::Hello(Address(3)),
+        Message::QueryState(Address(4)),
+        Message::QueryState(Address(5)),
+    ]);
+    let port = MockSerialPort::new(input, SerialFailure::None);
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let mut odk = Odk::try_new(port, bus)?;
+
+    // A deadline that has already passed processes nothing.
+    odk.process_until(Instant::now())?;
+    assert!(odk.seen_addresses().is_empty());
+
+    odk.process_messages(2)?;
+    assert_eq!(&HashSet::from([Address(3), Address(4)]), odk.seen_addresses());
+
+    odk.process_messages(1)?;
+    assert_eq!(&HashSet::from([Address(3), Address(4), Address(5)]), odk.seen_addresses());
+
+    Ok(())
+}
+
+#[test]
+fn on_frame_reports_timestamped_traffic() -> Result<(), Box<dyn Error>> {
+    let input = odk_input(&[Message::Hello(Address(3)), Message::DataChunksSent(ChunkCount(0))]);
+    let port = MockSerialPort::new(input, SerialFailure::None);
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+
+    let frames = Rc::new(RefCell::new(Vec::new()));
+    let frames_clone = frames.clone();
+    let mut odk = Odk::try_new(port, bus)?;
+    odk.on_frame(move |frame| frames_clone.borrow_mut().push(frame));
+    odk.process_messages(2)?;
+
+    let frames = frames.borrow();
+    let traffic: Vec<(Direction, Message<'_>)> = frames
+        .iter()
+        .map(|stamped| (stamped.direction, Message::from(stamped.frame.clone())))
+        .collect();
+    assert_eq!(
+        vec![
+            (Direction::OdkToSign, Message::Hello(Address(3))),
+            (Direction::SignToOdk, Message::ReportState(Address(3), State::Unconfigured)),
+            (Direction::OdkToSign, Message::DataChunksSent(ChunkCount(0))),
+        ],
+        traffic
+    );
+    assert!(frames.windows(2).all(|pair| pair[0].at <= pair[1].at));
+    assert!(frames[0]
+        .display_since(frames[0].at)
+        .to_string()
+        .starts_with("+     0.000 ms  ODK -> sign"));
+
+    Ok(())
+}
+
+#[test]
+fn on_unknown_reports_unknown_frames() -> Result<(), Box<dyn Error>> {
+    let unknown = Frame::new(Address(3), MsgType(0x7E), Data::try_new(vec![1, 2, 3])?);
+    let mut input = odk_input(&[Message::Hello(Address(3))]);
+    input.extend(unknown.to_bytes_with_newline());
+    let port = MockSerialPort::new(input, SerialFailure::None);
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+
+    let unknowns = Rc::new(RefCell::new(Vec::new()));
+    let unknowns_clone = unknowns.clone();
+    let mut odk = Odk::try_new(port, bus)?;
+    odk.on_unknown(move |frame| unknowns_clone.borrow_mut().push(frame.to_bytes()));
+    odk.process_message()?;
+    odk.process_message()?;
+
+    assert_eq!(vec![unknown.to_bytes()], *unknowns.borrow());
+
+    Ok(())
+}
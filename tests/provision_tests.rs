@@ -0,0 +1,47 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use flipdot::core::State;
+use flipdot::{provision, Address, Page, PageFlipStyle, PageId, SignError, SignType};
+use flipdot_testing::{VirtualSign, VirtualSignBus};
+
+#[test]
+fn provision_continues_past_failures() {
+    let bus = VirtualSignBus::new(vec![
+        VirtualSign::new(Address(1), PageFlipStyle::Manual),
+        VirtualSign::new(Address(3), PageFlipStyle::Automatic),
+    ]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let items = [
+        (Address(1), SignType::Max3000Front112x16, vec![Page::new(PageId(1), 112, 16)]),
+        (Address(2), SignType::Max3000Rear30x10, vec![Page::new(PageId(1), 30, 10)]),
+        (Address(4), SignType::Max3000Dash30x7, vec![]),
+        (
+            Address(3),
+            SignType::Max3000Side90x7,
+            vec![Page::new(PageId(1), 90, 7), Page::new(PageId(2), 90, 7)],
+        ),
+    ];
+
+    let results = provision(bus.clone(), &items);
+    let addresses: Vec<Address> = results.iter().map(|(address, _)| *address).collect();
+    assert_eq!(vec![Address(1), Address(2), Address(4), Address(3)], addresses);
+
+    assert!(results[0].1.is_ok());
+    assert!(matches!(results[1].1, Err(SignError::UnexpectedResponse { .. })));
+    assert!(matches!(results[2].1, Err(SignError::UnexpectedResponse { .. })));
+    assert!(results[3].1.is_ok());
+
+    let bus = bus.borrow();
+    assert_eq!(State::PageLoaded, bus.sign(0).state());
+    assert_eq!(1, bus.sign(0).pages().len());
+    assert_eq!(State::ShowingPages, bus.sign(1).state());
+    assert_eq!(2, bus.sign(1).pages().len());
+}
+
+#[test]
+fn provision_empty() {
+    let bus = Rc::new(RefCell::new(VirtualSignBus::new(vec![])));
+    assert!(provision(bus, &[]).is_empty());
+}
@@ -1,8 +1,11 @@
 use std::cell::RefCell;
 use std::error::Error;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use flipdot::core::{Frame, Message, Operation, State};
+use flipdot::core::{ChunkCount, Data, Frame, Message, Offset, Operation, SignBus, State};
+use flipdot::serial::Clock;
 use flipdot::{Address, PageId, SerialSignBus, Sign, SignType};
 use serial_core::{PortSettings, SerialDevice};
 
@@ -35,6 +38,7 @@ fn serial_sign_bus_works() -> Result<(), Box<dyn Error>> {
         flow_control: serial_core::FlowControl::FlowNone,
     };
     assert_eq!(expected, bus.port().read_settings()?);
+    assert_eq!(expected, bus.port_settings()?);
 
     let bus = Rc::new(RefCell::new(bus));
     let sign = Sign::new(bus.clone(), Address(1), SignType::HorizonFront160x16);
@@ -50,3 +54,175 @@ fn serial_sign_bus_works() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn chunk_callback() -> Result<(), Box<dyn Error>> {
+    let mut buf = Vec::new();
+    buf.extend(Frame::from(Message::AckOperation(Address(1), Operation::ReceivePixels)).to_bytes_with_newline());
+    buf.extend(Frame::from(Message::ReportState(Address(1), State::PixelsReceived)).to_bytes_with_newline());
+    buf.extend(Frame::from(Message::ReportState(Address(1), State::PageLoaded)).to_bytes_with_newline());
+
+    let chunks = Arc::new(Mutex::new(Vec::new()));
+    let chunks_clone = chunks.clone();
+    let port = MockSerialPort::new(buf, SerialFailure::None);
+    let bus = SerialSignBus::try_new(port)?.with_chunk_callback(move |index, total| {
+        chunks_clone.lock().unwrap().push((index, total));
+    });
+
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(1), SignType::Max3000Side90x7);
+    let pages = [sign.create_page(PageId(1)), sign.create_page(PageId(2))];
+    sign.send_pages(&pages)?;
+
+    let expected: Vec<(usize, usize)> = (0..12).map(|i| (i, 12)).collect();
+    assert_eq!(expected, *chunks.lock().unwrap());
+
+    bus.borrow().port().done();
+
+    Ok(())
+}
+
+#[test]
+fn chunk_callback_discards_interrupted_transfer() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let mut buf = Vec::new();
+    buf.extend(Frame::from(Message::ReportState(Address(1), State::PixelsInProgress)).to_bytes_with_newline());
+
+    let chunks = Arc::new(Mutex::new(Vec::new()));
+    let chunks_clone = chunks.clone();
+    let port = MockSerialPort::new(buf, SerialFailure::None);
+    let mut bus = SerialSignBus::try_new(port)?.with_chunk_callback(move |index, total| {
+        chunks_clone.lock().unwrap().push((index, total));
+    });
+
+    // The first chunk is dropped when another message interrupts the transfer.
+    let _ = bus.process_message(Message::SendData(Offset(0), Data::try_new(vec![1; 16])?))?;
+    let _ = bus.process_message(Message::QueryState(Address(1)))?;
+    let _ = bus.process_message(Message::SendData(Offset(0), Data::try_new(vec![2; 16])?))?;
+    let _ = bus.process_message(Message::DataChunksSent(ChunkCount(1)))?;
+
+    assert_eq!(vec![(0, 1)], *chunks.lock().unwrap());
+
+    bus.port().done();
+
+    Ok(())
+}
+
+/// Clock that records requested delays instead of sleeping.
+#[derive(Debug, Default)]
+struct FakeClock {
+    sleeps: Vec<Duration>,
+}
+
+impl Clock for FakeClock {
+    fn sleep(&mut self, duration: Duration) {
+        self.sleeps.push(duration);
+    }
+}
+
+#[test]
+fn delays_requested_from_clock() -> Result<(), Box<dyn Error>> {
+    let mut buf = Vec::new();
+    buf.extend(Frame::from(Message::AckOperation(Address(1), Operation::ReceivePixels)).to_bytes_with_newline());
+    buf.extend(Frame::from(Message::ReportState(Address(1), State::PixelsReceived)).to_bytes_with_newline());
+    buf.extend(Frame::from(Message::ReportState(Address(1), State::PageLoadInProgress)).to_bytes_with_newline());
+    buf.extend(Frame::from(Message::ReportState(Address(1), State::PageLoaded)).to_bytes_with_newline());
+    buf.extend(Frame::from(Message::AckOperation(Address(1), Operation::ShowLoadedPage)).to_bytes_with_newline());
+    buf.extend(Frame::from(Message::ReportState(Address(1), State::PageShowInProgress)).to_bytes_with_newline());
+    buf.extend(Frame::from(Message::ReportState(Address(1), State::PageShown)).to_bytes_with_newline());
+
+    let port = MockSerialPort::new(buf, SerialFailure::None);
+    let bus = SerialSignBus::try_new(port)?.with_clock(FakeClock::default());
+
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(1), SignType::Max3000Side90x7);
+    sign.send_pages(&[sign.create_page(PageId(1))])?;
+
+    // One 30ms delay after each data chunk, then 100ms while the page is loading.
+    let mut expected = vec![Duration::from_millis(30); 6];
+    expected.push(Duration::from_millis(100));
+    assert_eq!(expected, bus.borrow().clock().sleeps);
+
+    sign.show_loaded_page()?;
+    expected.push(Duration::from_millis(100));
+    assert_eq!(expected, bus.borrow().clock().sleeps);
+
+    bus.borrow().port().done();
+
+    Ok(())
+}
+
+#[test]
+fn inter_byte_delay() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let port = MockSerialPort::new(vec![], SerialFailure::None);
+    let mut bus = SerialSignBus::try_new(port)?
+        .with_clock(FakeClock::default())
+        .with_inter_byte_delay(Some(Duration::from_micros(250)));
+
+    // ":00000301FC\r\n" is 13 bytes, so 12 delays between them.
+    let _ = bus.process_message(Message::DataChunksSent(ChunkCount(3)))?;
+    assert_eq!(vec![Duration::from_micros(250); 12], bus.clock().sleeps);
+
+    let mut bus = bus.with_inter_byte_delay(None);
+    let _ = bus.process_message(Message::DataChunksSent(ChunkCount(3)))?;
+    assert_eq!(12, bus.clock().sleeps.len());
+
+    Ok(())
+}
+
+#[test]
+fn serial_bus_is_physical() -> Result<(), Box<dyn Error>> {
+    let port = MockSerialPort::new(vec![], SerialFailure::None);
+    let bus: Rc<RefCell<dyn SignBus>> = Rc::new(RefCell::new(SerialSignBus::try_new(port)?));
+    assert_eq!(Some(true), bus.borrow().is_physical());
+    Ok(())
+}
+
+#[test]
+fn echo_suppression() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let mut buf = Vec::new();
+    // Echo followed by the response.
+    buf.extend(Frame::from(Message::Hello(Address(1))).to_bytes_with_newline());
+    buf.extend(Frame::from(Message::ReportState(Address(1), State::Unconfigured)).to_bytes_with_newline());
+    // Echo of a message with no response.
+    buf.extend(Frame::from(Message::DataChunksSent(ChunkCount(1))).to_bytes_with_newline());
+    // Missing echo: the response is still picked up.
+    buf.extend(Frame::from(Message::ReportState(Address(1), State::ConfigReceived)).to_bytes_with_newline());
+
+    let port = MockSerialPort::new(buf, SerialFailure::None);
+    let mut bus = SerialSignBus::try_new(port)?.with_echo_suppression(true);
+
+    let response = bus.process_message(Message::Hello(Address(1)))?;
+    assert_eq!(Some(Message::ReportState(Address(1), State::Unconfigured)), response);
+
+    let response = bus.process_message(Message::DataChunksSent(ChunkCount(1)))?;
+    assert_eq!(None, response);
+
+    let response = bus.process_message(Message::QueryState(Address(1)))?;
+    assert_eq!(Some(Message::ReportState(Address(1), State::ConfigReceived)), response);
+
+    bus.port().done();
+
+    Ok(())
+}
+
+#[test]
+fn echo_suppression_without_echo() -> Result<(), Box<dyn Error + Send + Sync>> {
+    let buf = Frame::from(Message::ReportState(Address(1), State::ConfigReceived)).to_bytes_with_newline();
+    let port = MockSerialPort::new(buf, SerialFailure::TimeoutWhenEmpty);
+    let mut bus = SerialSignBus::try_new(port)?.with_echo_suppression(true);
+
+    // No echo, but the response is still picked up.
+    let response = bus.process_message(Message::QueryState(Address(1)))?;
+    assert_eq!(Some(Message::ReportState(Address(1), State::ConfigReceived)), response);
+
+    // No echo and no response: the timeout is not an error.
+    let response = bus.process_message(Message::DataChunksSent(ChunkCount(1)))?;
+    assert_eq!(None, response);
+
+    // A timeout waiting for a response is still an error.
+    assert!(bus.process_message(Message::QueryState(Address(1))).is_err());
+
+    bus.port().done();
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::rc::Rc;
+
+use flipdot::core::formats;
+use flipdot::{Address, Page, PageFlipStyle, PageId, Sign, SignError, SignMessage, SignMessageError, SignType};
+use flipdot_testing::{VirtualSign, VirtualSignBus};
+
+#[test]
+fn round_trip() -> Result<(), Box<dyn Error>> {
+    let message = SignMessage {
+        sign_type: SignType::HorizonFront160x16,
+        pages: vec![Page::checkerboard(PageId(1), 160, 16), Page::filled(PageId(2), 160, 16, true)],
+    };
+
+    let mut file = Vec::new();
+    message.save(&mut file)?;
+    assert_eq!(SignType::HorizonFront160x16.to_bytes(), &file[..16]);
+    assert_eq!(message, SignMessage::load(&mut &file[..])?);
+    Ok(())
+}
+
+#[test]
+fn wrong_dimensions() -> Result<(), Box<dyn Error>> {
+    let mut message = SignMessage {
+        sign_type: SignType::Max3000Side90x7,
+        pages: vec![Page::new(PageId(1), 30, 7)],
+    };
+    let error = message.save(&mut Vec::new()).unwrap_err();
+    assert!(matches!(error, SignMessageError::Format { .. }));
+
+    // Files containing mismatched pages are also rejected when loading.
+    let mut file = SignType::Max3000Side90x7.to_bytes().to_vec();
+    formats::write_flipdot(&mut file, &message.pages)?;
+    let error = SignMessage::load(&mut &file[..]).unwrap_err();
+    assert!(matches!(error, SignMessageError::Format { .. }));
+
+    message.pages.clear();
+    message.save(&mut Vec::new())?;
+    Ok(())
+}
+
+#[test]
+fn send_message_file() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let mut page = sign.create_page(PageId(1));
+    page.set_pixel(1, 2, true);
+    let message = SignMessage {
+        sign_type: SignType::Max3000Side90x7,
+        pages: vec![page.clone()],
+    };
+    let mut file = Vec::new();
+    message.save(&mut file)?;
+
+    let loaded = SignMessage::load(&mut &file[..])?;
+    assert_eq!(PageFlipStyle::Manual, sign.send_message_file(&loaded)?);
+    assert_eq!(&[page], bus.borrow().sign(0).pages());
+
+    let other = SignMessage {
+        sign_type: SignType::Max3000Front112x16,
+        pages: vec![],
+    };
+    let error = sign.send_message_file(&other).unwrap_err();
+    assert!(matches!(
+        error,
+        SignError::SignTypeMismatch {
+            expected: SignType::Max3000Side90x7,
+            actual: SignType::Max3000Front112x16,
+        }
+    ));
+    Ok(())
+}
+
+#[test]
+fn malformed_header() {
+    let mut file = SignType::Max3000Side90x7.to_bytes().to_vec();
+    file.extend_from_slice(b"FLIPDOT\x01");
+    file.extend_from_slice(&u32::MAX.to_le_bytes());
+    file.extend_from_slice(&u32::MAX.to_le_bytes());
+    file.extend_from_slice(&1u32.to_le_bytes());
+
+    let error = SignMessage::load(&mut &file[..]).unwrap_err();
+    assert!(matches!(
+        error,
+        SignMessageError::Format {
+            source: formats::FormatError::InvalidDimensions { .. }
+        }
+    ));
+}
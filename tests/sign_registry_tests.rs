@@ -0,0 +1,67 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::rc::Rc;
+
+use flipdot::core::State;
+use flipdot::{Address, PageFlipStyle, SignRegistry, SignRegistryError, SignType};
+use flipdot_testing::{VirtualSign, VirtualSignBus};
+
+fn registry() -> SignRegistry {
+    SignRegistry::new()
+        .with_sign("front", Address(1), SignType::Max3000Front112x16)
+        .with_sign("side", Address(3), SignType::Max3000Side90x7)
+        .with_sign("rear", Address(5), SignType::Max3000Rear30x10)
+}
+
+#[test]
+fn lookup() {
+    let mut registry = registry();
+    assert_eq!(3, registry.len());
+    assert_eq!(Some((Address(5), SignType::Max3000Rear30x10)), registry.get("rear"));
+    assert_eq!(Some("front"), registry.name_of(Address(1)));
+
+    let entries: Vec<_> = registry.iter().collect();
+    assert_eq!(
+        vec![
+            ("front", Address(1), SignType::Max3000Front112x16),
+            ("rear", Address(5), SignType::Max3000Rear30x10),
+            ("side", Address(3), SignType::Max3000Side90x7),
+        ],
+        entries
+    );
+
+    assert_eq!(Some((Address(3), SignType::Max3000Side90x7)), registry.remove("side"));
+    assert_eq!(None, registry.get("side"));
+    assert_eq!(None, registry.name_of(Address(3)));
+}
+
+#[test]
+fn signs_by_name() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![
+        VirtualSign::new(Address(1), PageFlipStyle::Manual),
+        VirtualSign::new(Address(3), PageFlipStyle::Manual),
+        VirtualSign::new(Address(5), PageFlipStyle::Manual),
+    ]);
+    let bus = Rc::new(RefCell::new(bus));
+    let registry = registry();
+
+    registry.sign("side", bus.clone())?.configure()?;
+    assert_eq!(State::Unconfigured, bus.borrow().sign(0).state());
+    assert_eq!(Some(SignType::Max3000Side90x7), bus.borrow().sign(1).sign_type());
+    assert_eq!(State::Unconfigured, bus.borrow().sign(2).state());
+
+    for (_, sign) in registry.signs(bus.clone()) {
+        sign.configure()?;
+    }
+    assert_eq!(Some(SignType::Max3000Front112x16), bus.borrow().sign(0).sign_type());
+    assert_eq!(Some(SignType::Max3000Rear30x10), bus.borrow().sign(2).sign_type());
+
+    Ok(())
+}
+
+#[test]
+fn unknown_name() {
+    let bus = Rc::new(RefCell::new(VirtualSignBus::new(vec![])));
+    let error = registry().sign("dash", bus).unwrap_err();
+    assert!(matches!(error, SignRegistryError::UnknownName { ref name } if name == "dash"));
+}
@@ -2,9 +2,10 @@ use std::cell::RefCell;
 use std::error::Error;
 use std::io;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 use flipdot::core::{ChunkCount, Data, Message, Offset, Operation, State};
-use flipdot::{Address, Page, PageFlipStyle, PageId, Sign, SignBus, SignError, SignType};
+use flipdot::{Address, Page, PageFlipStyle, PageId, Sign, SignBus, SignConfig, SignError, SignInfo, SignType};
 
 const CONFIG: &[u8] = &[
     0x04, 0x20, 0x00, 0x06, 0x07, 0x1E, 0x1E, 0x1E, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -199,7 +200,9 @@ fn config_retry() -> Result<(), Box<dyn Error>> {
     let bus = Rc::new(RefCell::new(bus));
     let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
 
+    assert_eq!(0, sign.last_retry_count());
     sign.configure()?;
+    assert_eq!(1, sign.last_retry_count());
 
     bus.borrow_mut().done();
 
@@ -320,6 +323,7 @@ fn config_retry_gives_up() {
 
     let error = sign.configure().unwrap_err();
     assert!(matches!(error, SignError::UnexpectedResponse { .. }));
+    assert_eq!(2, sign.last_retry_count());
 
     bus.borrow_mut().done();
 }
@@ -475,7 +479,11 @@ fn pixels_auto_flip() -> Result<(), Box<dyn Error>> {
     let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
 
     let page = Page::from_bytes(90, 7, DATA)?;
+    assert!(sign.supported_operations().contains(&Operation::LoadNextPage));
     assert_eq!(PageFlipStyle::Automatic, sign.send_pages(&[page])?);
+    assert!(!sign.supported_operations().contains(&Operation::LoadNextPage));
+    assert!(!sign.supported_operations().contains(&Operation::ShowLoadedPage));
+    assert!(sign.supported_operations().contains(&Operation::ReceivePixels));
 
     bus.borrow_mut().done();
 
@@ -602,6 +610,27 @@ fn shut_down() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn shut_down_and_wait() -> Result<(), Box<dyn Error>> {
+    let script = vec![ScriptItem {
+        expected: Message::Goodbye(Address(3)),
+        response: Ok(None),
+    }];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let config = SignConfig::default().with_shutdown_lockout(Duration::from_millis(50));
+    let sign = Sign::with_config(bus.clone(), Address(3), SignType::Max3000Side90x7, config);
+
+    let start = Instant::now();
+    sign.shut_down_and_wait()?;
+    assert!(start.elapsed() >= Duration::from_millis(50));
+
+    bus.borrow_mut().done();
+
+    Ok(())
+}
+
 #[test]
 fn config_needs_reset() -> Result<(), Box<dyn Error>> {
     let script = vec![
@@ -754,7 +783,7 @@ fn flip_page_unexpected_response_error() {
 fn error_propagates() {
     let script = vec![ScriptItem {
         expected: Message::Hello(Address(3)),
-        response: Err(io::Error::new(io::ErrorKind::Other, "oh no!").into()),
+        response: Err(io::Error::other("oh no!").into()),
     }];
 
     let bus = ScriptedSignBus::new(script.into_iter());
@@ -790,9 +819,52 @@ fn configure_if_needed() -> Result<(), Box<dyn Error>> {
             expected: Message::Hello(Address(3)),
             response: Ok(Some(Message::ReportState(Address(3), State::Unconfigured))),
         },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::ReceiveConfig),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::ReceiveConfig))),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(0), Data::try_new(CONFIG).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::DataChunksSent(ChunkCount(1)),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ConfigReceived))),
+        },
         ScriptItem {
             expected: Message::Hello(Address(3)),
-            response: Ok(Some(Message::ReportState(Address(3), State::Unconfigured))),
+            response: Ok(Some(Message::ReportState(Address(3), State::ConfigReceived))),
+        },
+    ];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+    sign.configure_if_needed()?;
+    sign.configure_if_needed()?;
+
+    bus.borrow_mut().done();
+
+    Ok(())
+}
+
+#[test]
+fn configure_if_needed_avoids_reset() -> Result<(), Box<dyn Error>> {
+    let script = vec![
+        // Already configured: nothing else is sent.
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::PageShown))),
+        },
+        // Previous configuration failed: config is resent without a reset.
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ConfigFailed))),
         },
         ScriptItem {
             expected: Message::RequestOperation(Address(3), Operation::ReceiveConfig),
@@ -810,8 +882,45 @@ fn configure_if_needed() -> Result<(), Box<dyn Error>> {
             expected: Message::QueryState(Address(3)),
             response: Ok(Some(Message::ReportState(Address(3), State::ConfigReceived))),
         },
+        // Some other state: falls back to a full reset.
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::PixelsInProgress))),
+        },
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::PixelsInProgress))),
+        },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::StartReset),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::StartReset))),
+        },
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ReadyToReset))),
+        },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::FinishReset),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::FinishReset))),
+        },
         ScriptItem {
             expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::Unconfigured))),
+        },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::ReceiveConfig),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::ReceiveConfig))),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(0), Data::try_new(CONFIG).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::DataChunksSent(ChunkCount(1)),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
             response: Ok(Some(Message::ReportState(Address(3), State::ConfigReceived))),
         },
     ];
@@ -822,6 +931,421 @@ fn configure_if_needed() -> Result<(), Box<dyn Error>> {
 
     sign.configure_if_needed()?;
     sign.configure_if_needed()?;
+    sign.configure_if_needed()?;
+
+    bus.borrow_mut().done();
+
+    Ok(())
+}
+
+#[test]
+fn send_pages_one_shot_iterator() -> Result<(), Box<dyn Error>> {
+    let script = vec![
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::ReceivePixels),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::ReceivePixels))),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(0), Data::try_new(&DATA[0..16]).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(16), Data::try_new(&DATA[16..32]).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(32), Data::try_new(&DATA[32..48]).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(48), Data::try_new(&DATA[48..64]).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(64), Data::try_new(&DATA[64..80]).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(80), Data::try_new(&DATA[80..96]).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::DataChunksSent(ChunkCount(6)),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::PixelsReceived))),
+        },
+        ScriptItem {
+            expected: Message::PixelsComplete(Address(3)),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::PageLoaded))),
+        },
+    ];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+    // Closures that mutably borrow state can't be cloned, so neither can this iterator.
+    let pages = [Page::from_bytes(90, 7, DATA)?];
+    let mut pages_seen = 0;
+    let one_shot = pages.iter().inspect(|_| pages_seen += 1);
+    assert_eq!(PageFlipStyle::Manual, sign.send_pages(one_shot)?);
+    assert_eq!(1, pages_seen);
+
+    bus.borrow_mut().done();
+
+    Ok(())
+}
+
+#[test]
+fn send_pages_empty() {
+    let bus = ScriptedSignBus::new(vec![].into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+    let error = sign.send_pages(&[]).unwrap_err();
+    assert!(matches!(error, SignError::NoPages));
+
+    bus.borrow_mut().done();
+}
+
+#[test]
+fn custom_chunk_size() -> Result<(), Box<dyn Error>> {
+    let script = vec![
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::ReceivePixels),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::ReceivePixels))),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(0), Data::try_new(&DATA[0..40]).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(40), Data::try_new(&DATA[40..80]).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(80), Data::try_new(&DATA[80..96]).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::DataChunksSent(ChunkCount(3)),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::PixelsReceived))),
+        },
+        ScriptItem {
+            expected: Message::PixelsComplete(Address(3)),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::PageLoaded))),
+        },
+    ];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let config = SignConfig::new().with_chunk_size(40);
+    let sign = Sign::with_config(bus.clone(), Address(3), SignType::Max3000Side90x7, config);
+
+    let page = Page::from_bytes(90, 7, DATA)?;
+    assert_eq!(PageFlipStyle::Manual, sign.send_pages(&[page])?);
+
+    bus.borrow_mut().done();
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn zero_chunk_size() {
+    let _ = SignConfig::new().with_chunk_size(0);
+}
+
+#[test]
+fn page_flip_timeout() {
+    let script = vec![ScriptItem {
+        expected: Message::QueryState(Address(3)),
+        response: Ok(Some(Message::ReportState(Address(3), State::PageShowInProgress))),
+    }];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let config = SignConfig::new().with_operation_timeout(Duration::ZERO);
+    let sign = Sign::with_config(bus.clone(), Address(3), SignType::Max3000Side90x7, config);
+
+    let error = sign.show_loaded_page().unwrap_err();
+    assert!(matches!(error, SignError::Timeout { .. }));
+
+    bus.borrow_mut().done();
+}
+
+#[test]
+fn config_retry_timeout() {
+    let script = vec![
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::Unconfigured))),
+        },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::ReceiveConfig),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::ReceiveConfig))),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(0), Data::try_new(CONFIG).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::DataChunksSent(ChunkCount(1)),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ConfigFailed))),
+        },
+    ];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let config = SignConfig::new().with_operation_timeout(Duration::ZERO);
+    let sign = Sign::with_config(bus.clone(), Address(3), SignType::Max3000Side90x7, config);
+
+    let error = sign.configure().unwrap_err();
+    assert!(matches!(error, SignError::Timeout { .. }));
+
+    bus.borrow_mut().done();
+}
+
+#[test]
+fn config_in_progress() -> Result<(), Box<dyn Error>> {
+    let script = vec![
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::Unconfigured))),
+        },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::ReceiveConfig),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::ReceiveConfig))),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(0), Data::try_new(CONFIG).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::DataChunksSent(ChunkCount(1)),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ConfigInProgress))),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ConfigInProgress))),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ConfigReceived))),
+        },
+    ];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+    sign.configure()?;
+
+    bus.borrow_mut().done();
+
+    Ok(())
+}
+
+#[test]
+fn config_in_progress_timeout() {
+    let script = vec![
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::Unconfigured))),
+        },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::ReceiveConfig),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::ReceiveConfig))),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(0), Data::try_new(CONFIG).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::DataChunksSent(ChunkCount(1)),
+            response: Ok(None),
+        },
+    ];
+
+    // The sign never finishes processing the configuration.
+    let stuck = std::iter::repeat_with(|| ScriptItem {
+        expected: Message::QueryState(Address(3)),
+        response: Ok(Some(Message::ReportState(Address(3), State::ConfigInProgress))),
+    });
+
+    let bus = ScriptedSignBus::new(script.into_iter().chain(stuck));
+    let bus = Rc::new(RefCell::new(bus));
+    let config = SignConfig::new().with_operation_timeout(Duration::from_millis(50));
+    let sign = Sign::with_config(bus.clone(), Address(3), SignType::Max3000Side90x7, config);
+
+    let start = Instant::now();
+    let error = sign.configure().unwrap_err();
+    assert!(matches!(error, SignError::Timeout { .. }));
+    assert!(start.elapsed() >= Duration::from_millis(50));
+}
+
+#[test]
+fn identify() -> Result<(), Box<dyn Error>> {
+    let script = vec![
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::PageShown))),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(None),
+        },
+    ];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+    let expected = SignInfo {
+        address: Address(3),
+        sign_type: SignType::Max3000Side90x7,
+        width: 90,
+        height: 7,
+        config: CONFIG,
+        state: State::PageShown,
+    };
+    assert_eq!(expected, sign.identify()?);
+
+    let error = sign.identify().unwrap_err();
+    assert!(matches!(error, SignError::UnexpectedResponse { .. }));
+
+    bus.borrow_mut().done();
+
+    Ok(())
+}
+
+#[test]
+fn recover_ready() -> Result<(), Box<dyn Error>> {
+    let script = vec![ScriptItem {
+        expected: Message::QueryState(Address(3)),
+        response: Ok(Some(Message::ReportState(Address(3), State::PageShown))),
+    }];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+    assert_eq!(State::PageShown, sign.recover()?);
+
+    bus.borrow_mut().done();
+
+    Ok(())
+}
+
+#[test]
+fn recover_config_failed() -> Result<(), Box<dyn Error>> {
+    let script = vec![
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ConfigFailed))),
+        },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::ReceiveConfig),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::ReceiveConfig))),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(0), Data::try_new(CONFIG).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::DataChunksSent(ChunkCount(1)),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ConfigReceived))),
+        },
+    ];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+    assert_eq!(State::ConfigReceived, sign.recover()?);
+
+    bus.borrow_mut().done();
+
+    Ok(())
+}
+
+#[test]
+fn recover_pixels_in_progress() -> Result<(), Box<dyn Error>> {
+    let script = vec![
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::PixelsInProgress))),
+        },
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::PixelsInProgress))),
+        },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::StartReset),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::StartReset))),
+        },
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ReadyToReset))),
+        },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::FinishReset),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::FinishReset))),
+        },
+        ScriptItem {
+            expected: Message::Hello(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::Unconfigured))),
+        },
+        ScriptItem {
+            expected: Message::RequestOperation(Address(3), Operation::ReceiveConfig),
+            response: Ok(Some(Message::AckOperation(Address(3), Operation::ReceiveConfig))),
+        },
+        ScriptItem {
+            expected: Message::SendData(Offset(0), Data::try_new(CONFIG).unwrap()),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::DataChunksSent(ChunkCount(1)),
+            response: Ok(None),
+        },
+        ScriptItem {
+            expected: Message::QueryState(Address(3)),
+            response: Ok(Some(Message::ReportState(Address(3), State::ConfigReceived))),
+        },
+    ];
+
+    let bus = ScriptedSignBus::new(script.into_iter());
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+
+    assert_eq!(State::ConfigReceived, sign.recover()?);
 
     bus.borrow_mut().done();
 
@@ -0,0 +1,118 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::rc::Rc;
+
+use flipdot::core::{ChunkCount, Message, Operation, State};
+use flipdot::{smoke_test, Address, PageFlipStyle, SignBus, SignError, SignType, SmokeStep};
+use flipdot_testing::{VirtualSign, VirtualSignBus};
+
+/// Wraps a `VirtualSignBus`, but miscounts the chunks of the first multi-chunk transfer.
+#[derive(Debug)]
+struct DroppedChunkBus<'a> {
+    bus: VirtualSignBus<'a>,
+    dropped: bool,
+}
+
+impl SignBus for DroppedChunkBus<'_> {
+    fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
+        match message {
+            Message::DataChunksSent(ChunkCount(count)) if count > 1 && !self.dropped => {
+                self.dropped = true;
+                self.bus.process_message(Message::DataChunksSent(ChunkCount(count + 1)))
+            }
+            message => self.bus.process_message(message),
+        }
+    }
+}
+
+#[test]
+fn manual_sign_passes() {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+    let report = smoke_test(bus.clone(), Address(3), SignType::Max3000Front98x16);
+
+    assert!(report.failure.is_none(), "{:?}", report.failure);
+    assert_eq!(Some(PageFlipStyle::Manual), report.flip_style);
+    assert_eq!(
+        vec![
+            SmokeStep::Configure,
+            SmokeStep::SendPages,
+            SmokeStep::ShowPage,
+            SmokeStep::LoadNextPage,
+            SmokeStep::ShowNextPage,
+            SmokeStep::ShutDown,
+        ],
+        report.completed
+    );
+    assert_eq!(
+        vec![
+            State::Unconfigured,
+            State::ConfigReceived,
+            State::PixelsReceived,
+            State::PageLoaded,
+            State::PageLoaded,
+            State::PageShowInProgress,
+            State::PageShown,
+            State::PageShown,
+            State::PageLoadInProgress,
+            State::PageLoaded,
+            State::PageLoaded,
+            State::PageShowInProgress,
+            State::PageShown,
+        ],
+        report.states
+    );
+    assert_eq!(State::Unconfigured, bus.borrow().sign(0).state());
+}
+
+#[test]
+fn automatic_sign_skips_page_flips() {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Automatic)]);
+    let report = smoke_test(Rc::new(RefCell::new(bus)), Address(3), SignType::HorizonDash40x12);
+
+    assert!(report.passed());
+    assert_eq!(Some(PageFlipStyle::Automatic), report.flip_style);
+    assert_eq!(
+        vec![SmokeStep::Configure, SmokeStep::SendPages, SmokeStep::ShutDown],
+        report.completed
+    );
+}
+
+#[test]
+fn configured_sign_is_reset() {
+    let mut bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let _ = bus.process_message(Message::RequestOperation(Address(3), Operation::ReceiveConfig));
+
+    let report = smoke_test(Rc::new(RefCell::new(bus)), Address(3), SignType::Max3000Side90x7);
+    assert!(report.passed());
+    assert_eq!(
+        &[State::ConfigInProgress, State::ReadyToReset, State::Unconfigured],
+        &report.states[..3]
+    );
+}
+
+#[test]
+fn missing_sign_fails() {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let report = smoke_test(Rc::new(RefCell::new(bus)), Address(4), SignType::Max3000Side90x7);
+
+    assert!(!report.passed());
+    assert!(report.completed.is_empty());
+    assert!(report.states.is_empty());
+
+    let failure = report.failure.unwrap();
+    assert_eq!(SmokeStep::Configure, failure.step);
+    assert!(matches!(failure.reason, SignError::UnexpectedResponse { .. }));
+}
+
+#[test]
+fn failed_transfer_is_retried() {
+    let bus = DroppedChunkBus {
+        bus: VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]),
+        dropped: false,
+    };
+    let report = smoke_test(Rc::new(RefCell::new(bus)), Address(3), SignType::Max3000Side90x7);
+
+    assert!(report.passed(), "{:?}", report.failure);
+    assert_eq!(&[State::PixelsFailed, State::PixelsReceived], &report.states[2..4]);
+}
@@ -1,10 +1,14 @@
 use std::cell::RefCell;
 use std::error::Error;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
-use flipdot::core::State;
-use flipdot::{Address, PageFlipStyle, PageId, Sign, SignType};
-use flipdot_testing::{VirtualSign, VirtualSignBus};
+use flipdot::core::{Data, Message, Offset, Operation, PageHeader, State};
+use flipdot::{Address, Page, PageFlipStyle, PageId, Sign, SignBus, SignConfig, SignError, SignType};
+use flipdot_testing::{VirtualSign, VirtualSignBus, VirtualSignSnapshot};
 
 #[test]
 fn sign_virtual_sign_interaction() -> Result<(), Box<dyn Error>> {
@@ -109,3 +113,530 @@ fn sign_virtual_sign_interaction() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn change_flip_style() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let pages = [sign.create_page(PageId(1))];
+    assert_eq!(PageFlipStyle::Manual, sign.send_pages(&pages)?);
+    assert_eq!(State::PageLoaded, bus.borrow().sign(0).state());
+
+    // Switching styles partway through should be reflected the next time pages are sent.
+    bus.borrow_mut().sign_mut(0).set_flip_style(PageFlipStyle::Automatic);
+    assert_eq!(State::PageLoaded, bus.borrow().sign(0).state());
+
+    assert_eq!(PageFlipStyle::Automatic, sign.send_pages(&pages)?);
+    assert_eq!(State::ShowingPages, bus.borrow().sign(0).state());
+
+    bus.borrow_mut().sign_mut(0).set_flip_style(PageFlipStyle::Manual);
+    assert_eq!(PageFlipStyle::Manual, sign.send_pages(&pages)?);
+    assert_eq!(State::PageLoaded, bus.borrow().sign(0).state());
+
+    Ok(())
+}
+
+#[test]
+fn snapshot_tracks_current_page() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let pages = [sign.create_page(PageId(1)), sign.create_page(PageId(2))];
+    sign.send_pages(&pages)?;
+
+    let mut expected = VirtualSignSnapshot {
+        address: Address(3),
+        state: State::PageLoaded,
+        sign_type: Some(SignType::Max3000Side90x7),
+        width: 90,
+        height: 7,
+        page_count: 2,
+        current_page_index: Some(0),
+    };
+    assert_eq!(expected, bus.borrow().sign(0).snapshot());
+
+    sign.show_loaded_page()?;
+    sign.load_next_page()?;
+    expected.current_page_index = Some(1);
+    assert_eq!(expected, bus.borrow().sign(0).snapshot());
+
+    // Loading past the last page wraps back around to the first.
+    sign.show_loaded_page()?;
+    sign.load_next_page()?;
+    expected.current_page_index = Some(0);
+    assert_eq!(expected, bus.borrow().sign(0).snapshot());
+
+    sign.shut_down()?;
+    let expected = VirtualSignSnapshot {
+        address: Address(3),
+        state: State::Unconfigured,
+        sign_type: None,
+        width: 0,
+        height: 0,
+        page_count: 0,
+        current_page_index: None,
+    };
+    assert_eq!(expected, bus.borrow().sign(0).snapshot());
+
+    Ok(())
+}
+
+#[test]
+fn page_summaries() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let mut page1 = sign.create_page(PageId(1));
+    page1.set_pixel(0, 0, true);
+    page1.set_pixel(89, 6, true);
+    let mut page2 = sign.create_page(PageId(2));
+    page2.set_all_pixels(true);
+    sign.send_pages(&[page1, page2])?;
+
+    assert_eq!(vec![(PageId(1), 2), (PageId(2), 630)], bus.borrow().sign(0).page_summaries());
+
+    Ok(())
+}
+
+#[test]
+fn stream_animation_manual() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let frames = (1..=5).map(|id| sign.create_page(PageId(id)));
+    sign.stream_animation(frames, 2, Duration::ZERO)?;
+
+    // The last batch contains only the fifth frame, which is now shown.
+    let ids: Vec<PageId> = bus.borrow().sign(0).pages().iter().map(|page| page.id()).collect();
+    assert_eq!(vec![PageId(5)], ids);
+    assert_eq!(State::PageShown, bus.borrow().sign(0).state());
+
+    Ok(())
+}
+
+#[test]
+fn stream_animation_automatic() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Automatic)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let frames = (1..=5).map(|id| sign.create_page(PageId(id)));
+    sign.stream_animation(frames, 3, Duration::ZERO)?;
+
+    let ids: Vec<PageId> = bus.borrow().sign(0).pages().iter().map(|page| page.id()).collect();
+    assert_eq!(vec![PageId(4), PageId(5)], ids);
+    assert_eq!(State::ShowingPages, bus.borrow().sign(0).state());
+
+    Ok(())
+}
+
+#[test]
+fn stream_animation_empty() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    sign.stream_animation(Vec::new(), 2, Duration::ZERO)?;
+    assert_eq!(State::ConfigReceived, bus.borrow().sign(0).state());
+
+    Ok(())
+}
+
+#[test]
+fn update_page_replaces_one_page() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let mut first = sign.create_page(PageId(1));
+    first.set_pixel(0, 0, true);
+    sign.send_pages(&[first.clone(), sign.create_page(PageId(2)), sign.create_page(PageId(3))])?;
+
+    let mut second = sign.create_page(PageId(2));
+    second.set_pixel(5, 5, true);
+    assert_eq!(PageFlipStyle::Manual, sign.update_page(&second)?);
+    assert_eq!(State::PageLoaded, bus.borrow().sign(0).state());
+
+    {
+        let bus = bus.borrow();
+        let pages = bus.sign(0).pages();
+        assert_eq!(3, pages.len());
+        assert_eq!(first, pages[0]);
+        assert_eq!(second, pages[1]);
+        assert_eq!(PageId(3), pages[2].id());
+    }
+
+    // Later updates build on earlier ones.
+    let third = sign.create_page(PageId(3));
+    sign.update_page(&third)?;
+    assert_eq!(second, bus.borrow().sign(0).pages()[1]);
+
+    Ok(())
+}
+
+#[test]
+fn update_page_unknown_id() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+    assert!(matches!(
+        sign.update_page(&sign.create_page(PageId(1))),
+        Err(SignError::UnknownPage { id: PageId(1) })
+    ));
+
+    sign.send_pages(&[sign.create_page(PageId(1))])?;
+    assert!(matches!(
+        sign.update_page(&sign.create_page(PageId(2))),
+        Err(SignError::UnknownPage { id: PageId(2) })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn update_page_forgets_pages_after_reset() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+    sign.send_pages(&[sign.create_page(PageId(1))])?;
+    sign.configure()?;
+    assert!(matches!(
+        sign.update_page(&sign.create_page(PageId(1))),
+        Err(SignError::UnknownPage { id: PageId(1) })
+    ));
+
+    sign.send_pages(&[sign.create_page(PageId(1))])?;
+    sign.shut_down()?;
+    assert!(matches!(
+        sign.update_page(&sign.create_page(PageId(1))),
+        Err(SignError::UnknownPage { id: PageId(1) })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn pages_in_order() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+    sign.send_pages(&[
+        sign.create_page(PageId(2)),
+        sign.create_page(PageId(0)),
+        sign.create_page(PageId(1)),
+    ])?;
+
+    let bus = bus.borrow();
+    let received: Vec<PageId> = bus.sign(0).pages().iter().map(|page| page.id()).collect();
+    assert_eq!(vec![PageId(2), PageId(0), PageId(1)], received);
+
+    let ordered: Vec<PageId> = bus.sign(0).pages_in_order().iter().map(|page| page.id()).collect();
+    assert_eq!(vec![PageId(0), PageId(1), PageId(2)], ordered);
+
+    Ok(())
+}
+
+#[test]
+fn page_lookup_by_id() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+    let mut pages = [sign.create_page(PageId(5)), sign.create_page(PageId(2))];
+    pages[1].set_pixel(10, 3, true);
+    sign.send_pages(&pages)?;
+
+    let bus = bus.borrow();
+    assert_eq!(Some(&pages[1]), bus.sign(0).page(PageId(2)));
+    assert_eq!(Some(&pages[0]), bus.sign(0).page(PageId(5)));
+    assert_eq!(None, bus.sign(0).page(PageId(0)));
+
+    Ok(())
+}
+
+#[test]
+fn configure_verbose_reports_recognized_type() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![
+        VirtualSign::new(Address(3), PageFlipStyle::Manual),
+        VirtualSign::new(Address(6), PageFlipStyle::Manual),
+    ]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(6), SignType::Max3000Front98x16);
+    assert_eq!(Some(SignType::Max3000Front98x16), sign.configure_verbose()?);
+    assert_eq!(None, bus.borrow().recognized_sign_type(Address(3)));
+    assert_eq!(None, bus.borrow().recognized_sign_type(Address(7)));
+
+    Ok(())
+}
+
+#[test]
+fn known_flip_style_is_remembered() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Automatic)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let sign = Sign::new(bus.clone(), Address(3), SignType::HorizonDash40x12);
+    assert_eq!(None, sign.known_flip_style());
+
+    sign.configure()?;
+    assert_eq!(None, sign.known_flip_style());
+
+    sign.send_pages(&[sign.create_page(PageId(1))])?;
+    assert_eq!(Some(PageFlipStyle::Automatic), sign.known_flip_style());
+
+    sign.configure()?;
+    assert_eq!(Some(PageFlipStyle::Automatic), sign.known_flip_style());
+
+    Ok(())
+}
+
+#[test]
+fn run_cycle_flips_until_stopped() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let pages = [sign.create_page(PageId(1)), sign.create_page(PageId(2))];
+    let stop = Arc::new(AtomicBool::new(false));
+    let stopper = {
+        let stop = stop.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            stop.store(true, Ordering::Relaxed);
+        })
+    };
+
+    let style = sign.run_cycle(&pages, Duration::from_millis(5), &stop)?;
+    stopper.join().unwrap();
+
+    assert_eq!(PageFlipStyle::Manual, style);
+    assert_eq!(State::PageShown, bus.borrow().sign(0).state());
+
+    Ok(())
+}
+
+#[test]
+fn run_cycle_returns_for_automatic_signs() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Automatic)]);
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::HorizonDash40x12);
+    sign.configure()?;
+
+    let stop = AtomicBool::new(false);
+    let style = sign.run_cycle(&[sign.create_page(PageId(1))], Duration::from_secs(60), &stop)?;
+    assert_eq!(PageFlipStyle::Automatic, style);
+
+    Ok(())
+}
+
+#[test]
+fn send_pages_checks_bits_per_column() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let error = sign.send_pages(&[Page::new(PageId(1), 90, 9)]).unwrap_err();
+    assert!(matches!(
+        error,
+        SignError::BitsPerColumnMismatch {
+            id: PageId(1),
+            expected: 8,
+            actual: 16
+        }
+    ));
+    assert_eq!(State::ConfigReceived, bus.borrow().sign(0).state());
+
+    sign.send_pages(&[sign.create_page(PageId(1))])?;
+    let error = sign.update_page(&Page::new(PageId(1), 90, 9)).unwrap_err();
+    assert!(matches!(error, SignError::BitsPerColumnMismatch { .. }));
+
+    Ok(())
+}
+
+#[test]
+fn with_address_shares_bus_and_config() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::with_addresses([Address(3), Address(4)], PageFlipStyle::Manual);
+    let bus = Rc::new(RefCell::new(bus));
+    let config = SignConfig::new().with_chunk_size(32);
+    let first = Sign::with_config(bus.clone(), Address(3), SignType::Max3000Front98x16, config);
+    first.configure()?;
+    first.send_pages(&[first.create_page(PageId(1))])?;
+
+    let second = first.with_address(Address(4));
+    assert_eq!(Address(4), second.address());
+    assert_eq!(SignType::Max3000Front98x16, second.sign_type());
+    assert_eq!(&config, second.config());
+    assert_eq!(None, second.known_flip_style());
+
+    second.configure()?;
+    assert_eq!(Some(SignType::Max3000Front98x16), bus.borrow().sign(1).sign_type());
+
+    Ok(())
+}
+
+#[test]
+fn random_pages_survive_transfer() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Front112x16);
+    sign.configure()?;
+
+    for seed in 0..4 {
+        let (width, height) = sign.dimensions();
+        let pages = [
+            Page::random(PageId(1), width, height, seed),
+            Page::random(PageId(2), width, height, seed + 100),
+        ];
+        sign.send_pages(&pages)?;
+        assert_eq!(&pages[..], bus.borrow().sign(0).pages());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn page_headers_survive_transfer() -> Result<(), Box<dyn Error>> {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let (width, height) = sign.dimensions();
+    let pages = [[0x01, 0x20, 0x03, 0x04], [0x02, 0x30, 0x05, 0x00]]
+        .iter()
+        .map(|&header| {
+            let header = PageHeader::from_bytes(header);
+            let mut page = Page::random(header.id, width, height, u64::from(header.id.0));
+            page.set_header_fields(header);
+            page
+        })
+        .collect::<Vec<_>>();
+    sign.send_pages(&pages)?;
+
+    let bus = bus.borrow();
+    let received = bus.sign(0).pages();
+    assert_eq!(&pages[..], received);
+    for (sent, received) in pages.iter().zip(received) {
+        assert_eq!(sent.header_fields(), received.header_fields());
+    }
+    assert_eq!([0x01, 0x20, 0x03, 0x04], received[0].header_fields().to_bytes());
+
+    Ok(())
+}
+
+/// Wraps a `VirtualSignBus`, but garbles any configuration other than the one for `accepts`.
+#[derive(Debug)]
+struct PickySignBus<'a> {
+    bus: VirtualSignBus<'a>,
+    accepts: SignType,
+}
+
+impl SignBus for PickySignBus<'_> {
+    fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
+        match message {
+            Message::SendData(Offset(0), ref data) if data.get().len() == 16 && data.get()[..] != *self.accepts.to_bytes() => {
+                self.bus
+                    .process_message(Message::SendData(Offset(0), Data::try_new(vec![0; 16])?))
+            }
+            message => self.bus.process_message(message),
+        }
+    }
+}
+
+#[test]
+fn probe_type_finds_accepted_config() -> Result<(), Box<dyn Error>> {
+    let bus = PickySignBus {
+        bus: VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]),
+        accepts: SignType::HorizonSide96x8,
+    };
+    let bus = Rc::new(RefCell::new(bus));
+
+    assert_eq!(SignType::HorizonSide96x8, Sign::probe_type(bus.clone(), Address(3))?);
+    assert_eq!(State::ConfigReceived, bus.borrow().bus.sign(0).state());
+    assert_eq!(Some(SignType::HorizonSide96x8), bus.borrow().bus.sign(0).sign_type());
+
+    Ok(())
+}
+
+#[test]
+fn probe_type_missing_sign() {
+    let bus = VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]);
+    let bus = Rc::new(RefCell::new(bus));
+
+    let error = Sign::probe_type(bus.clone(), Address(4)).unwrap_err();
+    assert!(matches!(error, SignError::NoTypeAccepted));
+}
+
+/// Wraps a `VirtualSignBus`, resetting the sign on the first state query and setting `stop` once a page is shown.
+#[derive(Debug)]
+struct GlitchySignBus<'a> {
+    bus: VirtualSignBus<'a>,
+    glitched: bool,
+    stop: Arc<AtomicBool>,
+}
+
+impl SignBus for GlitchySignBus<'_> {
+    fn process_message<'a>(&mut self, message: Message<'_>) -> Result<Option<Message<'a>>, Box<dyn Error + Send + Sync>> {
+        match message {
+            Message::QueryState(address) if !self.glitched => {
+                self.glitched = true;
+                let _ = self.bus.process_message(Message::Goodbye(address))?;
+            }
+            Message::RequestOperation(_, Operation::ShowLoadedPage) => self.stop.store(true, Ordering::Relaxed),
+            _ => {}
+        }
+        self.bus.process_message(message)
+    }
+}
+
+#[test]
+fn maintain_restores_reset_sign() -> Result<(), Box<dyn Error>> {
+    let stop = Arc::new(AtomicBool::new(false));
+    let bus = GlitchySignBus {
+        bus: VirtualSignBus::new(vec![VirtualSign::new(Address(3), PageFlipStyle::Manual)]),
+        glitched: true,
+        stop: stop.clone(),
+    };
+    let bus = Rc::new(RefCell::new(bus));
+    let sign = Sign::new(bus.clone(), Address(3), SignType::Max3000Side90x7);
+    sign.configure()?;
+
+    let mut pages = [sign.create_page(PageId(1)), sign.create_page(PageId(2))];
+    pages[1].set_pixel(3, 3, true);
+    sign.send_pages(&pages)?;
+    bus.borrow_mut().glitched = false;
+
+    assert_eq!(1, sign.maintain(&pages, Duration::from_millis(1), &stop)?);
+    assert_eq!(&pages[..], bus.borrow().bus.sign(0).pages());
+    assert_eq!(State::PageShown, bus.borrow().bus.sign(0).state());
+
+    Ok(())
+}